@@ -34,7 +34,7 @@
 //!
 //! To create your own assertion function, first create the type that represents
 //! the assertion, then create the function that produces the type. For example,
-//! to create an assertion that passes if it receives a `0`:
+//! to create an assertion that passes if it receives a `42`:
 //!
 //! ```
 //! use expecters::{
@@ -46,9 +46,9 @@
 //!
 //! // We need to create a struct for our assertion and define its behavior
 //! #[derive(Clone, Debug)]
-//! pub struct ToBeZero(Annotated<String>);
+//! pub struct ToBeFortyTwo(Annotated<String>);
 //!
-//! impl Assertion<i32> for ToBeZero {
+//! impl Assertion<i32> for ToBeFortyTwo {
 //!     // What does this assertion return when it's executed? Sometimes
 //!     // assertions want to return other output types, like if they need to
 //!     // run asynchronously and have to return a future instead.
@@ -60,7 +60,7 @@
 //!         cx.annotate("input parameter", &self.0);
 //!
 //!         // Then execute your assertion
-//!         cx.pass_if(value == 0, "was not zero")
+//!         cx.pass_if(value == 42, "was not 42")
 //!     }
 //! }
 //!
@@ -69,8 +69,8 @@
 //! trait MyAssertions {
 //!     // Input parameters are automatically annotated, so we need to wrap them
 //!     // with `Annotated<T>`
-//!     fn to_be_zero(&self, note: Annotated<String>) -> ToBeZero {
-//!         ToBeZero(note)
+//!     fn to_be_forty_two(&self, note: Annotated<String>) -> ToBeFortyTwo {
+//!         ToBeFortyTwo(note)
 //!     }
 //! }
 //!
@@ -80,15 +80,15 @@
 //! impl<M> MyAssertions for AssertionBuilder<i32, M> {}
 //!
 //! // Now we can use the assertion:
-//! expect!(0, to_be_zero("hello, world!".to_string()));
+//! expect!(42, to_be_forty_two("hello, world!".to_string()));
 //! // You can also use modifiers with your assertion:
-//! expect!(1, not, to_be_zero("this assertion is negated".to_string()));
+//! expect!(1, not, to_be_forty_two("this assertion is negated".to_string()));
 //! ```
 //!
 //! An assertion function that takes no parameters can be called without
 //! parentheses when using the [`expect!`] macro. For example, if the assertion
-//! function signature is `pub fn to_be_zero() -> ToBeZeroAssertion`, then the
-//! assertion can be used like `expect!(0, to_be_zero)`.
+//! function signature is `pub fn to_be_forty_two() -> ToBeFortyTwoAssertion`,
+//! then the assertion can be used like `expect!(42, to_be_forty_two)`.
 //!
 //! ## Creating a modifier
 //!
@@ -178,7 +178,7 @@
 //!
 //! // Now we need to attach our modifier. We can reuse an existing trait if we
 //! // want, if the input types are compatible. Note that we now take `self`
-//! // instead of `&self` (unlike the `to_be_zero` assertion):
+//! // instead of `&self` (unlike the `to_be_forty_two` assertion):
 //! trait MyAssertions<M> {
 //!     // We return an `AssertionBuilder<f32, ...>` here because we're passing
 //!     // a `f32` value to whatever assertion we receive. If we were to convert
@@ -227,21 +227,36 @@
 //! [`to_be_some`]: crate::prelude::OptionAssertions::to_be_some
 //! [`to_equal`]: crate::prelude::GeneralAssertions::to_equal
 
+pub mod any;
 #[cfg(feature = "futures")]
 pub mod async_read;
+pub mod bytes;
+pub mod capacity;
+pub mod channels;
+pub mod cow;
+pub mod errors;
+pub mod functions;
 #[cfg(feature = "futures")]
 pub mod futures;
 pub mod general;
 pub mod iterators;
+pub mod laws;
+pub mod maps;
 pub mod options;
+pub mod paths;
+pub mod ranges;
 pub mod read;
 pub mod results;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod strings;
 
 mod assertion;
 mod context;
 mod error;
+mod report;
 
 pub use assertion::*;
 pub use context::*;
 pub use error::*;
+pub use report::*;
@@ -0,0 +1,19 @@
+//! Modifiers for dynamically typed values, like [`Box<dyn Any>`](std::any::Any).
+//!
+//! Any type implementing [`Downcast`] can be used as a subject.
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! use std::any::Any;
+//!
+//! let value: Box<dyn Any> = Box::new(1);
+//! expect!(value, to_downcast_to::<i32>, to_equal(Box::new(1)));
+//! ```
+
+mod downcast;
+mod extensions;
+mod modifiers;
+
+pub use downcast::*;
+pub use extensions::*;
+pub use modifiers::*;
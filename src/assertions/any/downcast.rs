@@ -0,0 +1,41 @@
+use std::any::Any;
+
+/// A dynamically typed value that can be downcast to a concrete type.
+pub trait Downcast: Sized {
+    /// The downcast value.
+    type Output<U: Any>;
+
+    /// Attempts to downcast the value to a concrete type, returning the
+    /// original value if the underlying value is a different type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the original value if the underlying value is not a `U`.
+    fn downcast<U: Any>(self) -> Result<Self::Output<U>, Self>;
+}
+
+macro_rules! impl_downcast {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<'a> Downcast for &'a $ty {
+                type Output<U: Any> = &'a U;
+
+                #[inline]
+                fn downcast<U: Any>(self) -> Result<Self::Output<U>, Self> {
+                    self.downcast_ref().ok_or(self)
+                }
+            }
+
+            impl Downcast for Box<$ty> {
+                type Output<U: Any> = Box<U>;
+
+                #[inline]
+                fn downcast<U: Any>(self) -> Result<Self::Output<U>, Self> {
+                    Box::<$ty>::downcast(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_downcast!(dyn Any, dyn Any + Send, dyn Any + Send + Sync);
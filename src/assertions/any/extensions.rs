@@ -0,0 +1,50 @@
+use std::any::Any;
+
+use crate::assertions::AssertionBuilder;
+
+use super::{Downcast, DowncastModifier};
+
+/// Modifiers for dynamically typed values.
+pub trait AnyAssertions<T, M>
+where
+    T: Downcast,
+{
+    /// Downcasts the subject to a concrete type, then executes the assertion
+    /// on the downcast value. A `Box<dyn Any>` is downcast to a `Box<U>`, and
+    /// a `&dyn Any` is downcast to a `&U`.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::any::Any;
+    ///
+    /// let value: Box<dyn Any> = Box::new(String::from("plugin"));
+    /// expect!(value, to_downcast_to::<String>, as_display, to_equal("plugin"));
+    /// ```
+    ///
+    /// The assertion fails if the subject is a different type:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::any::Any;
+    ///
+    /// let value: Box<dyn Any> = Box::new(1);
+    /// expect!(value, to_downcast_to::<String>, as_display, to_equal("1"));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn to_downcast_to<U>(self) -> AssertionBuilder<T::Output<U>, DowncastModifier<M, U>>
+    where
+        U: Any;
+}
+
+impl<T, M> AnyAssertions<T, M> for AssertionBuilder<T, M>
+where
+    T: Downcast,
+{
+    #[inline]
+    fn to_downcast_to<U>(self) -> AssertionBuilder<T::Output<U>, DowncastModifier<M, U>>
+    where
+        U: Any,
+    {
+        AssertionBuilder::modify(self, DowncastModifier::new)
+    }
+}
@@ -0,0 +1,3 @@
+mod to_downcast_to;
+
+pub use to_downcast_to::*;
@@ -0,0 +1,97 @@
+use std::{
+    any::{type_name, Any},
+    marker::PhantomData,
+};
+
+use crate::assertions::{
+    any::Downcast, general::IntoInitializableOutput, Assertion, AssertionContext,
+    AssertionContextBuilder, AssertionModifier,
+};
+
+/// Downcasts the subject to a concrete type.
+#[derive(Clone, Debug)]
+pub struct DowncastModifier<M, U> {
+    prev: M,
+    target: PhantomData<fn() -> U>,
+}
+
+impl<M, U> DowncastModifier<M, U> {
+    #[inline]
+    pub(crate) fn new(prev: M) -> Self {
+        Self {
+            prev,
+            target: PhantomData,
+        }
+    }
+}
+
+impl<M, U, A> AssertionModifier<A> for DowncastModifier<M, U>
+where
+    M: AssertionModifier<DowncastAssertion<A, U>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            DowncastAssertion {
+                next,
+                target: PhantomData,
+            },
+        )
+    }
+}
+
+/// Downcasts the subject to a concrete type and executes the inner assertion on
+/// it. Fails if the subject is a different type.
+#[derive(Clone, Debug)]
+pub struct DowncastAssertion<A, U> {
+    next: A,
+    target: PhantomData<fn() -> U>,
+}
+
+impl<A, T, U> Assertion<T> for DowncastAssertion<A, U>
+where
+    A: Assertion<T::Output<U>, Output: IntoInitializableOutput>,
+    T: Downcast,
+    U: Any,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("target type", type_name::<U>());
+        match subject.downcast::<U>() {
+            Ok(value) => self.next.execute(cx, value).into_initialized(),
+            Err(_) => cx.fail("value is a different type"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn downcasts_boxes_and_references() {
+        let boxed: Box<dyn Any + Send> = Box::new(String::from("hi"));
+        expect!(boxed, to_downcast_to::<String>, as_display, to_equal("hi"));
+
+        let value = 1u8;
+        let value: &dyn Any = &value;
+        expect!(value, to_downcast_to::<u8>, to_equal(&1));
+    }
+
+    #[test]
+    fn target_type_is_annotated() {
+        let boxed: Box<dyn Any> = Box::new(1i32);
+        expect!(
+            try_expect!(boxed, to_downcast_to::<u32>, to_equal(Box::new(1))),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("target type: u32"),
+        );
+    }
+}
@@ -22,6 +22,19 @@ pub trait Assertion<T> {
     /// The output type from executing this assertion.
     type Output;
 
+    /// Whether [`is_determined_by`](Assertion::is_determined_by) can ever
+    /// return `true`.
+    ///
+    /// Modifiers that produce increasing values, like [`count`], check this
+    /// before calling [`is_determined_by`](Assertion::is_determined_by) so
+    /// assertions that can't short-circuit don't slow down the modifier. This
+    /// should be `true` for any assertion that overrides
+    /// [`is_determined_by`](Assertion::is_determined_by), and modifiers that
+    /// forward that method should forward this constant as well.
+    ///
+    /// [`count`]: crate::prelude::IteratorAssertions::count
+    const CAN_SHORT_CIRCUIT: bool = false;
+
     /// Executes this assertion on a given subject.
     fn execute(self, cx: AssertionContext, subject: T) -> Self::Output;
 
@@ -43,6 +56,22 @@ pub trait Assertion<T> {
     {
         self.execute(cx, subject.into_inner())
     }
+
+    /// Gets whether the result of this assertion is already determined by the
+    /// given subject. If this returns `true`, then executing this assertion on
+    /// `subject` or any value greater than `subject` must produce the same
+    /// result.
+    ///
+    /// Modifiers that produce increasing values, like [`count`], can use this
+    /// to stop early. By default, this returns `false`, which is always
+    /// correct. Modifiers that pass their subject through unchanged (or only
+    /// transform the output) should forward this to the inner assertion.
+    ///
+    /// [`count`]: crate::prelude::IteratorAssertions::count
+    #[inline]
+    fn is_determined_by(&self, _subject: &T) -> bool {
+        false
+    }
 }
 
 /// Modifies an assertion.
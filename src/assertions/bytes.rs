@@ -0,0 +1,17 @@
+//! Assertions and modifiers for tests that involve byte slices.
+//!
+//! These work directly on any subject that can be borrowed as a `[u8]`, like
+//! `&[u8]` and `Vec<u8>`, without needing to iterate over the bytes.
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! expect!(b"Hello!", to_be_valid_utf8);
+//! ```
+
+mod assertions;
+mod extensions;
+mod modifiers;
+
+pub use assertions::*;
+pub use extensions::*;
+pub use modifiers::*;
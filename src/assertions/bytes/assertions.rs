@@ -0,0 +1,5 @@
+mod to_be_valid_utf8;
+mod to_have_byte_affix;
+
+pub use to_be_valid_utf8::*;
+pub use to_have_byte_affix::*;
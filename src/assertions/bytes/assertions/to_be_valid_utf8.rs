@@ -0,0 +1,49 @@
+use std::str::from_utf8;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// Asserts that the subject is a valid UTF-8 byte sequence.
+#[derive(Clone, Debug)]
+pub struct ToBeValidUtf8 {}
+
+impl ToBeValidUtf8 {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T> Assertion<T> for ToBeValidUtf8
+where
+    T: AsRef<[u8]>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let Err(error) = from_utf8(subject.as_ref()) else {
+            return cx.pass();
+        };
+
+        cx.annotate("offset", error.valid_up_to());
+        cx.fail("invalid utf8")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn offset_is_annotated() {
+        expect!(Vec::<u8>::new(), to_be_valid_utf8);
+        expect!(
+            try_expect!(b"ab\xF0\xA4c", to_be_valid_utf8),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("offset: 2"),
+        );
+    }
+}
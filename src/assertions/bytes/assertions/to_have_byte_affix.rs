@@ -0,0 +1,90 @@
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject starts or ends with the given bytes.
+#[derive(Clone, Debug)]
+pub struct ToHaveByteAffix<P> {
+    affix: Annotated<P>,
+    location: AffixLocation,
+}
+
+impl<P> ToHaveByteAffix<P> {
+    #[inline]
+    pub(crate) fn new(affix: Annotated<P>, location: AffixLocation) -> Self {
+        Self { affix, location }
+    }
+}
+
+impl<P, T> Assertion<T> for ToHaveByteAffix<P>
+where
+    P: AsRef<[u8]>,
+    T: AsRef<[u8]>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let affix = self.affix.inner().as_ref();
+        cx.annotate("expected", format_args!("{affix:?}"));
+
+        let subject = subject.as_ref();
+        if subject.len() < affix.len() {
+            cx.annotate("length", subject.len());
+            return cx.fail("subject is shorter than the expected bytes");
+        }
+
+        // Offsets are relative to the start of the subject
+        let start = match self.location {
+            AffixLocation::Start => 0,
+            AffixLocation::End => subject.len() - affix.len(),
+        };
+        let mismatch = subject[start..]
+            .iter()
+            .zip(affix)
+            .position(|(actual, expected)| actual != expected);
+        let Some(idx) = mismatch else {
+            return cx.pass();
+        };
+
+        cx.annotate("offset", start + idx);
+        cx.annotate("actual", format_args!("{:#04x}", subject[start + idx]));
+        cx.fail("bytes not equal")
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum AffixLocation {
+    Start,
+    End,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn mismatch_offset_is_annotated() {
+        expect!(b"\x01\x02\x03", to_start_with_bytes(b""));
+        expect!(b"\x01\x02\x03", to_end_with_bytes([2, 3]));
+        expect!(
+            try_expect!(b"\x01\x02\x03\x04", to_start_with_bytes([1, 2, 4])),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("offset: 2"),
+        );
+        expect!(
+            try_expect!(b"\x01\x02\x03\x04", to_end_with_bytes([9, 3, 4])),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("offset: 1"),
+        );
+        expect!(
+            try_expect!(b"\x01", to_end_with_bytes([0, 1])),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("length: 1"),
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{assertions::AssertionBuilder, metadata::Annotated};
+
+use super::{AffixLocation, AsStrLossyModifier, ToBeValidUtf8, ToHaveByteAffix};
+
+/// Assertions and modifiers for byte slices.
+pub trait BytesAssertions<T, M>
+where
+    T: AsRef<[u8]>,
+{
+    /// Converts the subject to a string, replacing any invalid UTF-8 sequences
+    /// with [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER).
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(b"Hello\xFF", as_str_lossy, to_equal("Hello\u{FFFD}"));
+    /// ```
+    ///
+    /// To fail on invalid UTF-8 sequences instead, use
+    /// [`as_utf8`](crate::prelude::IteratorAssertions::as_utf8).
+    #[allow(clippy::wrong_self_convention)]
+    fn as_str_lossy(self) -> AssertionBuilder<String, AsStrLossyModifier<M>>;
+
+    /// Asserts that the subject is a valid UTF-8 byte sequence.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(b"Hello!", to_be_valid_utf8);
+    /// ```
+    ///
+    /// The assertion fails if the subject contains invalid UTF-8 sequences. The
+    /// byte offset of the first invalid sequence is included in the failure
+    /// message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(b"Hello\xFF", to_be_valid_utf8);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_valid_utf8(&self) -> ToBeValidUtf8 {
+        ToBeValidUtf8::new()
+    }
+
+    /// Asserts that the subject starts with the given bytes.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(b"\x89PNG\r\n", to_start_with_bytes(b"\x89PNG"));
+    /// ```
+    ///
+    /// The assertion fails if the subject does not start with the bytes. The
+    /// offset of the first mismatching byte is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(b"GIF89a", to_start_with_bytes(b"\x89PNG"));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_start_with_bytes<P>(&self, prefix: Annotated<P>) -> ToHaveByteAffix<P>
+    where
+        P: AsRef<[u8]>,
+    {
+        ToHaveByteAffix::new(prefix, AffixLocation::Start)
+    }
+
+    /// Asserts that the subject ends with the given bytes.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(b"data\r\n", to_end_with_bytes(b"\r\n"));
+    /// ```
+    ///
+    /// The assertion fails if the subject does not end with the bytes. The
+    /// offset of the first mismatching byte is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(b"data\n", to_end_with_bytes(b"\r\n"));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_end_with_bytes<P>(&self, suffix: Annotated<P>) -> ToHaveByteAffix<P>
+    where
+        P: AsRef<[u8]>,
+    {
+        ToHaveByteAffix::new(suffix, AffixLocation::End)
+    }
+}
+
+impl<T, M> BytesAssertions<T, M> for AssertionBuilder<T, M>
+where
+    T: AsRef<[u8]>,
+{
+    #[inline]
+    fn as_str_lossy(self) -> AssertionBuilder<String, AsStrLossyModifier<M>> {
+        AssertionBuilder::modify(self, AsStrLossyModifier::new)
+    }
+}
@@ -0,0 +1,3 @@
+mod as_str_lossy;
+
+pub use as_str_lossy::*;
@@ -0,0 +1,59 @@
+use crate::assertions::{Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier};
+
+/// Converts the subject to a string, replacing invalid UTF-8 sequences.
+#[derive(Clone, Debug)]
+pub struct AsStrLossyModifier<M> {
+    prev: M,
+}
+
+impl<M> AsStrLossyModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M) -> Self {
+        Self { prev }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for AsStrLossyModifier<M>
+where
+    M: AssertionModifier<AsStrLossyAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(cx, AsStrLossyAssertion { next })
+    }
+}
+
+/// Converts the subject to a string, replacing invalid UTF-8 sequences with
+/// [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER), then
+/// executes the inner assertion on it.
+#[derive(Clone, Debug)]
+pub struct AsStrLossyAssertion<A> {
+    next: A,
+}
+
+impl<A, T> Assertion<T> for AsStrLossyAssertion<A>
+where
+    A: Assertion<String>,
+    T: AsRef<[u8]>,
+{
+    type Output = A::Output;
+
+    #[inline]
+    fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
+        let subject = String::from_utf8_lossy(subject.as_ref()).into_owned();
+        self.next.execute(cx, subject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn invalid_sequences_are_replaced() {
+        expect!(b"ab\xFFc", as_str_lossy, to_equal("ab\u{FFFD}c"));
+        expect!(vec![b'a'], as_str_lossy, to_equal("a"));
+    }
+}
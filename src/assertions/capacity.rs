@@ -0,0 +1,18 @@
+//! Assertions and modifiers for tests that involve the capacity of
+//! collections.
+//!
+//! These are useful for verifying that code preallocates the storage it
+//! claims to. Any type implementing [`HasCapacity`] can be used as a subject.
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! expect!(Vec::<i32>::with_capacity(10), to_have_capacity_at_least(10));
+//! ```
+
+mod assertions;
+mod extensions;
+mod has_capacity;
+
+pub use assertions::*;
+pub use extensions::*;
+pub use has_capacity::*;
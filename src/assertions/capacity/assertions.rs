@@ -0,0 +1,3 @@
+mod to_have_capacity_at_least;
+
+pub use to_have_capacity_at_least::*;
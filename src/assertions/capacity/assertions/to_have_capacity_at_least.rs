@@ -0,0 +1,63 @@
+use crate::{
+    assertions::{capacity::HasCapacity, Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject can hold at least a certain number of elements
+/// without reallocating.
+#[derive(Clone, Debug)]
+pub struct ToHaveCapacityAtLeast {
+    min: Annotated<usize>,
+}
+
+impl ToHaveCapacityAtLeast {
+    #[inline]
+    pub(crate) fn new(min: Annotated<usize>) -> Self {
+        Self { min }
+    }
+}
+
+impl<T> Assertion<T> for ToHaveCapacityAtLeast
+where
+    T: HasCapacity,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let min = self.min.into_inner();
+        let capacity = subject.capacity();
+        cx.annotate("capacity", capacity);
+        cx.annotate("min", min);
+
+        cx.pass_if(capacity >= min, "capacity is less than the minimum")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, VecDeque};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn reserved_capacity_passes() {
+        expect!(Vec::<u8>::with_capacity(8), to_have_capacity_at_least(8));
+        let text = String::with_capacity(4);
+        expect!(&text, to_have_capacity_at_least(1));
+        expect!(VecDeque::<u8>::new(), to_have_capacity_at_least(0));
+        expect!(
+            HashMap::<u8, u8>::with_capacity(3),
+            to_have_capacity_at_least(3)
+        );
+    }
+
+    #[test]
+    fn capacity_is_annotated() {
+        let message = try_expect!(Vec::<u8>::new(), to_have_capacity_at_least(1))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("capacity: 0"));
+        expect!(&message, to_contain_substr("min: 1"));
+    }
+}
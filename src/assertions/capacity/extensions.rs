@@ -0,0 +1,31 @@
+use crate::{assertions::AssertionBuilder, metadata::Annotated};
+
+use super::{HasCapacity, ToHaveCapacityAtLeast};
+
+/// Assertions and modifiers for the capacity of collections.
+pub trait CapacityAssertions<T, M>
+where
+    T: HasCapacity,
+{
+    /// Asserts that the subject can hold at least `min` elements without
+    /// reallocating.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(String::with_capacity(16), to_have_capacity_at_least(16));
+    /// ```
+    ///
+    /// The assertion fails if the subject's capacity is less than `min`. The
+    /// actual capacity is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(Vec::<i32>::new(), to_have_capacity_at_least(1));
+    /// ```
+    #[inline]
+    fn to_have_capacity_at_least(&self, min: Annotated<usize>) -> ToHaveCapacityAtLeast {
+        ToHaveCapacityAtLeast::new(min)
+    }
+}
+
+impl<T, M> CapacityAssertions<T, M> for AssertionBuilder<T, M> where T: HasCapacity {}
@@ -0,0 +1,57 @@
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    ffi::OsString,
+    path::PathBuf,
+};
+
+/// A collection that can report how many elements it can hold without
+/// reallocating.
+pub trait HasCapacity {
+    /// Gets the number of elements the collection can hold without
+    /// reallocating.
+    fn capacity(&self) -> usize;
+}
+
+impl<T> HasCapacity for &T
+where
+    T: HasCapacity + ?Sized,
+{
+    #[inline]
+    fn capacity(&self) -> usize {
+        T::capacity(self)
+    }
+}
+
+impl<T> HasCapacity for &mut T
+where
+    T: HasCapacity + ?Sized,
+{
+    #[inline]
+    fn capacity(&self) -> usize {
+        T::capacity(self)
+    }
+}
+
+macro_rules! impl_has_capacity {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> HasCapacity for $ty {
+                #[inline]
+                fn capacity(&self) -> usize {
+                    <$ty>::capacity(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_has_capacity!(
+    [] String,
+    [] OsString,
+    [] PathBuf,
+    [T] Vec<T>,
+    [T] VecDeque<T>,
+    [T] BinaryHeap<T>,
+    [K, V, S] HashMap<K, V, S>,
+    [T, S] HashSet<T, S>,
+);
@@ -0,0 +1,19 @@
+//! Assertions and modifiers for tests that involve channels.
+//!
+//! These are useful for multi-threaded tests that communicate results back to
+//! the test thread through a [`Receiver`](std::sync::mpsc::Receiver).
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! use std::{sync::mpsc::channel, thread::spawn, time::Duration};
+//!
+//! let (tx, rx) = channel();
+//! spawn(move || tx.send(1).unwrap());
+//! expect!(rx, to_receive_within(Duration::from_secs(1)), to_equal(1));
+//! ```
+
+mod extensions;
+mod modifiers;
+
+pub use extensions::*;
+pub use modifiers::*;
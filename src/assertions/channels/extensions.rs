@@ -0,0 +1,45 @@
+use std::{sync::mpsc::Receiver, time::Duration};
+
+use crate::{assertions::AssertionBuilder, metadata::Annotated};
+
+use super::ReceiveWithinModifier;
+
+/// Assertions and modifiers for channel [`Receiver`]s.
+pub trait ChannelAssertions<T, M> {
+    /// Waits for the subject to receive a value, then continues the assertion
+    /// with the received value.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::{sync::mpsc::channel, thread::spawn, time::Duration};
+    ///
+    /// let (tx, rx) = channel();
+    /// spawn(move || tx.send(1).unwrap());
+    /// expect!(rx, to_receive_within(Duration::from_secs(1)), to_equal(1));
+    /// ```
+    ///
+    /// The assertion fails if no value is received before the timeout, or if
+    /// the channel is disconnected:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::{sync::mpsc::channel, time::Duration};
+    ///
+    /// let (_tx, rx) = channel::<i32>();
+    /// expect!(rx, to_receive_within(Duration::from_millis(1)), to_equal(1));
+    /// ```
+    fn to_receive_within(
+        self,
+        timeout: Annotated<Duration>,
+    ) -> AssertionBuilder<T, ReceiveWithinModifier<M>>;
+}
+
+impl<T, M> ChannelAssertions<T, M> for AssertionBuilder<Receiver<T>, M> {
+    #[inline]
+    fn to_receive_within(
+        self,
+        timeout: Annotated<Duration>,
+    ) -> AssertionBuilder<T, ReceiveWithinModifier<M>> {
+        AssertionBuilder::modify(self, move |prev| ReceiveWithinModifier::new(prev, timeout))
+    }
+}
@@ -0,0 +1,3 @@
+mod receive_within;
+
+pub use receive_within::*;
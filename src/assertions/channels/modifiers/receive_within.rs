@@ -0,0 +1,112 @@
+use std::{
+    sync::mpsc::{Receiver, RecvTimeoutError},
+    time::Duration,
+};
+
+use crate::{
+    assertions::{
+        general::IntoInitializableOutput, Assertion, AssertionContext, AssertionContextBuilder,
+        AssertionModifier,
+    },
+    metadata::Annotated,
+};
+
+/// Receives a value from the subject within a time limit.
+#[derive(Clone, Debug)]
+pub struct ReceiveWithinModifier<M> {
+    prev: M,
+    timeout: Annotated<Duration>,
+}
+
+impl<M> ReceiveWithinModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M, timeout: Annotated<Duration>) -> Self {
+        Self { prev, timeout }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for ReceiveWithinModifier<M>
+where
+    M: AssertionModifier<ReceiveWithinAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            ReceiveWithinAssertion {
+                next,
+                timeout: self.timeout,
+            },
+        )
+    }
+}
+
+/// Executes the inner assertion on the value received from the subject if a
+/// value is received within the time limit.
+#[derive(Clone, Debug)]
+pub struct ReceiveWithinAssertion<A> {
+    next: A,
+    timeout: Annotated<Duration>,
+}
+
+impl<A, T> Assertion<Receiver<T>> for ReceiveWithinAssertion<A>
+where
+    A: Assertion<T, Output: IntoInitializableOutput>,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    fn execute(self, mut cx: AssertionContext, subject: Receiver<T>) -> Self::Output {
+        let timeout = *self.timeout.inner();
+        cx.annotate("timeout", self.timeout);
+
+        match subject.recv_timeout(timeout) {
+            Ok(value) => self.next.execute(cx, value).into_initialized(),
+            Err(RecvTimeoutError::Timeout) => cx.fail("timed out waiting for a value"),
+            Err(RecvTimeoutError::Disconnected) => cx.fail("channel disconnected"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc::channel, thread::spawn, time::Duration};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn value_from_thread_is_received() {
+        let (tx, rx) = channel();
+        let handle = spawn(move || tx.send(1).unwrap());
+        expect!(rx, to_receive_within(Duration::from_secs(5)), to_equal(1));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn timeout_fails() {
+        let (_tx, rx) = channel::<i32>();
+        expect!(
+            try_expect!(
+                rx,
+                to_receive_within(Duration::from_millis(10)),
+                to_equal(1)
+            ),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("timed out"),
+        );
+    }
+
+    #[test]
+    fn disconnect_fails() {
+        let (tx, rx) = channel::<i32>();
+        drop(tx);
+        expect!(
+            rx,
+            not,
+            to_receive_within(Duration::from_secs(5)),
+            to_equal(1),
+        );
+    }
+}
@@ -30,6 +30,8 @@ pub struct AssertionContext {
     pub(crate) visited: Vec<ContextFrame>,
     pub(crate) remaining: &'static [&'static str],
     pub(crate) recovered: Vec<ContextFrame>,
+    pub(crate) message: Option<String>,
+    pub(crate) inverted: bool,
 }
 
 impl AssertionContext {
@@ -47,6 +49,8 @@ impl AssertionContext {
                 visited: vec![],
                 remaining: frames,
                 recovered: vec![],
+                message: None,
+                inverted: false,
             },
         }
     }
@@ -162,6 +166,16 @@ impl AssertionContext {
         self.source_loc
     }
 
+    /// Gets whether a success on this execution path is turned into a failure
+    /// by a modifier like [`not`](crate::prelude::GeneralAssertions::not).
+    ///
+    /// Assertions can use this to skip formatting values that are only needed
+    /// to explain a failure.
+    #[inline]
+    pub(crate) fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
     /// Recovers missing frames from another context.
     ///
     /// The recovered frames are used to provide additional information on what
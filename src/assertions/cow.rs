@@ -0,0 +1,21 @@
+//! Modifiers for copy-on-write values, like [`Cow<str>`](std::borrow::Cow) and
+//! `Cow<[T]>`.
+//!
+//! Assertions that only need to borrow the subject, like string assertions,
+//! already work with [`Cow`](std::borrow::Cow) subjects. Other assertions, like
+//! iterator assertions, need the subject to be converted into its owned form
+//! first. Both the borrowed and owned variants are converted the same way.
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! use std::borrow::Cow;
+//!
+//! let items: Cow<[i32]> = Cow::Borrowed(&[1, 2, 3]);
+//! expect!(items, into_owned, to_contain(2));
+//! ```
+
+mod extensions;
+mod modifiers;
+
+pub use extensions::*;
+pub use modifiers::*;
@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use crate::assertions::AssertionBuilder;
+
+use super::IntoOwnedModifier;
+
+/// Modifiers for copy-on-write values.
+pub trait CowAssertions<'a, B, M>
+where
+    B: ?Sized + ToOwned + 'a,
+{
+    /// Converts the subject into its owned form, then executes the assertion
+    /// on the owned value. A `Cow<str>` becomes a [`String`], and a `Cow<[T]>`
+    /// becomes a [`Vec<T>`]. Borrowed values are cloned, and owned values are
+    /// moved.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::borrow::Cow;
+    ///
+    /// let borrowed: Cow<[i32]> = Cow::Borrowed(&[1, 2, 3]);
+    /// expect!(borrowed, into_owned, to_contain_exactly([1, 2, 3]));
+    ///
+    /// let owned: Cow<[i32]> = Cow::Owned(vec![1, 2, 3]);
+    /// expect!(owned, into_owned, all, to_be_less_than(4));
+    /// ```
+    ///
+    /// The assertion fails if the owned value does not satisfy the assertion:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::borrow::Cow;
+    ///
+    /// let items: Cow<[i32]> = Cow::Borrowed(&[1, 2, 3]);
+    /// expect!(items, into_owned, to_contain(4));
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn into_owned(self) -> AssertionBuilder<B::Owned, IntoOwnedModifier<M>>;
+}
+
+impl<'a, B, M> CowAssertions<'a, B, M> for AssertionBuilder<Cow<'a, B>, M>
+where
+    B: ?Sized + ToOwned + 'a,
+{
+    #[inline]
+    fn into_owned(self) -> AssertionBuilder<B::Owned, IntoOwnedModifier<M>> {
+        AssertionBuilder::modify(self, IntoOwnedModifier::new)
+    }
+}
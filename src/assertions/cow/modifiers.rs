@@ -0,0 +1,3 @@
+mod into_owned;
+
+pub use into_owned::*;
@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+
+use crate::assertions::{Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier};
+
+/// Converts the subject into its owned form.
+#[derive(Clone, Debug)]
+pub struct IntoOwnedModifier<M> {
+    prev: M,
+}
+
+impl<M> IntoOwnedModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M) -> Self {
+        Self { prev }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for IntoOwnedModifier<M>
+where
+    M: AssertionModifier<IntoOwnedAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(cx, IntoOwnedAssertion { next })
+    }
+}
+
+/// Executes the inner assertion on the owned form of the subject.
+#[derive(Clone, Debug)]
+pub struct IntoOwnedAssertion<A> {
+    next: A,
+}
+
+impl<'a, A, B> Assertion<Cow<'a, B>> for IntoOwnedAssertion<A>
+where
+    A: Assertion<B::Owned>,
+    B: ?Sized + ToOwned + 'a,
+{
+    type Output = A::Output;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: Cow<'a, B>) -> Self::Output {
+        let variant = match subject {
+            Cow::Borrowed(_) => "borrowed",
+            Cow::Owned(_) => "owned",
+        };
+        cx.annotate("variant", variant);
+        self.next.execute(cx, subject.into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(Cow::Borrowed("hello world"); "borrowed")]
+    #[test_case(Cow::Owned("hello world".to_string()); "owned")]
+    fn strings_behave_identically(subject: Cow<'static, str>) {
+        expect!(&subject, to_contain_substr("lo wo"));
+        expect!(&subject, to_have_byte_length(11));
+        expect!(subject, into_owned, to_equal("hello world"));
+    }
+
+    #[test_case(Cow::Borrowed(&[1, 2, 3]); "borrowed")]
+    #[test_case(Cow::Owned(vec![1, 2, 3]); "owned")]
+    fn slices_behave_identically(subject: Cow<'static, [i32]>) {
+        expect!(subject.clone(), into_owned, to_contain(2));
+        expect!(subject, into_owned, count, to_equal(3));
+    }
+}
@@ -3,7 +3,7 @@ use std::{
     fmt::{Debug, Display, Formatter},
 };
 
-use crate::{assertions::ContextFrame, styles};
+use crate::{assertions::ContextFrame, metadata::SourceLoc, styles};
 
 use super::AssertionContext;
 
@@ -40,6 +40,61 @@ impl AssertionOutput {
         self.error.is_none()
     }
 
+    /// Gets the string representation of the subject of the assertion.
+    #[inline]
+    #[must_use]
+    pub fn subject(&self) -> &str {
+        &self.cx.subject
+    }
+
+    /// Gets the location in the source code where the assertion was created.
+    #[inline]
+    #[must_use]
+    pub fn source_loc(&self) -> SourceLoc {
+        self.cx.source_loc
+    }
+
+    /// Calls a function if this output indicates a success. This is useful for
+    /// performing side effects, like recording metrics, without needing to
+    /// branch on the result.
+    #[inline]
+    pub fn on_pass(self, f: impl FnOnce()) -> Self {
+        if self.is_pass() {
+            f();
+        }
+
+        self
+    }
+
+    /// Calls a function with the failure message if this output indicates a
+    /// failure.
+    #[inline]
+    pub fn on_fail(self, f: impl FnOnce(&str)) -> Self {
+        if let Some(message) = self.error() {
+            f(message);
+        }
+
+        self
+    }
+
+    /// Gets the context of this output.
+    #[inline]
+    pub(crate) fn cx(&self) -> &AssertionContext {
+        &self.cx
+    }
+
+    /// Gets the failure message of this output, if any.
+    #[inline]
+    pub(crate) fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Sets the custom message shown at the top of the failure message.
+    #[inline]
+    pub(crate) fn set_message(&mut self, message: String) {
+        self.cx.message = Some(message);
+    }
+
     /// Sets the state of this output to a pass. This overrides the context of
     /// the result.
     #[inline]
@@ -111,7 +166,10 @@ impl Counter {
 
 impl Display for AssertionError {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        writeln!(f, "assertion failed:")?;
+        match &self.cx.message {
+            Some(message) => writeln!(f, "assertion failed: {message}")?,
+            None => writeln!(f, "assertion failed:")?,
+        }
         writeln!(
             f,
             "  {}",
@@ -126,11 +184,10 @@ impl Display for AssertionError {
 
         // Write frames
         writeln!(f, "steps:")?;
-        let mut idx = 0;
         let mut pages = Vec::new();
         let frames = self.cx.visited.iter().chain(self.cx.recovered.iter());
         let mut reference_idxs = Counter(1);
-        for frame in frames {
+        for (idx, frame) in frames.enumerate() {
             let mut comment_parts = Vec::new();
 
             // Additional pages
@@ -158,14 +215,12 @@ impl Display for AssertionError {
                 format!(" {}", comment_parts.join(" "))
             };
             write_frame(f, frame, &comment)?;
-            idx += 1;
         }
 
         // Write non-visited frames
         for frame in &self.cx.remaining[self.cx.recovered.len()..] {
             writeln!(f, "  {frame}: {}", styles::dimmed(&"(not visited)"))?;
             writeln!(f)?;
-            idx += 1;
         }
 
         // Write context pages
@@ -205,4 +260,29 @@ mod tests {
         expect!(&message, to_contain_substr("second"));
         expect!(message.matches("[1]"), count, to_equal(3));
     }
+
+    #[test]
+    fn passing_output_has_metadata() {
+        let cx = AssertionContext::__new("1 + 1".into(), crate::source_loc!(), &["a"]).inner;
+        let output: AssertionOutput = cx.next().pass();
+
+        expect!(output.is_pass(), to_equal(true));
+        expect!(output.subject(), to_equal("1 + 1"));
+        expect!(output.source_loc().file(), to_equal(file!()));
+    }
+
+    #[test]
+    fn callbacks_match_outcome() {
+        let cx = AssertionContext::__new(String::new(), crate::source_loc!(), &["a"]).inner;
+        let mut passed = false;
+        let mut failed = None;
+        let output: AssertionOutput = cx.next().fail("test failure");
+        let output = output
+            .on_pass(|| passed = true)
+            .on_fail(|message| failed = Some(message.to_string()));
+
+        expect!(output.is_pass(), to_equal(false));
+        expect!(passed, to_equal(false));
+        expect!(failed, to_be_some_and, to_equal("test failure"));
+    }
 }
@@ -0,0 +1,17 @@
+//! Assertions for tests that involve [`Error`](std::error::Error)s.
+//!
+//! These assertions inspect the full chain of errors returned by
+//! [`Error::source`](std::error::Error::source), which makes them useful with
+//! error types that wrap other errors.
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! let error = std::fmt::Error;
+//! expect!(error, to_have_error_source_containing("error"));
+//! ```
+
+mod assertions;
+mod extensions;
+
+pub use assertions::*;
+pub use extensions::*;
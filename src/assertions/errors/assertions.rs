@@ -0,0 +1,3 @@
+mod to_have_error_source_containing;
+
+pub use to_have_error_source_containing::*;
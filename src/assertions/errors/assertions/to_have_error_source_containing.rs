@@ -0,0 +1,122 @@
+use std::{error::Error, fmt::Write};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that an error or any of its sources contains the given substring in
+/// its [`Display`](std::fmt::Display) representation.
+#[derive(Clone, Debug)]
+pub struct ToHaveErrorSourceContaining<P> {
+    pattern: Annotated<P>,
+}
+
+impl<P> ToHaveErrorSourceContaining<P> {
+    #[inline]
+    pub(crate) fn new(pattern: Annotated<P>) -> Self {
+        Self { pattern }
+    }
+}
+
+impl<P, T> Assertion<T> for ToHaveErrorSourceContaining<P>
+where
+    P: AsRef<str>,
+    T: Error,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let pattern = self.pattern.inner().as_ref();
+        cx.annotate("expected", format_args!("{pattern:?}"));
+
+        let mut chain = String::new();
+        let mut error: Option<&dyn Error> = Some(&subject);
+        let mut depth = 0;
+        while let Some(current) = error {
+            let message = current.to_string();
+            if message.contains(pattern) {
+                return cx.pass();
+            }
+
+            write!(chain, "\n  {depth}: {message}").unwrap();
+            error = current.source();
+            depth += 1;
+        }
+
+        cx.add_page("error chain", chain.trim_start_matches('\n'));
+        cx.fail("substring not found in any error in the chain")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        error::Error,
+        fmt::{Display, Formatter},
+    };
+
+    use crate::prelude::*;
+
+    #[derive(Debug)]
+    struct Wrapper {
+        message: &'static str,
+        source: Option<Box<Wrapper>>,
+    }
+
+    impl Wrapper {
+        fn chain(messages: &[&'static str]) -> Option<Box<Self>> {
+            let (&message, rest) = messages.split_first()?;
+            Some(Box::new(Self {
+                message,
+                source: Self::chain(rest),
+            }))
+        }
+    }
+
+    impl Display for Wrapper {
+        fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+            f.write_str(self.message)
+        }
+    }
+
+    impl Error for Wrapper {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            let source: &(dyn Error + 'static) = self.source.as_deref()?;
+            Some(source)
+        }
+    }
+
+    #[test]
+    fn sources_are_searched() {
+        let error = Wrapper::chain(&["reading config", "opening file", "file not found"]);
+        expect!(
+            &error,
+            to_be_some_and,
+            to_have_error_source_containing("reading")
+        );
+        expect!(
+            &error,
+            to_be_some_and,
+            to_have_error_source_containing("not found")
+        );
+        expect!(
+            error,
+            to_be_some_and,
+            not,
+            to_have_error_source_containing("permission denied"),
+        );
+    }
+
+    #[test]
+    fn chain_is_rendered_on_failure() {
+        let error = Wrapper::chain(&["reading config", "file not found"]).unwrap();
+        expect!(
+            try_expect!(error, to_have_error_source_containing("permission denied")),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("0: reading config\n  1: file not found"),
+        );
+    }
+}
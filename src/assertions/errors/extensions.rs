@@ -0,0 +1,42 @@
+use std::error::Error;
+
+use crate::{assertions::AssertionBuilder, metadata::Annotated};
+
+use super::ToHaveErrorSourceContaining;
+
+/// Assertions for values that implement [`Error`].
+pub trait ErrorAssertions<T, M>
+where
+    T: Error,
+{
+    /// Asserts that the subject or any error in its chain of
+    /// [sources](Error::source) contains the given substring in its
+    /// [`Display`](std::fmt::Display) representation.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let error = "abc".parse::<i32>().unwrap_err();
+    /// expect!(error, to_have_error_source_containing("invalid digit"));
+    /// ```
+    ///
+    /// The assertion fails if no error in the chain contains the substring. The
+    /// full chain of errors is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let error = "abc".parse::<i32>().unwrap_err();
+    /// expect!(error, to_have_error_source_containing("overflow"));
+    /// ```
+    #[inline]
+    fn to_have_error_source_containing<P>(
+        &self,
+        pattern: Annotated<P>,
+    ) -> ToHaveErrorSourceContaining<P>
+    where
+        P: AsRef<str>,
+    {
+        ToHaveErrorSourceContaining::new(pattern)
+    }
+}
+
+impl<T, M> ErrorAssertions<T, M> for AssertionBuilder<T, M> where T: Error {}
@@ -0,0 +1,20 @@
+//! Assertions and modifiers for tests that involve functions and closures.
+//!
+//! The subject of these assertions is usually a function that takes no
+//! arguments. The function is called when the assertion is executed.
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! use std::time::Duration;
+//! expect!(|| 1 + 1, to_complete_within(Duration::from_secs(1)));
+//! ```
+
+mod assertions;
+mod callable_mut;
+mod extensions;
+mod modifiers;
+
+pub use assertions::*;
+pub use callable_mut::*;
+pub use extensions::*;
+pub use modifiers::*;
@@ -0,0 +1,9 @@
+mod to_change_by;
+mod to_complete_within;
+#[cfg(feature = "regex")]
+mod to_panic_matching;
+
+pub use to_change_by::*;
+pub use to_complete_within::*;
+#[cfg(feature = "regex")]
+pub use to_panic_matching::*;
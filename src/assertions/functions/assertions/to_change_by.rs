@@ -0,0 +1,138 @@
+use std::{fmt::Debug, ops::Add};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that calling the subject changes a value by an expected amount.
+#[derive(Clone, Debug)]
+pub struct ToChangeBy<G, D> {
+    getter: Annotated<G>,
+    delta: Annotated<D>,
+}
+
+impl<G, D> ToChangeBy<G, D> {
+    #[inline]
+    pub(crate) fn new(getter: Annotated<G>, delta: Annotated<D>) -> Self {
+        Self { getter, delta }
+    }
+}
+
+impl<F, O, G, V, D> Assertion<F> for ToChangeBy<G, D>
+where
+    F: FnOnce() -> O,
+    G: FnMut() -> V,
+    V: Add<D, Output: PartialEq<V> + Debug> + Debug,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: F) -> Self::Output {
+        cx.annotate("getter", &self.getter);
+        cx.annotate("expected delta", &self.delta);
+
+        let mut getter = self.getter.into_inner();
+        let before = getter();
+        let _ = subject();
+        let after = getter();
+        cx.annotate("before", format_args!("{before:?}"));
+        cx.annotate("after", format_args!("{after:?}"));
+
+        // Adding to the first reading avoids underflowing unsigned values when
+        // the value decreases
+        let expected = before + self.delta.into_inner();
+        cx.annotate("expected after", format_args!("{expected:?}"));
+        cx.pass_if(expected == after, "changed by a different amount")
+    }
+}
+
+/// Asserts that calling the subject does not change a value.
+#[derive(Clone, Debug)]
+pub struct ToNotChange<G> {
+    getter: Annotated<G>,
+}
+
+impl<G> ToNotChange<G> {
+    #[inline]
+    pub(crate) fn new(getter: Annotated<G>) -> Self {
+        Self { getter }
+    }
+}
+
+impl<F, O, G, V> Assertion<F> for ToNotChange<G>
+where
+    F: FnOnce() -> O,
+    G: FnMut() -> V,
+    V: PartialEq + Debug,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: F) -> Self::Output {
+        cx.annotate("getter", &self.getter);
+
+        let mut getter = self.getter.into_inner();
+        let before = getter();
+        let _ = subject();
+        let after = getter();
+        if before == after {
+            return cx.pass();
+        }
+
+        cx.annotate("before", format_args!("{before:?}"));
+        cx.annotate("after", format_args!("{after:?}"));
+        cx.fail("value changed")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn delta_is_annotated() {
+        let counter = Cell::new(5);
+        expect!(
+            || counter.set(counter.get() + 2),
+            to_change_by(|| counter.get(), 2),
+        );
+
+        let message = try_expect!(
+            || counter.set(counter.get() - 1),
+            to_change_by(|| counter.get(), 1),
+        )
+        .unwrap_err()
+        .to_string();
+        expect!(&message, to_contain_substr("before: 7"));
+        expect!(&message, to_contain_substr("after: 6"));
+        expect!(&message, to_contain_substr("expected after: 8"));
+    }
+
+    #[test]
+    fn decreasing_unsigned_values_fail() {
+        let items = std::cell::RefCell::new(vec![1, 2, 3]);
+        let message = try_expect!(
+            || items.borrow_mut().pop(),
+            to_change_by(|| items.borrow().len(), 1),
+        )
+        .unwrap_err()
+        .to_string();
+        expect!(&message, to_contain_substr("before: 3"));
+        expect!(&message, to_contain_substr("after: 2"));
+        expect!(&message, to_contain_substr("expected after: 4"));
+    }
+
+    #[test]
+    fn unchanged_values_pass() {
+        let value = Cell::new(1);
+        expect!(|| value.set(value.get()), to_not_change(|| value.get()));
+
+        let message = try_expect!(|| value.set(3), to_not_change(|| value.get()))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("before: 1"));
+        expect!(&message, to_contain_substr("after: 3"));
+    }
+}
@@ -0,0 +1,38 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject completes within a time limit.
+#[derive(Clone, Debug)]
+pub struct ToCompleteWithin {
+    limit: Annotated<Duration>,
+}
+
+impl ToCompleteWithin {
+    #[inline]
+    pub(crate) fn new(limit: Annotated<Duration>) -> Self {
+        Self { limit }
+    }
+}
+
+impl<F, O> Assertion<F> for ToCompleteWithin
+where
+    F: FnOnce() -> O,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: F) -> Self::Output {
+        let start = Instant::now();
+        let _ = subject();
+        let elapsed = start.elapsed();
+
+        let passed = elapsed <= *self.limit.inner();
+        cx.annotate("limit", self.limit);
+        cx.annotate("elapsed", format!("{elapsed:?}"));
+        cx.pass_if(passed, "took too long")
+    }
+}
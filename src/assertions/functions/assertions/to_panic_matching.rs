@@ -0,0 +1,89 @@
+use std::{
+    any::Any,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Arc,
+};
+
+use regex::Regex;
+
+use crate::{
+    assertions::{strings::compile_regex, Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// Asserts that calling the subject panics with a message that matches a
+/// regular expression.
+#[derive(Clone, Debug)]
+pub struct ToPanicMatching {
+    regex: Arc<Regex>,
+}
+
+impl ToPanicMatching {
+    #[inline]
+    pub(crate) fn new(pattern: &str) -> Self {
+        Self {
+            regex: compile_regex(pattern),
+        }
+    }
+}
+
+impl<F, O> Assertion<F> for ToPanicMatching
+where
+    F: FnOnce() -> O,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: F) -> Self::Output {
+        cx.annotate("pattern", self.regex.as_str());
+
+        let Err(payload) = catch_unwind(AssertUnwindSafe(subject)) else {
+            return cx.fail("did not panic");
+        };
+
+        let Some(message) = panic_message(payload.as_ref()) else {
+            return cx.fail("panic payload is not a string");
+        };
+
+        cx.annotate("message", message);
+        cx.pass_if(
+            self.regex.is_match(message),
+            "panic message didn't match pattern",
+        )
+    }
+}
+
+/// Gets the message from a panic payload, if it is a string.
+fn panic_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::panic_any;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn formatted_messages_match() {
+        let id = 42;
+        expect!(
+            || panic!("user {id} not found"),
+            to_panic_matching(r"^user \d+ not found$"),
+        );
+    }
+
+    #[test]
+    fn failures_are_annotated() {
+        let message = try_expect!(|| panic!("oops"), to_panic_matching("^fatal"))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("message: oops"));
+        expect!(&message, to_contain_substr("didn't match pattern"));
+
+        expect!(|| 1 + 1, not, to_panic_matching(".*"));
+        expect!(|| panic_any(5), not, to_panic_matching(".*"));
+    }
+}
@@ -0,0 +1,48 @@
+/// A function that can be called repeatedly with a tuple of arguments.
+///
+/// This is implemented for [`FnMut`] functions and closures that take up to 12
+/// arguments. The arguments are passed as a tuple, so a function that takes no
+/// arguments is called with `()`, and a function that takes one argument is
+/// called with `(arg,)`.
+pub trait CallableMut<Args> {
+    /// The value returned by the function.
+    type Output;
+
+    /// Calls the function with the given arguments.
+    fn call_with(&mut self, args: Args) -> Self::Output;
+}
+
+macro_rules! impl_callable_mut {
+    ($(($($arg:ident $name:ident),*)),* $(,)?) => {
+        $(
+            impl<F, O, $($arg),*> CallableMut<($($arg,)*)> for F
+            where
+                F: FnMut($($arg),*) -> O,
+            {
+                type Output = O;
+
+                #[inline]
+                fn call_with(&mut self, args: ($($arg,)*)) -> Self::Output {
+                    let ($($name,)*) = args;
+                    self($($name),*)
+                }
+            }
+        )*
+    };
+}
+
+impl_callable_mut!(
+    (),
+    (A1 a1),
+    (A1 a1, A2 a2),
+    (A1 a1, A2 a2, A3 a3),
+    (A1 a1, A2 a2, A3 a3, A4 a4),
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5),
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6),
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7),
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8),
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9),
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10),
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10, A11 a11),
+    (A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10, A11 a11, A12 a12),
+);
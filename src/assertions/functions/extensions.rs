@@ -0,0 +1,288 @@
+use std::{fmt::Debug, ops::Add, time::Duration};
+
+use crate::{assertions::AssertionBuilder, metadata::Annotated};
+
+use super::{
+    CallableMut, CalledNTimesModifier, CompleteWithinModifier, ToChangeBy, ToCompleteWithin,
+    ToNotChange,
+};
+
+/// Assertions and modifiers for functions.
+pub trait FunctionAssertions<T, M> {
+    /// Asserts that the subject returns within a time limit.
+    ///
+    /// The subject is called once, and the wall-clock time it takes to return
+    /// is measured. The measured duration is included in failure messages.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::time::Duration;
+    /// expect!(|| 1 + 1, to_complete_within(Duration::from_secs(1)));
+    /// ```
+    ///
+    /// The assertion fails if the subject takes too long:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::{thread::sleep, time::Duration};
+    /// expect!(
+    ///     || sleep(Duration::from_millis(50)),
+    ///     to_complete_within(Duration::from_millis(1)),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_complete_within<O>(&self, limit: Annotated<Duration>) -> ToCompleteWithin
+    where
+        T: FnOnce() -> O,
+    {
+        ToCompleteWithin::new(limit)
+    }
+
+    /// Asserts that calling the subject changes a value by an expected amount.
+    /// The value is read with a getter before and after the subject is called,
+    /// and the second reading must equal the first reading plus the expected
+    /// amount.
+    ///
+    /// The operation is the subject rather than the value being changed. The
+    /// subject is only evaluated once, before any assertions run, so it can't
+    /// be read again after the operation. Reading the value through a getter
+    /// lets both readings be taken around the call.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::cell::Cell;
+    ///
+    /// let counter = Cell::new(0);
+    /// expect!(
+    ///     || counter.set(counter.get() + 1),
+    ///     to_change_by(|| counter.get(), 1),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the value changes by a different amount. Both
+    /// readings are included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::cell::Cell;
+    ///
+    /// let counter = Cell::new(0);
+    /// expect!(
+    ///     || counter.set(counter.get() + 2),
+    ///     to_change_by(|| counter.get(), 1),
+    /// );
+    /// ```
+    #[inline]
+    fn to_change_by<O, G, V, D>(
+        &self,
+        getter: Annotated<G>,
+        delta: Annotated<D>,
+    ) -> ToChangeBy<G, D>
+    where
+        T: FnOnce() -> O,
+        G: FnMut() -> V,
+        V: Add<D, Output: PartialEq<V> + Debug> + Debug,
+    {
+        ToChangeBy::new(getter, delta)
+    }
+
+    /// Asserts that calling the subject does not change a value. The value is
+    /// read with a getter before and after the subject is called. This is
+    /// useful for checking that an operation is idempotent, or that a code path
+    /// has no side effects.
+    ///
+    /// Like [`to_change_by`](Self::to_change_by), the operation is the subject
+    /// so the value can be read again after the operation runs.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let mut items = vec![1, 2, 3];
+    /// items.dedup();
+    /// let items = std::cell::RefCell::new(items);
+    /// expect!(
+    ///     || items.borrow_mut().dedup(),
+    ///     to_not_change(|| items.borrow().clone()),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the value changes. Both readings are included in
+    /// the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let items = std::cell::RefCell::new(vec![1, 1, 2]);
+    /// expect!(
+    ///     || items.borrow_mut().dedup(),
+    ///     to_not_change(|| items.borrow().clone()),
+    /// );
+    /// ```
+    #[inline]
+    fn to_not_change<O, G, V>(&self, getter: Annotated<G>) -> ToNotChange<G>
+    where
+        T: FnOnce() -> O,
+        G: FnMut() -> V,
+        V: PartialEq + Debug,
+    {
+        ToNotChange::new(getter)
+    }
+
+    /// Asserts that calling the subject panics with a message that matches the
+    /// given regular expression. The panic is caught, so it does not unwind
+    /// past the assertion.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let id = 42;
+    /// expect!(
+    ///     || panic!("user {id} not found"),
+    ///     to_panic_matching(r"user \d+ not found"),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the subject does not panic, or if the panic
+    /// message does not match the pattern. The captured message is included in
+    /// the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(|| panic!("user not found"), to_panic_matching(r"user \d+"));
+    /// ```
+    ///
+    /// Panics can only be caught if the panic strategy is `unwind`. Panic
+    /// payloads that are not strings never match the pattern.
+    ///
+    /// ## Panics
+    ///
+    /// This panics immediately, without executing the assertion, if the provided
+    /// pattern is an invalid regular expression.
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "regex")]
+    fn to_panic_matching<O, P>(&self, pattern: Annotated<P>) -> super::ToPanicMatching
+    where
+        T: FnOnce() -> O,
+        P: AsRef<str>,
+    {
+        super::ToPanicMatching::new(pattern.inner().as_ref())
+    }
+
+    /// Asserts that the subject returns within a time limit, then continues the
+    /// assertion with the subject's return value.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::time::Duration;
+    /// expect!(
+    ///     || 1 + 1,
+    ///     to_complete_within_and(Duration::from_secs(1)),
+    ///     to_equal(2),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the subject takes too long:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::{thread::sleep, time::Duration};
+    /// expect!(
+    ///     || {
+    ///         sleep(Duration::from_millis(50));
+    ///         1 + 1
+    ///     },
+    ///     to_complete_within_and(Duration::from_millis(1)),
+    ///     to_equal(2),
+    /// );
+    /// ```
+    fn to_complete_within_and<O>(
+        self,
+        limit: Annotated<Duration>,
+    ) -> AssertionBuilder<O, CompleteWithinModifier<M>>
+    where
+        T: FnOnce() -> O;
+
+    /// Calls the subject a fixed number of times with the same arguments, then
+    /// executes the rest of the assertion on the values it returned, in call
+    /// order. Unlike most function assertions, the subject may be a stateful
+    /// [`FnMut`] closure, which makes this useful for testing counters and
+    /// generators.
+    ///
+    /// The arguments are passed as a tuple and cloned for each call. Use `()`
+    /// if the subject takes no arguments:
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let mut state = 1;
+    /// let mut next_power = || {
+    ///     state *= 2;
+    ///     state
+    /// };
+    /// expect!(
+    ///     &mut next_power,
+    ///     when_called_n_times(3, ()),
+    ///     to_equal(vec![2, 4, 8]),
+    /// );
+    ///
+    /// let mut total = 0;
+    /// expect!(
+    ///     |amount: i32| {
+    ///         total += amount;
+    ///         total
+    ///     },
+    ///     when_called_n_times(3, (5,)),
+    ///     to_equal(vec![5, 10, 15]),
+    /// );
+    /// ```
+    ///
+    /// The value returned by each call is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let mut state = 0;
+    /// expect!(
+    ///     || {
+    ///         state += 1;
+    ///         state % 2
+    ///     },
+    ///     when_called_n_times(4, ()),
+    ///     all,
+    ///     to_equal(1),
+    /// );
+    /// ```
+    fn when_called_n_times<Args>(
+        self,
+        calls: Annotated<usize>,
+        args: Annotated<Args>,
+    ) -> AssertionBuilder<Vec<T::Output>, CalledNTimesModifier<M, Args>>
+    where
+        T: CallableMut<Args, Output: Debug>,
+        Args: Clone;
+}
+
+impl<T, M> FunctionAssertions<T, M> for AssertionBuilder<T, M> {
+    #[inline]
+    fn to_complete_within_and<O>(
+        self,
+        limit: Annotated<Duration>,
+    ) -> AssertionBuilder<O, CompleteWithinModifier<M>>
+    where
+        T: FnOnce() -> O,
+    {
+        AssertionBuilder::modify(self, move |prev| CompleteWithinModifier::new(prev, limit))
+    }
+
+    #[inline]
+    fn when_called_n_times<Args>(
+        self,
+        calls: Annotated<usize>,
+        args: Annotated<Args>,
+    ) -> AssertionBuilder<Vec<T::Output>, CalledNTimesModifier<M, Args>>
+    where
+        T: CallableMut<Args, Output: Debug>,
+        Args: Clone,
+    {
+        AssertionBuilder::modify(self, move |prev| {
+            CalledNTimesModifier::new(prev, calls, args)
+        })
+    }
+}
@@ -0,0 +1,5 @@
+mod called_n_times;
+mod complete_within;
+
+pub use called_n_times::*;
+pub use complete_within::*;
@@ -0,0 +1,141 @@
+use std::fmt::{Debug, Write};
+
+use crate::{
+    assertions::{
+        functions::CallableMut, Assertion, AssertionContext, AssertionContextBuilder,
+        AssertionModifier,
+    },
+    metadata::Annotated,
+};
+
+/// Calls the subject repeatedly and collects the values it returns.
+#[derive(Clone, Debug)]
+pub struct CalledNTimesModifier<M, Args> {
+    prev: M,
+    calls: Annotated<usize>,
+    args: Annotated<Args>,
+}
+
+impl<M, Args> CalledNTimesModifier<M, Args> {
+    #[inline]
+    pub(crate) fn new(prev: M, calls: Annotated<usize>, args: Annotated<Args>) -> Self {
+        Self { prev, calls, args }
+    }
+}
+
+impl<M, Args, A> AssertionModifier<A> for CalledNTimesModifier<M, Args>
+where
+    M: AssertionModifier<CalledNTimesAssertion<A, Args>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            CalledNTimesAssertion {
+                next,
+                calls: self.calls,
+                args: self.args,
+            },
+        )
+    }
+}
+
+/// Executes the inner assertion on the values returned by calling the subject
+/// a fixed number of times with the same arguments.
+#[derive(Clone, Debug)]
+pub struct CalledNTimesAssertion<A, Args> {
+    next: A,
+    calls: Annotated<usize>,
+    args: Annotated<Args>,
+}
+
+impl<A, Args, F> Assertion<F> for CalledNTimesAssertion<A, Args>
+where
+    A: Assertion<Vec<F::Output>>,
+    Args: Clone,
+    F: CallableMut<Args, Output: Debug>,
+{
+    type Output = A::Output;
+
+    fn execute(self, mut cx: AssertionContext, mut subject: F) -> Self::Output {
+        let calls = *self.calls.inner();
+        cx.annotate("calls", self.calls);
+        cx.annotate("arguments", &self.args);
+
+        let args = self.args.into_inner();
+        let outputs: Vec<_> = (0..calls)
+            .map(|_| subject.call_with(args.clone()))
+            .collect();
+        let mut page = String::new();
+        for (idx, output) in outputs.iter().enumerate() {
+            writeln!(page, "[{idx}] {output:?}").unwrap();
+        }
+        if !page.is_empty() {
+            cx.add_page("results", page.trim_end());
+        }
+
+        self.next.execute(cx, outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn collects_each_result() {
+        let mut counter = 0;
+        expect!(
+            || {
+                counter += 1;
+                counter
+            },
+            when_called_n_times(3, ()),
+            to_equal(vec![1, 2, 3]),
+        );
+        expect!(counter, to_equal(3));
+    }
+
+    #[test]
+    fn results_are_listed() {
+        let mut next = 10;
+        let message = try_expect!(
+            || {
+                next -= 1;
+                next
+            },
+            when_called_n_times(2, ()),
+            all,
+            to_be_greater_than(9),
+        )
+        .unwrap_err()
+        .to_string();
+        expect!(&message, to_contain_substr("calls: 2"));
+        expect!(&message, to_contain_substr("[0] 9\n[1] 8"));
+    }
+
+    #[test]
+    fn arguments_are_passed_to_each_call() {
+        let mut total = 0;
+        expect!(
+            |step: i32, scale: i32| {
+                total += step * scale;
+                total
+            },
+            when_called_n_times(3, (2, 10)),
+            to_equal(vec![20, 40, 60]),
+        );
+
+        let message = try_expect!(
+            |prefix: &str| format!("{prefix}!"),
+            when_called_n_times(1, ("hi",)),
+            all,
+            to_equal("hey!"),
+        )
+        .unwrap_err()
+        .to_string();
+        expect!(&message, to_contain_substr("arguments: (\"hi\",)"));
+    }
+}
@@ -0,0 +1,98 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    assertions::{
+        general::IntoInitializableOutput, Assertion, AssertionContext, AssertionContextBuilder,
+        AssertionModifier,
+    },
+    metadata::Annotated,
+};
+
+/// Calls the subject and ensures it completes within a time limit.
+#[derive(Clone, Debug)]
+pub struct CompleteWithinModifier<M> {
+    prev: M,
+    limit: Annotated<Duration>,
+}
+
+impl<M> CompleteWithinModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M, limit: Annotated<Duration>) -> Self {
+        Self { prev, limit }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for CompleteWithinModifier<M>
+where
+    M: AssertionModifier<CompleteWithinAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            CompleteWithinAssertion {
+                next,
+                limit: self.limit,
+            },
+        )
+    }
+}
+
+/// Executes the inner assertion on the subject's return value if the subject
+/// completes within the time limit.
+#[derive(Clone, Debug)]
+pub struct CompleteWithinAssertion<A> {
+    next: A,
+    limit: Annotated<Duration>,
+}
+
+impl<A, F, O> Assertion<F> for CompleteWithinAssertion<A>
+where
+    A: Assertion<O, Output: IntoInitializableOutput>,
+    F: FnOnce() -> O,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    fn execute(self, mut cx: AssertionContext, subject: F) -> Self::Output {
+        let start = Instant::now();
+        let output = subject();
+        let elapsed = start.elapsed();
+
+        let exceeded = elapsed > *self.limit.inner();
+        cx.annotate("limit", self.limit);
+        cx.annotate("elapsed", format!("{elapsed:?}"));
+        if exceeded {
+            return cx.fail("took too long");
+        }
+
+        self.next.execute(cx, output).into_initialized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread::sleep, time::Duration};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn passes_output_to_next_assertion() {
+        expect!(
+            || 1 + 1,
+            to_complete_within_and(Duration::from_secs(5)),
+            to_equal(2)
+        );
+    }
+
+    #[test]
+    fn fails_when_too_slow() {
+        expect!(
+            || sleep(Duration::from_millis(20)),
+            not,
+            to_complete_within_and(Duration::from_millis(1)),
+            to_equal(())
+        );
+    }
+}
@@ -1,4 +1,4 @@
-//! Modifiers used for asynchronous tests.
+//! Assertions and modifiers used for asynchronous tests.
 //!
 //! This module contains types used primarily for testing asynchronous code. The
 //! assertions created from the modifiers in this module are generally
@@ -16,10 +16,14 @@
 //! # }
 //! ```
 
+mod assertions;
 mod extensions;
+mod future_tuple;
 mod modifiers;
 mod outputs;
 
+pub use assertions::*;
 pub use extensions::*;
+pub use future_tuple::*;
 pub use modifiers::*;
 pub use outputs::*;
@@ -0,0 +1,3 @@
+mod to_be_poll_variant;
+
+pub use to_be_poll_variant::*;
@@ -0,0 +1,73 @@
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll},
+};
+
+use futures::task::noop_waker_ref;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// Asserts that the subject is ready or pending after being polled once.
+#[derive(Clone, Debug)]
+pub struct ToBePollVariant {
+    expected: PollVariant,
+}
+
+impl ToBePollVariant {
+    #[inline]
+    pub(crate) fn new(expected: PollVariant) -> Self {
+        Self { expected }
+    }
+}
+
+impl<T> Assertion<T> for ToBePollVariant
+where
+    T: Future,
+{
+    type Output = AssertionOutput;
+
+    #[inline]
+    fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
+        let ready = poll_once(subject).is_ready();
+        match self.expected {
+            PollVariant::Ready => cx.pass_if(ready, "received Pending"),
+            PollVariant::Pending => cx.pass_if(!ready, "received Ready"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum PollVariant {
+    Ready,
+    Pending,
+}
+
+/// Polls a future exactly once with a waker that does nothing when woken.
+pub(crate) fn poll_once<T>(subject: T) -> Poll<T::Output>
+where
+    T: Future,
+{
+    let subject = pin!(subject);
+    subject.poll(&mut Context::from_waker(noop_waker_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::{pending, ready};
+
+    use tokio::task::yield_now;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn only_first_poll_is_checked() {
+        expect!(ready(1), to_be_ready);
+        expect!(pending::<()>(), to_be_pending);
+        expect!(yield_now(), to_be_pending);
+        expect!(async { yield_now().await }, not, to_be_ready);
+    }
+}
@@ -1,8 +1,11 @@
-use std::future::Future;
+use std::{future::Future, time::Duration};
 
 use crate::{assertions::AssertionBuilder, metadata::Annotated};
 
-use super::{CompletionOrder, CompletionOrderModifier, WhenReadyModifier};
+use super::{
+    CompletionOrder, CompletionOrderModifier, FutureTuple, PollVariant, ReadyAndModifier,
+    ToBePollVariant, WhenJoinedModifier, WhenReadyModifier, WhenReadyRetryingModifier,
+};
 
 /// Assertions and modifiers for [Future]s.
 pub trait FutureAssertions<T, M>
@@ -44,6 +47,21 @@ where
     /// ```
     fn when_ready(self) -> AssertionBuilder<T::Output, WhenReadyModifier<M>>;
 
+    /// Same as [`when_ready`](FutureAssertions::when_ready), but also measures
+    /// how long the subject takes to become ready. The time is measured from
+    /// the first time the assertion is polled, and is included in the failure
+    /// message as `elapsed`.
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::future::ready;
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// expect!(ready(1), when_ready_timed, to_equal(2)).await;
+    /// # }
+    /// ```
+    fn when_ready_timed(self) -> AssertionBuilder<T::Output, WhenReadyModifier<M>>;
+
     /// Executes an assertion on the output of a future, but only if it does not
     /// complete after another future.
     ///
@@ -119,6 +137,77 @@ where
     ) -> AssertionBuilder<T::Output, CompletionOrderModifier<Fut, M>>
     where
         Fut: Future;
+
+    /// Polls the subject once, and asserts that it is immediately ready. The
+    /// rest of the assertion is executed on its output.
+    ///
+    /// Unlike [`when_ready`](FutureAssertions::when_ready), this does not wait
+    /// for the subject to complete. The subject is polled exactly once with a
+    /// waker that does nothing, so the assertion does not need to be awaited.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::future::ready;
+    /// expect!(ready(1), to_be_ready_and, to_equal(1));
+    /// ```
+    ///
+    /// The assertion fails if the subject is not ready after the first poll:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::future::pending;
+    /// expect!(pending::<i32>(), to_be_ready_and, to_equal(1));
+    /// ```
+    fn to_be_ready_and(self) -> AssertionBuilder<T::Output, ReadyAndModifier<M>>;
+
+    /// Polls the subject once, and asserts that it is immediately ready.
+    ///
+    /// The subject is polled exactly once with a waker that does nothing, so the
+    /// assertion does not need to be awaited.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::future::ready;
+    /// expect!(ready(1), to_be_ready);
+    /// ```
+    ///
+    /// The assertion fails if the subject is not ready after the first poll:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::future::pending;
+    /// expect!(pending::<()>(), to_be_ready);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_ready(&self) -> ToBePollVariant {
+        ToBePollVariant::new(PollVariant::Ready)
+    }
+
+    /// Polls the subject once, and asserts that it is not ready yet. This is
+    /// useful for checking that a future is lazy.
+    ///
+    /// The subject is polled exactly once with a waker that does nothing, so the
+    /// assertion does not need to be awaited.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::future::pending;
+    /// expect!(pending::<()>(), to_be_pending);
+    /// ```
+    ///
+    /// The assertion fails if the subject is ready after the first poll:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::future::ready;
+    /// expect!(ready(1), to_be_pending);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_pending(&self) -> ToBePollVariant {
+        ToBePollVariant::new(PollVariant::Pending)
+    }
 }
 
 impl<T, M> FutureAssertions<T, M> for AssertionBuilder<T, M>
@@ -127,7 +216,12 @@ where
 {
     #[inline]
     fn when_ready(self) -> AssertionBuilder<T::Output, WhenReadyModifier<M>> {
-        AssertionBuilder::modify(self, WhenReadyModifier::new)
+        AssertionBuilder::modify(self, |prev| WhenReadyModifier::new(prev, false))
+    }
+
+    #[inline]
+    fn when_ready_timed(self) -> AssertionBuilder<T::Output, WhenReadyModifier<M>> {
+        AssertionBuilder::modify(self, |prev| WhenReadyModifier::new(prev, true))
     }
 
     #[inline]
@@ -155,4 +249,121 @@ where
             CompletionOrderModifier::new(prev, other, CompletionOrder::After)
         })
     }
+
+    #[inline]
+    fn to_be_ready_and(self) -> AssertionBuilder<T::Output, ReadyAndModifier<M>> {
+        AssertionBuilder::modify(self, ReadyAndModifier::new)
+    }
+}
+
+/// Assertions and modifiers for tuples of [Future]s.
+pub trait FutureTupleAssertions<T, M>
+where
+    T: FutureTuple,
+{
+    /// Executes an assertion on the outputs of a tuple of futures.
+    ///
+    /// The futures are polled concurrently. When all of them are ready, the
+    /// assertion is executed on a tuple of their outputs. The futures can be
+    /// different types, and tuples of up to 12 futures are supported. Like
+    /// [`when_ready`](FutureAssertions::when_ready), the assertion must be
+    /// awaited in order for it to actually perform the assertion.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::future::ready;
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// expect!(
+    ///     (ready(1), async { "two" }),
+    ///     when_joined,
+    ///     to_equal((1, "two")),
+    /// )
+    /// .await;
+    /// # }
+    /// ```
+    fn when_joined(self) -> AssertionBuilder<T::Output, WhenJoinedModifier<M>>;
+}
+
+impl<T, M> FutureTupleAssertions<T, M> for AssertionBuilder<T, M>
+where
+    T: FutureTuple,
+{
+    #[inline]
+    fn when_joined(self) -> AssertionBuilder<T::Output, WhenJoinedModifier<M>> {
+        AssertionBuilder::modify(self, WhenJoinedModifier::new)
+    }
+}
+
+/// Assertions and modifiers for functions that create [Future]s.
+pub trait AsyncFunctionAssertions<T, M> {
+    /// Repeatedly calls the subject to create a future, then executes an
+    /// assertion on its output until the assertion passes.
+    ///
+    /// This is useful for testing eventually-consistent systems, where a fresh
+    /// request needs to be made on each attempt. The subject is called up to
+    /// `attempts` times, waiting for `delay` between attempts. The delay does
+    /// not depend on any particular async runtime.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::{cell::Cell, future::ready, time::Duration};
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let calls = Cell::new(0);
+    /// let next_value = || {
+    ///     calls.set(calls.get() + 1);
+    ///     ready(calls.get())
+    /// };
+    /// expect!(
+    ///     next_value,
+    ///     when_ready_retrying(5, Duration::from_millis(10)),
+    ///     to_equal(3),
+    /// )
+    /// .await;
+    /// # }
+    /// ```
+    ///
+    /// The assertion fails if none of the attempts pass. The number of attempts
+    /// and the failure from the last attempt are included in the failure
+    /// message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::{future::ready, time::Duration};
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// expect!(
+    ///     || ready(1),
+    ///     when_ready_retrying(3, Duration::from_millis(10)),
+    ///     to_equal(2),
+    /// )
+    /// .await;
+    /// # }
+    /// ```
+    fn when_ready_retrying<Fut>(
+        self,
+        attempts: Annotated<usize>,
+        delay: Annotated<Duration>,
+    ) -> AssertionBuilder<Fut::Output, WhenReadyRetryingModifier<M>>
+    where
+        T: FnMut() -> Fut,
+        Fut: Future;
+}
+
+impl<T, M> AsyncFunctionAssertions<T, M> for AssertionBuilder<T, M> {
+    #[inline]
+    fn when_ready_retrying<Fut>(
+        self,
+        attempts: Annotated<usize>,
+        delay: Annotated<Duration>,
+    ) -> AssertionBuilder<Fut::Output, WhenReadyRetryingModifier<M>>
+    where
+        T: FnMut() -> Fut,
+        Fut: Future,
+    {
+        AssertionBuilder::modify(self, move |prev| {
+            WhenReadyRetryingModifier::new(prev, attempts, delay)
+        })
+    }
 }
@@ -0,0 +1,85 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::future::{maybe_done, MaybeDone};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A tuple of [`Future`]s that can be awaited together.
+///
+/// This is implemented for tuples of up to 12 futures. The futures do not need
+/// to be the same type, and the output is a tuple of each future's output.
+pub trait FutureTuple: sealed::Sealed {
+    /// The outputs of each future in the tuple.
+    type Output;
+
+    #[doc(hidden)]
+    type State: Unpin;
+
+    #[doc(hidden)]
+    fn into_state(self) -> Self::State;
+
+    #[doc(hidden)]
+    fn poll_state(state: &mut Self::State, cx: &mut Context) -> Poll<Self::Output>;
+}
+
+macro_rules! impl_future_tuple {
+    ($(($($fut:ident $name:ident),+)),* $(,)?) => {
+        $(
+            impl<$($fut),+> sealed::Sealed for ($($fut,)+)
+            where
+                $($fut: Future,)+
+            {
+            }
+
+            impl<$($fut),+> FutureTuple for ($($fut,)+)
+            where
+                $($fut: Future,)+
+            {
+                type Output = ($($fut::Output,)+);
+                type State = ($(MaybeDone<Pin<Box<$fut>>>,)+);
+
+                #[inline]
+                fn into_state(self) -> Self::State {
+                    let ($($name,)+) = self;
+                    ($(maybe_done(Box::pin($name)),)+)
+                }
+
+                fn poll_state(state: &mut Self::State, cx: &mut Context) -> Poll<Self::Output> {
+                    let ($($name,)+) = state;
+
+                    // Every future needs to be polled so they all make progress
+                    let mut ready = true;
+                    $(ready &= Pin::new(&mut *$name).poll(cx).is_ready();)+
+                    if !ready {
+                        return Poll::Pending;
+                    }
+
+                    Poll::Ready(($(
+                        Pin::new($name).take_output().expect("poll after ready"),
+                    )+))
+                }
+            }
+        )*
+    };
+}
+
+impl_future_tuple!(
+    (F1 f1),
+    (F1 f1, F2 f2),
+    (F1 f1, F2 f2, F3 f3),
+    (F1 f1, F2 f2, F3 f3, F4 f4),
+    (F1 f1, F2 f2, F3 f3, F4 f4, F5 f5),
+    (F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6),
+    (F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6, F7 f7),
+    (F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6, F7 f7, F8 f8),
+    (F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6, F7 f7, F8 f8, F9 f9),
+    (F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6, F7 f7, F8 f8, F9 f9, F10 f10),
+    (F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6, F7 f7, F8 f8, F9 f9, F10 f10, F11 f11),
+    (F1 f1, F2 f2, F3 f3, F4 f4, F5 f5, F6 f6, F7 f7, F8 f8, F9 f9, F10 f10, F11 f11, F12 f12),
+);
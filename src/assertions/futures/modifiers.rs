@@ -1,5 +1,11 @@
 mod completion_order;
+mod ready_and;
+mod when_joined;
 mod when_ready;
+mod when_ready_retrying;
 
 pub use completion_order::*;
+pub use ready_and::*;
+pub use when_joined::*;
 pub use when_ready::*;
+pub use when_ready_retrying::*;
@@ -0,0 +1,54 @@
+use std::{future::Future, task::Poll};
+
+use crate::assertions::{
+    futures::poll_once, general::IntoInitializableOutput, Assertion, AssertionContext,
+    AssertionContextBuilder, AssertionModifier,
+};
+
+/// Polls the subject once and ensures it is immediately ready.
+#[derive(Clone, Debug)]
+pub struct ReadyAndModifier<M> {
+    prev: M,
+}
+
+impl<M> ReadyAndModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M) -> Self {
+        Self { prev }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for ReadyAndModifier<M>
+where
+    M: AssertionModifier<ReadyAndAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(cx, ReadyAndAssertion { next })
+    }
+}
+
+/// Executes the inner assertion on the subject's output if it is ready after
+/// being polled once.
+#[derive(Clone, Debug)]
+pub struct ReadyAndAssertion<A> {
+    next: A,
+}
+
+impl<A, T> Assertion<T> for ReadyAndAssertion<A>
+where
+    A: Assertion<T::Output, Output: IntoInitializableOutput>,
+    T: Future,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    #[inline]
+    fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
+        let Poll::Ready(output) = poll_once(subject) else {
+            return cx.fail("received Pending");
+        };
+        self.next.execute(cx, output).into_initialized()
+    }
+}
@@ -0,0 +1,48 @@
+use crate::assertions::{
+    futures::{FutureTuple, WhenJoinedFuture},
+    Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier,
+};
+
+/// Executes an assertion when every future in the subject is ready.
+#[derive(Clone, Debug)]
+pub struct WhenJoinedModifier<M> {
+    prev: M,
+}
+
+impl<M> WhenJoinedModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M) -> Self {
+        Self { prev }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for WhenJoinedModifier<M>
+where
+    M: AssertionModifier<WhenJoinedAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(cx, WhenJoinedAssertion { next })
+    }
+}
+
+/// Executes the inner assertion when every future in the subject is ready.
+#[derive(Clone, Debug)]
+pub struct WhenJoinedAssertion<A> {
+    next: A,
+}
+
+impl<A, T> Assertion<T> for WhenJoinedAssertion<A>
+where
+    T: FutureTuple,
+    A: Assertion<T::Output>,
+{
+    type Output = WhenJoinedFuture<T, A>;
+
+    #[inline]
+    fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
+        WhenJoinedFuture::new(cx, subject, self.next)
+    }
+}
@@ -9,12 +9,13 @@ use crate::assertions::{
 #[derive(Clone, Debug)]
 pub struct WhenReadyModifier<M> {
     prev: M,
+    timed: bool,
 }
 
 impl<M> WhenReadyModifier<M> {
     #[inline]
-    pub(crate) fn new(prev: M) -> Self {
-        Self { prev }
+    pub(crate) fn new(prev: M, timed: bool) -> Self {
+        Self { prev, timed }
     }
 }
 
@@ -26,7 +27,13 @@ where
 
     #[inline]
     fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
-        self.prev.apply(cx, WhenReadyAssertion { next })
+        self.prev.apply(
+            cx,
+            WhenReadyAssertion {
+                next,
+                timed: self.timed,
+            },
+        )
     }
 }
 
@@ -34,6 +41,7 @@ where
 #[derive(Clone, Debug)]
 pub struct WhenReadyAssertion<A> {
     next: A,
+    timed: bool,
 }
 
 impl<A, T> Assertion<T> for WhenReadyAssertion<A>
@@ -45,6 +53,6 @@ where
 
     #[inline]
     fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
-        WhenReadyFuture::new(cx, subject, self.next)
+        WhenReadyFuture::new(cx, subject, self.next, self.timed)
     }
 }
@@ -0,0 +1,77 @@
+use std::{future::Future, time::Duration};
+
+use crate::{
+    assertions::{
+        futures::WhenReadyRetryingFuture, Assertion, AssertionContext, AssertionContextBuilder,
+        AssertionModifier,
+    },
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Repeatedly creates a future using the subject and executes an assertion on
+/// its output until the assertion passes.
+#[derive(Clone, Debug)]
+pub struct WhenReadyRetryingModifier<M> {
+    prev: M,
+    attempts: Annotated<usize>,
+    delay: Annotated<Duration>,
+}
+
+impl<M> WhenReadyRetryingModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M, attempts: Annotated<usize>, delay: Annotated<Duration>) -> Self {
+        Self {
+            prev,
+            attempts,
+            delay,
+        }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for WhenReadyRetryingModifier<M>
+where
+    M: AssertionModifier<WhenReadyRetryingAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            WhenReadyRetryingAssertion {
+                next,
+                attempts: self.attempts,
+                delay: self.delay,
+            },
+        )
+    }
+}
+
+/// Executes the inner assertion on the output of futures created by the
+/// subject until the assertion passes or no attempts remain.
+#[derive(Clone, Debug)]
+pub struct WhenReadyRetryingAssertion<A> {
+    next: A,
+    attempts: Annotated<usize>,
+    delay: Annotated<Duration>,
+}
+
+impl<A, F, Fut> Assertion<F> for WhenReadyRetryingAssertion<A>
+where
+    A: Assertion<Fut::Output, Output = AssertionOutput> + Clone,
+    F: FnMut() -> Fut,
+    Fut: Future,
+{
+    type Output = WhenReadyRetryingFuture<F, Fut, A>;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: F) -> Self::Output {
+        let max_attempts = *self.attempts.inner();
+        cx.annotate("max attempts", self.attempts);
+        let delay = *self.delay.inner();
+        cx.annotate("delay", self.delay);
+
+        WhenReadyRetryingFuture::new(cx, subject, self.next, max_attempts, delay)
+    }
+}
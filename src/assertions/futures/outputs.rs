@@ -1,13 +1,21 @@
 mod completion_order;
+mod delay;
 mod initialized;
 mod inverted;
 mod merged;
 mod unwrapped;
+mod when_joined;
 mod when_ready;
+mod when_ready_retrying;
+mod with_message;
 
 pub use completion_order::*;
+pub(crate) use delay::*;
 pub use initialized::*;
 pub use inverted::*;
 pub use merged::*;
 pub use unwrapped::*;
+pub use when_joined::*;
 pub use when_ready::*;
+pub use when_ready_retrying::*;
+pub use with_message::*;
@@ -0,0 +1,57 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A [`Future`] that completes after a duration has passed.
+///
+/// This does not depend on any particular async runtime. The first time the
+/// future is polled before its deadline, a thread is spawned that wakes the
+/// most recent waker once the deadline has passed.
+#[derive(Debug)]
+pub(crate) struct Delay {
+    deadline: Instant,
+    waker: Option<Arc<Mutex<Waker>>>,
+}
+
+impl Delay {
+    #[inline]
+    pub(crate) fn new(duration: Duration) -> Self {
+        Self {
+            deadline: Instant::now() + duration,
+            waker: None,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+
+        if let Some(waker) = &self.waker {
+            waker
+                .lock()
+                .expect("waker lock poisoned")
+                .clone_from(cx.waker());
+            return Poll::Pending;
+        }
+
+        let waker = Arc::new(Mutex::new(cx.waker().clone()));
+        self.waker = Some(waker.clone());
+        let deadline = self.deadline;
+        let _ = thread::spawn(move || {
+            thread::sleep(deadline.saturating_duration_since(Instant::now()));
+            waker.lock().expect("waker lock poisoned").wake_by_ref();
+        });
+
+        Poll::Pending
+    }
+}
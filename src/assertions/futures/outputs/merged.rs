@@ -27,6 +27,7 @@ pin_project! {
         inner: Collect<FuturesUnordered<F>, Vec<F::Output>>,
         cx: Option<AssertionContext>,
         strategy: MergeStrategy,
+        reporting: bool,
     }
 }
 
@@ -44,6 +45,20 @@ where
             inner: FuturesUnordered::from_iter(outputs).collect(),
             cx: Some(cx),
             strategy,
+            reporting: false,
+        }
+    }
+
+    /// Creates a new merged outputs future that checks every output and
+    /// reports each failure.
+    #[inline]
+    pub(crate) fn new_reporting<I>(cx: AssertionContext, outputs: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+    {
+        Self {
+            reporting: true,
+            ..Self::new(cx, MergeStrategy::All, outputs)
         }
     }
 }
@@ -58,7 +73,11 @@ where
         let projected = self.project();
         let outputs = ready!(projected.inner.poll(cx));
         let cx = projected.cx.take().expect("poll after ready");
-        Poll::Ready(MergeableOutput::merge(cx, *projected.strategy, outputs))
+        if *projected.reporting {
+            Poll::Ready(MergeableOutput::merge_reporting(cx, outputs))
+        } else {
+            Poll::Ready(MergeableOutput::merge(cx, *projected.strategy, outputs))
+        }
     }
 }
 
@@ -75,4 +94,12 @@ where
     {
         MergedOutputsFuture::new(cx, strategy, outputs)
     }
+
+    #[inline]
+    fn merge_reporting<I>(cx: AssertionContext, outputs: I) -> Self::Merged
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        MergedOutputsFuture::new_reporting(cx, outputs)
+    }
 }
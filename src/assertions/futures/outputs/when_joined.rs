@@ -0,0 +1,74 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::assertions::{futures::FutureTuple, Assertion, AssertionContext};
+
+pin_project! {
+    /// A [`Future`] which executes an assertion when every future in its
+    /// subject is ready.
+    ///
+    /// Created by [`when_joined`](crate::prelude::FutureTupleAssertions::when_joined).
+    #[derive(Clone, Debug)]
+    #[must_use]
+    pub struct WhenJoinedFuture<T, A>
+    where
+        T: FutureTuple,
+    {
+        state: T::State,
+        next: Option<(AssertionContext, A)>,
+    }
+}
+
+impl<T, A> WhenJoinedFuture<T, A>
+where
+    T: FutureTuple,
+    A: Assertion<T::Output>,
+{
+    /// Creates a new instance of this future.
+    #[inline]
+    pub(crate) fn new(cx: AssertionContext, subject: T, next: A) -> Self {
+        Self {
+            state: subject.into_state(),
+            next: Some((cx, next)),
+        }
+    }
+}
+
+impl<T, A> Future for WhenJoinedFuture<T, A>
+where
+    T: FutureTuple,
+    A: Assertion<T::Output>,
+{
+    type Output = A::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let projected = self.project();
+        let input = ready!(T::poll_state(projected.state, cx));
+        let (cx, next) = projected.next.take().expect("poll after ready");
+        Poll::Ready(next.execute(cx, input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{future::ready, time::Duration};
+
+    use tokio::time::sleep;
+
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn waits_for_every_future() {
+        let slow = async {
+            sleep(Duration::from_millis(10)).await;
+            "slow"
+        };
+        expect!((slow, ready(1)), when_joined, to_equal(("slow", 1))).await;
+        expect!((ready(1), ready(2)), when_joined, not, to_equal((1, 3))).await;
+    }
+}
@@ -2,6 +2,7 @@ use std::{
     future::Future,
     pin::Pin,
     task::{ready, Context, Poll},
+    time::Instant,
 };
 
 use pin_project_lite::pin_project;
@@ -11,7 +12,12 @@ use crate::assertions::{Assertion, AssertionContext};
 pin_project! {
     /// A [`Future`] which executes an assertion when its subject is ready.
     ///
-    /// Created by [`when_ready`](crate::prelude::when_ready).
+    /// If the future is timed, the time taken for the subject to become ready,
+    /// measured from the first time this future is polled, is annotated as
+    /// `elapsed`.
+    ///
+    /// Created by [`when_ready`](crate::prelude::FutureAssertions::when_ready) and
+    /// [`when_ready_timed`](crate::prelude::FutureAssertions::when_ready_timed).
     #[derive(Clone, Debug)]
     #[must_use]
     pub struct WhenReadyFuture<T, A>
@@ -21,6 +27,8 @@ pin_project! {
         #[pin]
         subject: T,
         next: Option<(AssertionContext, A)>,
+        timed: bool,
+        started: Option<Instant>,
     }
 }
 
@@ -31,10 +39,12 @@ where
 {
     /// Creates a new instance of this future.
     #[inline]
-    pub(crate) fn new(cx: AssertionContext, subject: T, next: A) -> Self {
+    pub(crate) fn new(cx: AssertionContext, subject: T, next: A, timed: bool) -> Self {
         Self {
             subject,
             next: Some((cx, next)),
+            timed,
+            started: None,
         }
     }
 }
@@ -48,8 +58,54 @@ where
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
         let projected = self.project();
+        if *projected.timed {
+            let _ = projected.started.get_or_insert_with(Instant::now);
+        }
         let input = ready!(projected.subject.poll(cx));
-        let (cx, next) = projected.next.take().expect("poll after ready");
+
+        let (mut cx, next) = projected.next.take().expect("poll after ready");
+        if let Some(started) = projected.started {
+            cx.annotate("elapsed", format_args!("{:?}", started.elapsed()));
+        }
         Poll::Ready(next.execute(cx, input))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::sleep;
+
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn elapsed_is_annotated_when_timed() {
+        let result = try_expect!(
+            sleep(Duration::from_millis(10)),
+            when_ready_timed,
+            to_satisfy(|()| false),
+        )
+        .await;
+        expect!(
+            result,
+            to_be_err_and,
+            as_display,
+            to_contain_substr("elapsed: "),
+        );
+
+        let result = try_expect!(
+            sleep(Duration::from_millis(10)),
+            when_ready,
+            to_satisfy(|()| false),
+        )
+        .await;
+        expect!(
+            result,
+            to_be_err_and,
+            as_display,
+            not,
+            to_contain_substr("elapsed: "),
+        );
+    }
+}
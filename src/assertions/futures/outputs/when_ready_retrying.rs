@@ -0,0 +1,162 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::Duration,
+};
+
+use pin_project_lite::pin_project;
+
+use crate::{
+    assertions::{futures::Delay, Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+pin_project! {
+    /// A [`Future`] which repeatedly creates a future using its subject and
+    /// executes an assertion on its output until the assertion passes or it
+    /// runs out of attempts.
+    ///
+    /// Created by
+    /// [`when_ready_retrying`](crate::prelude::AsyncFunctionAssertions::when_ready_retrying).
+    #[derive(Debug)]
+    #[must_use]
+    pub struct WhenReadyRetryingFuture<F, Fut, A> {
+        subject: F,
+        #[pin]
+        current: Option<Fut>,
+        delay: Option<Delay>,
+        cx: AssertionContext,
+        next: A,
+        attempt: usize,
+        max_attempts: usize,
+        retry_delay: Duration,
+    }
+}
+
+impl<F, Fut, A> WhenReadyRetryingFuture<F, Fut, A> {
+    /// Creates a new instance of this future.
+    #[inline]
+    pub(crate) fn new(
+        cx: AssertionContext,
+        subject: F,
+        next: A,
+        max_attempts: usize,
+        retry_delay: Duration,
+    ) -> Self {
+        Self {
+            subject,
+            current: None,
+            delay: None,
+            cx,
+            next,
+            attempt: 0,
+            max_attempts,
+            retry_delay,
+        }
+    }
+}
+
+impl<F, Fut, A> Future for WhenReadyRetryingFuture<F, Fut, A>
+where
+    F: FnMut() -> Fut,
+    Fut: Future,
+    A: Assertion<Fut::Output, Output = AssertionOutput> + Clone,
+{
+    type Output = AssertionOutput;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut projected = self.project();
+        if *projected.max_attempts == 0 {
+            return Poll::Ready(projected.cx.clone().fail("no attempts were made"));
+        }
+
+        loop {
+            // Wait between attempts
+            if let Some(delay) = projected.delay.as_mut() {
+                ready!(Pin::new(delay).poll(cx));
+                *projected.delay = None;
+            }
+
+            // Start a new attempt if needed
+            if projected.current.is_none() {
+                projected.current.set(Some((projected.subject)()));
+                *projected.attempt += 1;
+            }
+
+            let output = ready!(projected
+                .current
+                .as_mut()
+                .as_pin_mut()
+                .expect("no current attempt")
+                .poll(cx));
+            projected.current.set(None);
+
+            // Execute the assertion on a fork of the context so that only the
+            // last attempt's failure is reported
+            let mut attempt_cx = projected.cx.clone();
+            attempt_cx.annotate("attempts", *projected.attempt);
+            let result = projected.next.clone().execute(attempt_cx, output);
+            if result.is_pass() || projected.attempt >= projected.max_attempts {
+                return Poll::Ready(result);
+            }
+
+            *projected.delay = Some(Delay::new(*projected.retry_delay));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        future::ready,
+        time::{Duration, Instant},
+    };
+
+    use crate::prelude::*;
+
+    #[tokio::test]
+    async fn retries_until_pass() {
+        let calls = Cell::new(0);
+        let start = Instant::now();
+        expect!(
+            || {
+                calls.set(calls.get() + 1);
+                ready(calls.get())
+            },
+            when_ready_retrying(5, Duration::from_millis(5)),
+            to_equal(3),
+        )
+        .await;
+        expect!(calls.get(), to_equal(3));
+        expect!(
+            start.elapsed(),
+            to_be_greater_than_or_equal_to(Duration::from_millis(10))
+        );
+    }
+
+    #[tokio::test]
+    async fn last_failure_is_reported() {
+        let calls = Cell::new(0);
+        let result = try_expect!(
+            || {
+                calls.set(calls.get() + 1);
+                ready(calls.get())
+            },
+            when_ready_retrying(3, Duration::ZERO),
+            to_equal(0),
+        )
+        .await;
+        expect!(
+            result,
+            to_be_err_and,
+            as_display,
+            to_satisfy_with(|message| {
+                try_expect!(&message, to_contain_substr("attempts: 3"))?;
+                try_expect!(&message, to_contain_substr("received: 3"))?;
+                Ok(())
+            }),
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::assertions::general::MessageOutput;
+
+pin_project! {
+    /// Attaches a custom message to an asynchronous output.
+    #[derive(Clone, Debug)]
+    #[must_use]
+    pub struct MessageOutputFuture<F, M> {
+        #[pin]
+        inner: F,
+        message: Option<M>,
+    }
+}
+
+impl<F, M> MessageOutputFuture<F, M>
+where
+    F: Future<Output: MessageOutput<M>>,
+{
+    /// Creates a new message output future.
+    #[inline]
+    pub fn new(inner: F, message: M) -> Self {
+        Self {
+            inner,
+            message: Some(message),
+        }
+    }
+}
+
+impl<F, M> Future for MessageOutputFuture<F, M>
+where
+    F: Future<Output: MessageOutput<M>>,
+{
+    type Output = <F::Output as MessageOutput<M>>::WithMessage;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let projected = self.project();
+        let output = ready!(projected.inner.poll(cx));
+        let message = projected.message.take().expect("poll after ready");
+        Poll::Ready(output.with_message(message))
+    }
+}
+
+impl<F, M> MessageOutput<M> for F
+where
+    F: Future<Output: MessageOutput<M>>,
+{
+    type WithMessage = MessageOutputFuture<F, M>;
+
+    #[inline]
+    fn with_message(self, message: M) -> Self::WithMessage {
+        MessageOutputFuture::new(self, message)
+    }
+}
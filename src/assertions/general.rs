@@ -8,11 +8,13 @@
 //! importing the prelude will import all the assertions and modifiers from this
 //! module.
 
+mod approx_eq;
 mod assertions;
 mod extensions;
 mod modifiers;
 mod outputs;
 
+pub use approx_eq::*;
 pub use assertions::*;
 pub use extensions::*;
 pub use modifiers::*;
@@ -0,0 +1,211 @@
+use std::fmt::{Debug, Formatter};
+
+/// A value that can be compared to another value within a tolerance.
+///
+/// This is implemented for floats, as well as for arrays, slices, [`Vec`]s, and
+/// tuples of values that share a tolerance type. Implement this for your own
+/// types (like vectors or colors) to use them with
+/// [`to_be_approximately_equal`](crate::prelude::GeneralAssertions::to_be_approximately_equal).
+///
+/// ```
+/// use expecters::{assertions::general::ApproxEq, prelude::*};
+///
+/// #[derive(Debug)]
+/// struct Vec2 {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// impl ApproxEq for Vec2 {
+///     type Tolerance = f32;
+///
+///     fn approx_eq(&self, other: &Self, tolerance: &f32) -> bool {
+///         (self.x - other.x).hypot(self.y - other.y) <= *tolerance
+///     }
+/// }
+///
+/// expect!(
+///     Vec2 { x: 1.0, y: 2.0 },
+///     to_be_approximately_equal(Vec2 { x: 1.0, y: 2.05 }, 0.1),
+/// );
+/// ```
+pub trait ApproxEq<Rhs = Self>
+where
+    Rhs: ?Sized,
+{
+    /// The type of the tolerance used in comparisons.
+    type Tolerance;
+
+    /// Gets whether `self` and `other` are equal within the given tolerance.
+    fn approx_eq(&self, other: &Rhs, tolerance: &Self::Tolerance) -> bool;
+}
+
+impl<T, U> ApproxEq<U> for &T
+where
+    T: ApproxEq<U> + ?Sized,
+    U: ?Sized,
+{
+    type Tolerance = T::Tolerance;
+
+    #[inline]
+    fn approx_eq(&self, other: &U, tolerance: &Self::Tolerance) -> bool {
+        T::approx_eq(self, other, tolerance)
+    }
+}
+
+macro_rules! impl_approx_eq_float {
+    ($($ty:ty),*) => {
+        $(
+            impl ApproxEq for $ty {
+                type Tolerance = $ty;
+
+                #[inline]
+                fn approx_eq(&self, other: &Self, tolerance: &Self::Tolerance) -> bool {
+                    (self - other).abs() <= *tolerance
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_float!(f32, f64);
+
+impl<T, U, const N: usize> ApproxEq<[U; N]> for [T; N]
+where
+    T: ApproxEq<U>,
+{
+    type Tolerance = T::Tolerance;
+
+    #[inline]
+    fn approx_eq(&self, other: &[U; N], tolerance: &Self::Tolerance) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(left, right)| left.approx_eq(right, tolerance))
+    }
+}
+
+impl<T, U> ApproxEq<[U]> for [T]
+where
+    T: ApproxEq<U>,
+{
+    type Tolerance = T::Tolerance;
+
+    #[inline]
+    fn approx_eq(&self, other: &[U], tolerance: &Self::Tolerance) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(left, right)| left.approx_eq(right, tolerance))
+    }
+}
+
+impl<T, U> ApproxEq<Vec<U>> for Vec<T>
+where
+    T: ApproxEq<U>,
+{
+    type Tolerance = T::Tolerance;
+
+    #[inline]
+    fn approx_eq(&self, other: &Vec<U>, tolerance: &Self::Tolerance) -> bool {
+        self.as_slice().approx_eq(other.as_slice(), tolerance)
+    }
+}
+
+impl<T, U, const N: usize> ApproxEq<[U; N]> for Vec<T>
+where
+    T: ApproxEq<U>,
+{
+    type Tolerance = T::Tolerance;
+
+    #[inline]
+    fn approx_eq(&self, other: &[U; N], tolerance: &Self::Tolerance) -> bool {
+        self.as_slice().approx_eq(other.as_slice(), tolerance)
+    }
+}
+
+macro_rules! impl_approx_eq_tuple {
+    ($(($first:ident $first_rhs:ident $first_idx:tt $(, $rest:ident $rest_rhs:ident $rest_idx:tt)*)),* $(,)?) => {
+        $(
+            impl<$first, $first_rhs, $($rest, $rest_rhs),*> ApproxEq<($first_rhs, $($rest_rhs,)*)>
+                for ($first, $($rest,)*)
+            where
+                $first: ApproxEq<$first_rhs>,
+                $($rest: ApproxEq<$rest_rhs, Tolerance = $first::Tolerance>,)*
+            {
+                type Tolerance = $first::Tolerance;
+
+                #[inline]
+                fn approx_eq(
+                    &self,
+                    other: &($first_rhs, $($rest_rhs,)*),
+                    tolerance: &Self::Tolerance,
+                ) -> bool {
+                    self.$first_idx.approx_eq(&other.$first_idx, tolerance)
+                        $(&& self.$rest_idx.approx_eq(&other.$rest_idx, tolerance))*
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_tuple!(
+    (A0 B0 0),
+    (A0 B0 0, A1 B1 1),
+    (A0 B0 0, A1 B1 1, A2 B2 2),
+    (A0 B0 0, A1 B1 1, A2 B2 2, A3 B3 3),
+);
+
+/// A value whose equality comparisons are performed within a tolerance.
+///
+/// This is created by
+/// [`with_float_tolerance`](crate::prelude::GeneralAssertions::with_float_tolerance),
+/// and implements [`PartialEq`] using [`ApproxEq`]. This allows
+/// [`to_equal`](crate::prelude::GeneralAssertions::to_equal) and
+/// [`to_be_one_of`](crate::prelude::GeneralAssertions::to_be_one_of) to compare
+/// values approximately.
+#[derive(Clone, Copy)]
+pub struct WithTolerance<T, D> {
+    value: T,
+    tolerance: D,
+}
+
+impl<T, D> WithTolerance<T, D> {
+    #[inline]
+    pub(crate) fn new(value: T, tolerance: D) -> Self {
+        Self { value, tolerance }
+    }
+
+    /// Gets the wrapped value.
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Gets the tolerance used in comparisons.
+    #[inline]
+    pub fn tolerance(&self) -> &D {
+        &self.tolerance
+    }
+}
+
+impl<T, U, D> PartialEq<U> for WithTolerance<T, D>
+where
+    T: ApproxEq<U, Tolerance = D>,
+{
+    #[inline]
+    fn eq(&self, other: &U) -> bool {
+        self.value.approx_eq(other, &self.tolerance)
+    }
+}
+
+// The tolerance is annotated separately, so only the value is shown
+impl<T, D> Debug for WithTolerance<T, D>
+where
+    T: Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
@@ -1,13 +1,31 @@
+mod to_be_approx_equal;
+mod to_be_approx_one_of;
 mod to_be_one_of;
+mod to_be_valid_index_for;
+mod to_be_variant;
 mod to_cmp;
+mod to_compare_as;
 mod to_equal;
 mod to_equal_approx;
+mod to_have_integer_property;
+mod to_have_sign;
+mod to_match_pattern;
 mod to_satisfy;
+mod to_satisfy_eventually;
 mod to_satisfy_with;
 
+pub use to_be_approx_equal::*;
+pub use to_be_approx_one_of::*;
 pub use to_be_one_of::*;
+pub use to_be_valid_index_for::*;
+pub use to_be_variant::*;
 pub use to_cmp::*;
+pub use to_compare_as::*;
 pub use to_equal::*;
 pub use to_equal_approx::*;
+pub use to_have_integer_property::*;
+pub use to_have_sign::*;
+pub use to_match_pattern::*;
 pub use to_satisfy::*;
+pub use to_satisfy_eventually::*;
 pub use to_satisfy_with::*;
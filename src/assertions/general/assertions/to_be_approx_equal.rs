@@ -0,0 +1,59 @@
+use crate::{
+    assertions::{general::ApproxEq, Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject is equal to an expected value within a tolerance.
+#[derive(Clone, Debug)]
+pub struct ToBeApproxEqual<U, D> {
+    expected: Annotated<U>,
+    tolerance: Annotated<D>,
+}
+
+impl<U, D> ToBeApproxEqual<U, D> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<U>, tolerance: Annotated<D>) -> Self {
+        Self {
+            expected,
+            tolerance,
+        }
+    }
+}
+
+impl<T, U, D> Assertion<T> for ToBeApproxEqual<U, D>
+where
+    T: ApproxEq<U, Tolerance = D>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.expected);
+        cx.annotate("tolerance", &self.tolerance);
+        cx.pass_if(
+            subject.approx_eq(self.expected.inner(), self.tolerance.inner()),
+            "values not approximately equal",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn compares_elementwise() {
+        expect!((1.0, 2.0), to_be_approximately_equal((1.05, 1.95), 0.1));
+        expect!([0.1f32; 3], to_be_approximately_equal([0.15; 3], 0.1));
+        expect!(&1.0, to_be_approximately_equal(1.01, 0.1));
+    }
+
+    #[test_case((1.0, 2.0), (1.0, 2.5); "tuple")]
+    #[test_case((1.0, f64::NAN), (1.0, f64::NAN); "nan")]
+    #[should_panic = "values not approximately equal"]
+    fn failure_cases(subject: (f64, f64), expected: (f64, f64)) {
+        expect!(subject, to_be_approximately_equal(expected, 0.1));
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+use super::Float;
+
+/// Asserts that the subject is approximately equal to one of the items in an
+/// iterator.
+#[derive(Clone, Debug)]
+pub struct ToBeApproxOneOf<I, T> {
+    candidates: Annotated<I>,
+    max_delta: Annotated<T>,
+}
+
+impl<I, T> ToBeApproxOneOf<I, T> {
+    #[inline]
+    pub(crate) fn new(candidates: Annotated<I>, max_delta: Annotated<T>) -> Self {
+        Self {
+            candidates,
+            max_delta,
+        }
+    }
+}
+
+impl<I, T> Assertion<T> for ToBeApproxOneOf<I, T>
+where
+    I: IntoIterator<Item = T>,
+    T: Float,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("candidates", &self.candidates);
+        let max_delta = *self.max_delta.inner();
+        cx.annotate("max delta", self.max_delta);
+
+        // Find the closest candidate. A NaN delta can't be compared, so any
+        // other candidate replaces it
+        let is_nan = |value: T| value.partial_cmp(&value).is_none();
+        let mut closest: Option<(T, T)> = None;
+        for candidate in self.candidates.into_inner() {
+            let delta = (subject - candidate).abs();
+            if closest
+                .is_none_or(|(_, closest_delta)| delta < closest_delta || is_nan(closest_delta))
+            {
+                closest = Some((candidate, delta));
+            }
+        }
+
+        let Some((candidate, delta)) = closest else {
+            return cx.fail("no candidates");
+        };
+        cx.annotate("closest", format_args!("{candidate:?}"));
+        cx.annotate("delta", format_args!("{delta:?}"));
+        cx.pass_if(delta <= max_delta, "out of range of every candidate")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn picks_closest_candidate() {
+        expect!(2.05, to_be_approximately_one_of([1.0, 2.0, 3.0], 0.1));
+        expect!(2.5, not, to_be_approximately_one_of([1.0, 2.0, 3.0], 0.1));
+        expect!(1.0, not, to_be_approximately_one_of(Vec::<f64>::new(), 0.1));
+    }
+
+    #[test]
+    fn nan_candidates_are_skipped() {
+        expect!(0.5, to_be_approximately_one_of([f64::NAN, 0.5], 0.1));
+        expect!(0.5, to_be_approximately_one_of([0.5, f64::NAN], 0.1));
+    }
+
+    #[test]
+    fn nan_never_matches() {
+        expect!(
+            f32::NAN,
+            not,
+            to_be_approximately_one_of([f32::NAN, 0.0], 1.0)
+        );
+    }
+}
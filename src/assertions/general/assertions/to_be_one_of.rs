@@ -1,9 +1,14 @@
+use std::fmt::Write;
+
 use crate::{
     assertions::{Assertion, AssertionContext},
     metadata::Annotated,
     AssertionOutput,
 };
 
+/// The maximum number of candidates listed in failure messages.
+const MAX_LISTED_CANDIDATES: usize = 20;
+
 /// Asserts that the subject is equal to one of the items in an iterator.
 #[derive(Clone, Debug)]
 pub struct ToBeOneOf<I> {
@@ -24,15 +29,223 @@ where
 {
     type Output = AssertionOutput;
 
-    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
-        cx.annotate("items", &self.items);
+    #[inline]
+    fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
+        self.check(cx, &subject, None)
+    }
 
+    #[inline]
+    fn execute_annotated(self, cx: AssertionContext, subject: Annotated<T>) -> Self::Output
+    where
+        Self: Sized,
+    {
+        let subject_repr = subject.as_debug().map(|debug| format!("{debug:?}"));
+        self.check(cx, subject.inner(), subject_repr)
+    }
+}
+
+impl<I> ToBeOneOf<I>
+where
+    I: IntoIterator,
+{
+    fn check<T>(
+        self,
+        mut cx: AssertionContext,
+        subject: &T,
+        subject_repr: Option<String>,
+    ) -> AssertionOutput
+    where
+        T: PartialEq<I::Item>,
+    {
+        // The candidates are consumed while searching, so their representation
+        // needs to be captured first
+        let items_repr = self.items.to_string();
+
+        let mut candidates = 0;
         for item in self.items.into_inner() {
-            if subject == item {
+            if subject == &item {
                 return cx.pass();
             }
+
+            candidates += 1;
         }
 
+        cx.annotate("candidates", candidates);
+        if candidates == 0 {
+            return cx.fail("no candidates");
+        }
+
+        // Render each candidate on its own line if the candidates' combined
+        // representation can be split, marking the candidate whose
+        // representation is most similar to the subject's
+        let Some(reprs) = split_entries(&items_repr).filter(|reprs| reprs.len() == candidates)
+        else {
+            cx.annotate("items", items_repr);
+            return cx.fail("not found");
+        };
+        let closest = subject_repr.and_then(|subject| {
+            reprs
+                .iter()
+                .enumerate()
+                .map(|(idx, repr)| (idx, similarity(&subject, repr)))
+                .filter(|&(_, score)| score > 0)
+                .min_by_key(|&(idx, score)| (usize::MAX - score, idx))
+                .map(|(idx, _)| idx)
+        });
+
+        let mut page = String::new();
+        for (idx, repr) in reprs.iter().enumerate().take(MAX_LISTED_CANDIDATES) {
+            let marker = if closest == Some(idx) {
+                " (closest)"
+            } else {
+                ""
+            };
+            writeln!(page, "[{idx}] {repr}{marker}").unwrap();
+        }
+        if reprs.len() > MAX_LISTED_CANDIDATES {
+            writeln!(page, "... and {} more", reprs.len() - MAX_LISTED_CANDIDATES).unwrap();
+        }
+        cx.add_page("candidates", page.trim_end());
+
+        if let Some(idx) = closest {
+            cx.annotate("closest", format_args!("[{idx}] {}", reprs[idx]));
+        }
         cx.fail("not found")
     }
 }
+
+/// Splits the [`Debug`](std::fmt::Debug) representation of a list or set into
+/// the representations of its entries. Returns [`None`] if the representation
+/// isn't a bracketed sequence.
+fn split_entries(repr: &str) -> Option<Vec<&str>> {
+    let inner = repr
+        .strip_prefix('[')
+        .and_then(|repr| repr.strip_suffix(']'))
+        .or_else(|| repr.strip_prefix('{')?.strip_suffix('}'))?;
+    if inner.trim().is_empty() {
+        return Some(Vec::new());
+    }
+
+    // Only split on commas outside of nested values and string or char literals
+    let mut entries = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (idx, c) in inner.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quote.is_some() => escaped = true,
+            '"' | '\'' if quote == Some(c) => quote = None,
+            _ if quote.is_some() => {}
+            '"' | '\'' => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                entries.push(inner[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    if quote.is_some() || depth != 0 {
+        return None;
+    }
+
+    entries.push(inner[start..].trim());
+    Some(entries)
+}
+
+/// Scores how similar two representations are by the lengths of their common
+/// prefix and suffix.
+fn similarity(left: &str, right: &str) -> usize {
+    let prefix = left
+        .chars()
+        .zip(right.chars())
+        .take_while(|(l, r)| l == r)
+        .count();
+    let max_suffix = left.chars().count().min(right.chars().count()) - prefix;
+    let suffix = left
+        .chars()
+        .rev()
+        .zip(right.chars().rev())
+        .take(max_suffix)
+        .take_while(|(l, r)| l == r)
+        .count();
+    prefix + suffix
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn candidates_are_listed_individually() {
+        let message = try_expect!("grape", to_be_one_of(["apple", "grapes", "pear"]))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr(r#"[0] "apple""#));
+        expect!(&message, to_contain_substr(r#"[1] "grapes" (closest)"#));
+        expect!(&message, to_contain_substr(r#"closest: [1] "grapes""#));
+    }
+
+    #[test]
+    fn long_candidate_lists_are_truncated() {
+        let message = try_expect!(100, to_be_one_of((0..50).collect::<Vec<_>>()))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("candidates: 50"));
+        expect!(&message, to_contain_substr("[19] 19"));
+        expect!(&message, not, to_contain_substr("[20] 20"));
+        expect!(&message, to_contain_substr("... and 30 more"));
+    }
+
+    #[test]
+    fn unbounded_candidates_short_circuit() {
+        expect!(5u64, to_be_one_of(0u64..));
+    }
+
+    #[test]
+    fn candidates_without_debug_are_supported() {
+        #[derive(PartialEq)]
+        struct NotDebug(i32);
+
+        expect!(NotDebug(1), to_be_one_of([NotDebug(1), NotDebug(2)]));
+        expect!(
+            try_expect!(NotDebug(3), to_be_one_of([NotDebug(1), NotDebug(2)])),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("candidates: 2"),
+        );
+    }
+
+    #[test]
+    fn nested_candidates_are_split() {
+        let message = try_expect!((1, "a, b"), to_be_one_of([(1, "a"), (2, "a, b")]))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr(r#"[0] (1, "a")"#));
+        expect!(&message, to_contain_substr(r#"[1] (2, "a, b")"#));
+
+        let message = try_expect!('x', to_be_one_of([',', '\'']))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("[0] ','"));
+        expect!(&message, to_contain_substr(r"[1] '\''"));
+    }
+
+    #[test]
+    fn unsplittable_candidates_are_shown_together() {
+        expect!(
+            try_expect!(100, to_be_one_of(0..50)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("items: 0..50"),
+        );
+    }
+
+    #[test]
+    fn empty_candidates_fail() {
+        expect!(1, not, to_be_one_of(Vec::<i32>::new()));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+use super::Integer;
+
+/// Asserts that the subject is a valid index into a collection of a given
+/// length.
+#[derive(Clone, Debug)]
+pub struct ToBeValidIndexFor {
+    len: Annotated<usize>,
+}
+
+impl ToBeValidIndexFor {
+    #[inline]
+    pub(crate) fn new(len: Annotated<usize>) -> Self {
+        Self { len }
+    }
+}
+
+impl<T> Assertion<T> for ToBeValidIndexFor
+where
+    T: Integer + TryInto<usize>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let len = *self.len.inner();
+        cx.annotate("length", self.len);
+
+        let Ok(index) = subject.try_into() else {
+            return cx.fail(format_args!("{subject:?} can't be used as an index"));
+        };
+
+        cx.pass_if(index < len, "index out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(0, 1; "first")]
+    #[test_case(4, 5; "last")]
+    fn valid_indexes(index: usize, len: usize) {
+        expect!(index, to_be_valid_index_for(len));
+    }
+
+    #[test_case(5, 5; "one past the end")]
+    #[test_case(0, 0; "empty")]
+    #[test_case(-1, 5; "negative")]
+    fn invalid_indexes(index: i64, len: usize) {
+        expect!(index, not, to_be_valid_index_for(len));
+    }
+
+    #[test]
+    fn length_is_annotated() {
+        let message = try_expect!(7usize, to_be_valid_index_for(3))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("length: 3"));
+        expect!(&message, to_contain_substr("index out of bounds"));
+    }
+}
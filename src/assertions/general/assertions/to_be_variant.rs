@@ -0,0 +1,102 @@
+use crate::{
+    assertions::{Assertion, AssertionBuilder, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+#[doc(hidden)]
+#[inline]
+pub fn __to_be_variant<T, M, F>(
+    _builder: &AssertionBuilder<T, M>,
+    predicate: F,
+    variant: &'static str,
+) -> ToBeVariant<F>
+where
+    F: FnOnce(&T) -> bool,
+{
+    ToBeVariant { predicate, variant }
+}
+
+/// Asserts that the subject is a particular enum variant. Created by the
+/// [`to_be_variant!`](crate::to_be_variant!) macro.
+#[derive(Clone, Debug)]
+pub struct ToBeVariant<F> {
+    predicate: F,
+    variant: &'static str,
+}
+
+impl<F, T> Assertion<T> for ToBeVariant<F>
+where
+    F: FnOnce(&T) -> bool,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected variant", self.variant);
+        cx.pass_if((self.predicate)(&subject), "was a different variant")
+    }
+
+    fn execute_annotated(self, mut cx: AssertionContext, subject: Annotated<T>) -> Self::Output
+    where
+        Self: Sized,
+    {
+        cx.annotate("expected variant", self.variant);
+        if let Some(debug) = subject.as_debug() {
+            // The variant name is everything before the fields, if any
+            let debug = format!("{debug:?}");
+            let end = debug.find(['(', '{', ' ']).unwrap_or(debug.len());
+            cx.annotate("actual variant", &debug[..end]);
+        }
+
+        cx.pass_if((self.predicate)(subject.inner()), "was a different variant")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    enum Shape {
+        Circle(f32),
+        Rectangle { width: f32, height: f32 },
+        Point,
+    }
+
+    #[test]
+    fn all_variant_kinds_work() {
+        expect!(Shape::Circle(1.0), to_be_variant!(Shape::Circle));
+        expect!(
+            Shape::Rectangle {
+                width: 1.0,
+                height: 2.0
+            },
+            to_be_variant!(Shape::Rectangle),
+        );
+        expect!(Shape::Point, to_be_variant!(Shape::Point));
+        expect!(Shape::Point, not, to_be_variant!(Shape::Circle));
+    }
+
+    #[test]
+    fn refs_work() {
+        let shape = Shape::Circle(1.0);
+        expect!(&shape, to_be_variant!(Shape::Circle));
+    }
+
+    #[test]
+    fn annotates_actual_variant() {
+        let error = try_expect!(
+            Shape::Rectangle {
+                width: 1.0,
+                height: 2.0
+            },
+            to_be_variant!(Shape::Point),
+        )
+        .unwrap_err();
+        expect!(
+            error.to_string(),
+            to_contain_substr("actual variant: Rectangle"),
+        );
+    }
+}
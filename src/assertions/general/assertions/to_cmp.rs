@@ -37,6 +37,7 @@ where
     T: PartialOrd<U>,
 {
     type Output = AssertionOutput;
+    const CAN_SHORT_CIRCUIT: bool = true;
 
     fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
         cx.annotate("boundary", &self.boundary);
@@ -62,4 +63,19 @@ where
         };
         cx.pass_if(success, format_args!("not {} boundary", self.cmp_message))
     }
+
+    fn is_determined_by(&self, subject: &T) -> bool {
+        // Once a subject is greater than the boundary, so is every greater
+        // subject. For `>`/`>=` this is a permanent pass, for `<`/`<=` this is a
+        // permanent failure.
+        match subject.partial_cmp(self.boundary.inner()) {
+            Some(Ordering::Greater) => self.ordering != Ordering::Equal,
+            Some(Ordering::Equal) => match self.ordering {
+                Ordering::Greater => self.allow_eq,
+                Ordering::Less => !self.allow_eq,
+                Ordering::Equal => false,
+            },
+            _ => false,
+        }
+    }
 }
@@ -0,0 +1,90 @@
+use std::cmp::Ordering;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that comparing the subject to another value with [`Ord::cmp`]
+/// produces an expected [`Ordering`].
+#[derive(Clone, Debug)]
+pub struct ToCompareAs<T> {
+    other: Annotated<T>,
+    expected: Annotated<Ordering>,
+}
+
+impl<T> ToCompareAs<T> {
+    #[inline]
+    pub(crate) fn new(other: Annotated<T>, expected: Annotated<Ordering>) -> Self {
+        Self { other, expected }
+    }
+}
+
+impl<T> Assertion<T> for ToCompareAs<T>
+where
+    T: Ord,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("other", &self.other);
+        let expected = *self.expected.inner();
+        cx.annotate("expected", self.expected);
+
+        let actual = subject.cmp(self.other.inner());
+        cx.annotate("actual", format_args!("{actual:?}"));
+        cx.pass_if(actual == expected, "orderings not equal")
+    }
+}
+
+/// Asserts that comparing the subject to another value with
+/// [`PartialOrd::partial_cmp`] produces an expected [`Ordering`], if any.
+#[derive(Clone, Debug)]
+pub struct ToPartiallyCompareAs<U> {
+    other: Annotated<U>,
+    expected: Annotated<Option<Ordering>>,
+}
+
+impl<U> ToPartiallyCompareAs<U> {
+    #[inline]
+    pub(crate) fn new(other: Annotated<U>, expected: Annotated<Option<Ordering>>) -> Self {
+        Self { other, expected }
+    }
+}
+
+impl<T, U> Assertion<T> for ToPartiallyCompareAs<U>
+where
+    T: PartialOrd<U>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("other", &self.other);
+        let expected = *self.expected.inner();
+        cx.annotate("expected", self.expected);
+
+        let actual = subject.partial_cmp(self.other.inner());
+        cx.annotate("actual", format_args!("{actual:?}"));
+        cx.pass_if(actual == expected, "orderings not equal")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn actual_ordering_is_annotated() {
+        expect!("b", to_compare_as("b", Ordering::Equal));
+        expect!(f64::NAN, to_partially_compare_as(1.0, None));
+        expect!(
+            try_expect!(2, to_compare_as(1, Ordering::Less)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("actual: Greater"),
+        );
+    }
+}
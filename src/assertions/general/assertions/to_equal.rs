@@ -5,6 +5,9 @@ use crate::{
     AssertionOutput,
 };
 
+/// The minimum length of a single-line representation that gets diffed.
+const MIN_DIFF_LEN: usize = 32;
+
 /// Asserts that the subject is equal to an expected value.
 #[derive(Clone, Debug)]
 pub struct ToEqual<U> {
@@ -30,11 +33,12 @@ where
         Self: Sized,
     {
         // Only diff if the values are different
-        cx.annotate("expected", &self.expected);
         if subject.inner() == self.expected.inner() {
-            return cx.pass();
+            return pass(cx, &self.expected);
         }
 
+        cx.annotate("expected", &self.expected);
+
         // Get string representations of values
         let (subject_repr, expected_repr) = if let Some((subject, expected)) =
             subject.as_display().zip(self.expected.as_display())
@@ -46,9 +50,10 @@ where
             return cx.fail("values not equal");
         };
 
-        // Skip the diff if the representations aren't multiline to avoid
-        // cluttering the output
-        if subject_repr.contains('\n') || expected_repr.contains('\n') {
+        // Skip the diff if the representations are short and aren't multiline
+        // to avoid cluttering the output
+        let is_long = |repr: &str| repr.contains('\n') || repr.len() >= MIN_DIFF_LEN;
+        if is_long(&subject_repr) || is_long(&expected_repr) {
             // Perform the diff
             if let Some(diff) = fmt_diff(&expected_repr, &subject_repr) {
                 cx.add_page("diff", diff);
@@ -60,11 +65,26 @@ where
 
     #[inline]
     fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        if subject == *self.expected.inner() {
+            return pass(cx, &self.expected);
+        }
+
         cx.annotate("expected", &self.expected);
-        cx.pass_if(subject == self.expected.into_inner(), "values not equal")
+        cx.fail("values not equal")
     }
 }
 
+/// Passes without formatting the expected value unless a modifier (like `not`)
+/// turns the success into a failure. Formatting large values can be expensive,
+/// and the representation is only needed to explain a failure.
+fn pass<U>(mut cx: AssertionContext, expected: &Annotated<U>) -> AssertionOutput {
+    if cx.is_inverted() {
+        cx.annotate("expected", expected);
+    }
+
+    cx.pass()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -81,6 +101,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn expected_is_not_formatted_on_success() {
+        struct Subject;
+        struct Expected;
+
+        impl PartialEq<Expected> for Subject {
+            fn eq(&self, _: &Expected) -> bool {
+                true
+            }
+        }
+
+        impl std::fmt::Debug for Expected {
+            fn fmt(&self, _: &mut std::fmt::Formatter) -> std::fmt::Result {
+                panic!("formatted the expected value")
+            }
+        }
+
+        expect!(Subject, to_equal(Expected));
+        expect!(Subject, not, not, to_equal(Expected));
+    }
+
+    #[test]
+    fn expected_is_formatted_when_inverted() {
+        expect!(
+            try_expect!(vec![1, 2, 3], not, to_equal(vec![1, 2, 3])),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("expected: [1, 2, 3]"),
+        );
+    }
+
     #[test]
     #[cfg(feature = "diff")]
     fn do_diff() {
@@ -92,4 +143,20 @@ mod tests {
             to_contain_substr("diff"),
         );
     }
+
+    #[test]
+    #[cfg(feature = "diff")]
+    fn do_diff_long_display() {
+        // Show diffs for long single-line values, like the output of as_display
+        expect!(
+            try_expect!(
+                "the quick brown fox jumps over the lazy dog",
+                as_display,
+                to_equal("the quick brown fox jumped over the lazy dog"),
+            ),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("diff"),
+        );
+    }
 }
@@ -1,3 +1,8 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Sub},
+};
+
 use crate::{
     assertions::{Assertion, AssertionContext},
     metadata::Annotated,
@@ -24,31 +29,362 @@ impl<T> ToEqualApprox<T> {
 impl Assertion<f32> for ToEqualApprox<f32> {
     type Output = AssertionOutput;
 
-    fn execute(self, mut cx: AssertionContext, subject: f32) -> Self::Output {
-        let expected = self.expected.into_inner();
-        let max_delta = self.max_delta.into_inner();
-        let range = (expected - max_delta)..=(expected + max_delta);
-
-        cx.annotate("expected", format_args!("{range:?}"));
-        cx.pass_if(range.contains(&subject), "out of expected range")
+    #[inline]
+    fn execute(self, cx: AssertionContext, subject: f32) -> Self::Output {
+        self.check(cx, subject)
     }
 }
 
 impl Assertion<f64> for ToEqualApprox<f64> {
     type Output = AssertionOutput;
 
-    fn execute(self, mut cx: AssertionContext, subject: f64) -> Self::Output {
-        let expected = self.expected.into_inner();
-        let max_delta = self.max_delta.into_inner();
-        let range = (expected - max_delta)..=(expected + max_delta);
+    #[inline]
+    fn execute(self, cx: AssertionContext, subject: f64) -> Self::Output {
+        self.check(cx, subject)
+    }
+}
+
+impl<T> ToEqualApprox<T>
+where
+    T: Float,
+{
+    fn check(self, mut cx: AssertionContext, subject: T) -> AssertionOutput {
+        let expected = *self.expected.inner();
+        cx.annotate("expected", self.expected);
+        let max_delta = *self.max_delta.inner();
+        cx.annotate("max delta", self.max_delta);
+
+        let lower = expected - max_delta;
+        let upper = expected + max_delta;
+        cx.annotate("interval", format_args!("[{lower:?}, {upper:?}]"));
+        if subject < lower {
+            let distance = lower - subject;
+            cx.annotate("position", format_args!("{distance:?} below lower bound"));
+        } else if subject > upper {
+            let distance = subject - upper;
+            cx.annotate("position", format_args!("{distance:?} above upper bound"));
+        } else if subject >= lower && subject <= upper {
+            return cx.pass();
+        } else {
+            cx.annotate("position", "not comparable to the interval");
+        }
 
-        cx.annotate("expected", format_args!("{range:?}"));
-        cx.pass_if(range.contains(&subject), "out of expected range")
+        cx.fail("out of expected range")
     }
 }
 
+/// Asserts that the subject differs from another value by at least a minimum
+/// amount.
+#[derive(Clone, Debug)]
+pub struct ToDifferBy<T> {
+    other: Annotated<T>,
+    min_delta: Annotated<T>,
+}
+
+impl<T> ToDifferBy<T> {
+    #[inline]
+    pub(crate) fn new(other: Annotated<T>, min_delta: Annotated<T>) -> Self {
+        Self { other, min_delta }
+    }
+}
+
+impl<T> Assertion<T> for ToDifferBy<T>
+where
+    T: Float,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let other = *self.other.inner();
+        cx.annotate("other", self.other);
+        let min_delta = *self.min_delta.inner();
+        cx.annotate("min delta", self.min_delta);
+
+        let delta = (subject - other).abs();
+        cx.annotate("delta", format_args!("{delta:?}"));
+        cx.pass_if(delta >= min_delta, "values too close")
+    }
+}
+
+/// Asserts that the subject is approximately zero.
+#[derive(Clone, Debug)]
+pub struct ToBeApproxZero<T> {
+    max_delta: Annotated<T>,
+}
+
+impl<T> ToBeApproxZero<T> {
+    #[inline]
+    pub(crate) fn new(max_delta: Annotated<T>) -> Self {
+        Self { max_delta }
+    }
+}
+
+impl<T> Assertion<T> for ToBeApproxZero<T>
+where
+    T: Float,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let max_delta = *self.max_delta.inner();
+        cx.annotate("max delta", self.max_delta);
+
+        let abs = subject.abs();
+        cx.annotate("abs", format_args!("{abs:?}"));
+        cx.pass_if(abs <= max_delta, "not close enough to zero")
+    }
+}
+
+/// Asserts that the subject is within a number of units in the last place
+/// (ULPs) of an expected value.
+#[derive(Clone, Debug)]
+pub struct ToEqualWithinUlps<T> {
+    expected: Annotated<T>,
+    max_ulps: Annotated<u64>,
+}
+
+impl<T> ToEqualWithinUlps<T> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<T>, max_ulps: Annotated<u64>) -> Self {
+        Self { expected, max_ulps }
+    }
+}
+
+impl<T> Assertion<T> for ToEqualWithinUlps<T>
+where
+    T: Float,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let expected = *self.expected.inner();
+        cx.annotate("expected", self.expected);
+        let max_ulps = *self.max_ulps.inner();
+        cx.annotate("max ulps", self.max_ulps);
+
+        let Some(ulps) = subject.ulps_from(expected) else {
+            return cx.fail("NaN is not within any distance of a value");
+        };
+
+        cx.annotate("ulps", ulps);
+        cx.pass_if(ulps <= max_ulps, "too many ulps apart")
+    }
+}
+
+/// Asserts that the subject differs from an expected value by at most a
+/// percentage of the expected value.
+#[derive(Clone, Debug)]
+pub struct ToBeWithinPercentage<T> {
+    expected: Annotated<T>,
+    percent: Annotated<f64>,
+}
+
+impl<T> ToBeWithinPercentage<T> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<T>, percent: Annotated<f64>) -> Self {
+        Self { expected, percent }
+    }
+}
+
+impl<T> Assertion<T> for ToBeWithinPercentage<T>
+where
+    T: AsF64,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", self.expected);
+        let expected = self.expected.into_inner().as_f64();
+        let max_percent = *self.percent.inner();
+        cx.annotate("max percentage", self.percent);
+
+        // The percentage is relative to the expected value, so it is only
+        // defined if the expected value is non-zero
+        let subject = subject.as_f64();
+        if expected == 0.0 {
+            return cx.pass_if(
+                subject == 0.0,
+                "expected value is zero, so only zero is within any percentage of it",
+            );
+        }
+
+        let percent = (subject - expected).abs() / expected.abs() * 100.0;
+        cx.annotate("percentage", format_args!("{percent}%"));
+        cx.pass_if(percent <= max_percent, "not within percentage")
+    }
+}
+
+#[doc(hidden)]
+pub trait Float: Copy + PartialOrd + Debug + Add<Output = Self> + Sub<Output = Self> {
+    #[must_use]
+    fn abs(self) -> Self;
+
+    /// Gets whether the value is neither infinite nor NaN.
+    #[must_use]
+    fn is_finite(self) -> bool;
+
+    /// Gets the number of representable values between `self` and `other`, or
+    /// [`None`] if either is NaN. Positive and negative zero are treated as
+    /// the same value.
+    #[must_use]
+    fn ulps_from(self, other: Self) -> Option<u64>;
+}
+
+macro_rules! impl_float {
+    ($($ty:ty => $bits:ty),*) => {
+        $(
+            impl Float for $ty {
+                #[inline]
+                fn abs(self) -> Self {
+                    <$ty>::abs(self)
+                }
+
+                #[inline]
+                fn is_finite(self) -> bool {
+                    <$ty>::is_finite(self)
+                }
+
+                #[inline]
+                fn ulps_from(self, other: Self) -> Option<u64> {
+                    // Maps the bits of a float onto a signed integer such that
+                    // the integers are ordered the same way as the floats and
+                    // both zeroes map to 0.
+                    fn ordered(value: $ty) -> $bits {
+                        #[allow(clippy::cast_possible_wrap)]
+                        let bits = value.to_bits() as $bits;
+                        if bits < 0 {
+                            <$bits>::MIN - bits
+                        } else {
+                            bits
+                        }
+                    }
+
+                    if self.is_nan() || other.is_nan() {
+                        return None;
+                    }
+
+                    Some(ordered(self).abs_diff(ordered(other)).into())
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32 => i32, f64 => i64);
+
+/// Converts a primitive number into an [`f64`], rounding to the nearest
+/// representable value if needed.
 #[doc(hidden)]
-pub trait Float {}
+pub trait AsF64: Copy {
+    #[must_use]
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! impl_as_f64 {
+    ($($ty:ty),*) => {
+        $(
+            impl AsF64 for $ty {
+                #[inline]
+                #[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_as_f64!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32);
+
+impl AsF64 for f64 {
+    #[inline]
+    fn as_f64(self) -> f64 {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn approx_interval_is_annotated() {
+        expect!(1.1f32, to_equal_approximately(1.0, 0.25));
+        expect!(f64::NAN, not, to_equal_approximately(1.0, 0.25));
+
+        let message = try_expect!(0.5f64, to_equal_approximately(1.0, 0.25))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("interval: [0.75, 1.25]"));
+        expect!(
+            &message,
+            to_contain_substr("position: 0.25 below lower bound")
+        );
+
+        let message = try_expect!(2.0f64, to_equal_approximately(1.0, 0.25))
+            .unwrap_err()
+            .to_string();
+        expect!(
+            &message,
+            to_contain_substr("position: 0.75 above upper bound")
+        );
+    }
+
+    #[test]
+    fn differ_by_is_inclusive() {
+        expect!(1.5, to_differ_by_at_least(1.0, 0.5));
+        expect!(0.5, to_differ_by_at_least(1.0, 0.5));
+        expect!(1.4, not, to_differ_by_at_least(1.0, 0.5));
+    }
+
+    #[test]
+    fn percentage_is_relative_to_expected() {
+        expect!(95.0, to_be_within_percentage(100.0, 5.0));
+        expect!(-105.0f32, to_be_within_percentage(-100.0, 5.0));
+        expect!(0.0, to_be_within_percentage(0.0, 1.0));
+        expect!(1e-9, not, to_be_within_percentage(0.0, 1.0));
+        expect!(
+            try_expect!(90.0, to_be_within_percentage(100.0, 5.0)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("percentage: 10%"),
+        );
+    }
+
+    #[test]
+    fn percentage_supports_integers() {
+        expect!(95, to_be_within_percentage(100, 5.0));
+        expect!(94u8, not, to_be_within_percentage(100, 5.0));
+        expect!(-105i16, to_be_within_percentage(-100, 5.0));
+        expect!(105usize, to_be_within_percentage(100, 5.0));
+        expect!(106usize, not, to_be_within_percentage(100, 5.0));
+        expect!(u64::MAX, to_be_within_percentage(u64::MAX - 1, 1.0));
+    }
 
-impl Float for f32 {}
-impl Float for f64 {}
+    #[test]
+    fn ulps_cross_zero() {
+        expect!(0.0, to_equal_within_ulps(-0.0, 0));
+        expect!(
+            f64::from_bits(1),
+            to_equal_within_ulps(-f64::from_bits(1), 2)
+        );
+        expect!(
+            f64::from_bits(1),
+            not,
+            to_equal_within_ulps(-f64::from_bits(1), 1)
+        );
+        expect!(f32::MAX, to_equal_within_ulps(f32::INFINITY, 1));
+    }
+
+    #[test]
+    fn nan_is_never_within_ulps() {
+        expect!(f32::NAN, not, to_equal_within_ulps(f32::NAN, u64::MAX));
+        expect!(1.0, not, to_equal_within_ulps(f64::NAN, u64::MAX));
+    }
+
+    #[test]
+    fn nan_never_differs() {
+        expect!(f64::NAN, not, to_differ_by_at_least(1.0, 0.5));
+        expect!(1.0, not, to_differ_by_at_least(f64::NAN, 0.5));
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt::Debug;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject has a property of integers, like being even or
+/// being a power of two.
+#[derive(Clone, Debug)]
+pub struct ToHaveIntegerProperty<T> {
+    property: IntegerProperty<T>,
+}
+
+impl<T> ToHaveIntegerProperty<T> {
+    #[inline]
+    pub(crate) fn new(property: IntegerProperty<T>) -> Self {
+        Self { property }
+    }
+}
+
+impl<T> Assertion<T> for ToHaveIntegerProperty<T>
+where
+    T: Integer,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("actual", format_args!("{subject:?}"));
+
+        let (pass, expected) = match self.property {
+            IntegerProperty::PowerOfTwo => (subject.is_power_of_two(), "a power of two".into()),
+            IntegerProperty::Even => (subject.is_multiple_of(T::TWO), "even".into()),
+            IntegerProperty::Odd => (!subject.is_multiple_of(T::TWO), "odd".into()),
+            IntegerProperty::MultipleOf(divisor) => {
+                let divisor = divisor.into_inner();
+                let expected = format!("a multiple of {divisor:?}");
+                (subject.is_multiple_of(divisor), expected)
+            }
+        };
+        cx.pass_if(pass, format_args!("{subject:?} is not {expected}"))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum IntegerProperty<T> {
+    PowerOfTwo,
+    Even,
+    Odd,
+    MultipleOf(Annotated<T>),
+}
+
+#[doc(hidden)]
+pub trait Integer: Copy + Debug {
+    const TWO: Self;
+
+    #[must_use]
+    fn is_power_of_two(self) -> bool;
+
+    #[must_use]
+    fn is_multiple_of(self, divisor: Self) -> bool;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Integer for $ty {
+                const TWO: Self = 2;
+
+                #[inline]
+                fn is_power_of_two(self) -> bool {
+                    self > 0 && self & (self - 1) == 0
+                }
+
+                #[inline]
+                fn is_multiple_of(self, divisor: Self) -> bool {
+                    match self.checked_rem(divisor) {
+                        Some(rem) => rem == 0,
+                        // Only zero is a multiple of zero, and the remainder
+                        // overflows only for MIN % -1, which is a multiple
+                        None => divisor != 0 || self == 0,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(1)]
+    #[test_case(2)]
+    #[test_case(64)]
+    #[test_case(1 << 62)]
+    fn powers_of_two(n: i64) {
+        expect!(n, to_be_power_of_two);
+    }
+
+    #[test_case(0)]
+    #[test_case(3)]
+    #[test_case(-2)]
+    #[test_case(i64::MIN)]
+    fn not_powers_of_two(n: i64) {
+        expect!(n, not, to_be_power_of_two);
+    }
+
+    #[test]
+    fn multiples() {
+        expect!(0, to_be_multiple_of(0));
+        expect!(1, not, to_be_multiple_of(0));
+        expect!(i32::MIN, to_be_multiple_of(-1));
+        expect!(-6, to_be_multiple_of(3));
+        expect!(-3, to_be_odd);
+        expect!(0u8, to_be_even);
+    }
+
+    #[test]
+    fn message_includes_value() {
+        expect!(
+            try_expect!(37, to_be_multiple_of(5)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("37 is not a multiple of 5"),
+        );
+    }
+}
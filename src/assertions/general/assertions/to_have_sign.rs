@@ -0,0 +1,91 @@
+use std::cmp::Ordering;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// Asserts that the subject has a particular sign. The sign is determined by
+/// comparing the subject to its type's [`Default`] value (usually zero).
+#[derive(Clone, Debug)]
+pub struct ToHaveSign {
+    expected: Sign,
+}
+
+impl ToHaveSign {
+    #[inline]
+    pub(crate) fn new(expected: Sign) -> Self {
+        Self { expected }
+    }
+}
+
+impl<T> Assertion<T> for ToHaveSign
+where
+    T: PartialOrd + Default,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let (message, pass) = match subject.partial_cmp(&T::default()) {
+            Some(Ordering::Less) => ("negative", self.expected == Sign::Negative),
+            Some(Ordering::Equal) => (
+                "zero",
+                matches!(self.expected, Sign::Zero | Sign::NonNegative),
+            ),
+            Some(Ordering::Greater) => (
+                "positive",
+                matches!(self.expected, Sign::Positive | Sign::NonNegative),
+            ),
+            None => ("not comparable to zero", false),
+        };
+
+        cx.annotate("expected", self.expected.as_str());
+        cx.annotate("actual", message);
+        cx.pass_if(pass, format_args!("value is {message}"))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Sign {
+    Positive,
+    Negative,
+    NonNegative,
+    Zero,
+}
+
+impl Sign {
+    fn as_str(self) -> &'static str {
+        match self {
+            Sign::Positive => "positive",
+            Sign::Negative => "negative",
+            Sign::NonNegative => "non-negative",
+            Sign::Zero => "zero",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn zero_is_non_negative() {
+        expect!(0, to_be_zero);
+        expect!(0, to_be_non_negative);
+        expect!(0, not, to_be_positive);
+        expect!(0, not, to_be_negative);
+    }
+
+    #[test]
+    fn nan_has_no_sign() {
+        expect!(f64::NAN, not, to_be_positive);
+        expect!(f64::NAN, not, to_be_negative);
+        expect!(f64::NAN, not, to_be_non_negative);
+        expect!(f64::NAN, not, to_be_zero);
+    }
+
+    #[test]
+    fn signed_zero_is_zero() {
+        expect!(-0.0, to_be_zero);
+    }
+}
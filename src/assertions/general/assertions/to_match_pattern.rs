@@ -0,0 +1,60 @@
+use crate::{
+    assertions::{Assertion, AssertionBuilder, AssertionContext},
+    AssertionOutput,
+};
+
+#[doc(hidden)]
+#[inline]
+pub fn __to_match_pattern<T, M, F>(
+    _builder: &AssertionBuilder<T, M>,
+    predicate: F,
+    pattern: &'static str,
+) -> ToMatchPattern<F>
+where
+    F: FnOnce(T) -> bool,
+{
+    ToMatchPattern { predicate, pattern }
+}
+
+/// Asserts that the subject matches a pattern. Created by the
+/// [`to_match_pattern!`](crate::to_match_pattern!) macro.
+#[derive(Clone, Debug)]
+pub struct ToMatchPattern<F> {
+    predicate: F,
+    pattern: &'static str,
+}
+
+impl<F, T> Assertion<T> for ToMatchPattern<F>
+where
+    F: FnOnce(T) -> bool,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("pattern", self.pattern);
+        cx.pass_if((self.predicate)(subject), "did not match pattern")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn guards_can_use_bindings() {
+        expect!(Some(1), to_match_pattern!(Some(n) if n > 0));
+        expect!(Some(-1), not, to_match_pattern!(Some(n) if n > 0));
+        expect!(None::<i32>, not, to_match_pattern!(Some(n) if n > 0));
+    }
+
+    #[test]
+    fn or_patterns_work() {
+        expect!(["a", "b", "c"], all, to_match_pattern!("a" | "b" | "c"));
+        expect!(
+            ["a", "b", "d"],
+            not,
+            all,
+            to_match_pattern!("a" | "b" | "c")
+        );
+    }
+}
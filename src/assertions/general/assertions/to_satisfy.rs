@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use crate::{
     assertions::{Assertion, AssertionContext},
     metadata::Annotated,
@@ -31,3 +33,106 @@ where
         )
     }
 }
+
+/// Asserts that the subject satisfies a predicate, describing the predicate by
+/// name instead of by its source.
+#[derive(Clone, Debug)]
+pub struct ToSatisfyNamed<N, F> {
+    name: Annotated<N>,
+    predicate: F,
+}
+
+impl<N, F> ToSatisfyNamed<N, F> {
+    #[inline]
+    pub(crate) fn new(name: Annotated<N>, predicate: Annotated<F>) -> Self {
+        Self {
+            name,
+            predicate: predicate.into_inner(),
+        }
+    }
+}
+
+impl<N, F, T> Assertion<T> for ToSatisfyNamed<N, F>
+where
+    N: Display,
+    F: FnOnce(T) -> bool,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let name = self.name.into_inner();
+        cx.annotate("predicate", &name);
+        cx.pass_if(
+            (self.predicate)(subject),
+            format_args!("did not satisfy {name}"),
+        )
+    }
+}
+
+/// Asserts that the subject satisfies a predicate, using the reason returned by
+/// the predicate as the failure message.
+#[derive(Clone, Debug)]
+pub struct ToSatisfyOrExplain<F> {
+    predicate: Annotated<F>,
+}
+
+impl<F> ToSatisfyOrExplain<F> {
+    #[inline]
+    pub(crate) fn new(predicate: Annotated<F>) -> Self {
+        Self { predicate }
+    }
+}
+
+impl<F, T, E> Assertion<T> for ToSatisfyOrExplain<F>
+where
+    F: FnOnce(T) -> Result<(), E>,
+    E: Display,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("predicate", &self.predicate);
+        match (self.predicate.into_inner())(subject) {
+            Ok(()) => cx.pass(),
+            Err(reason) => cx.fail(reason),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn name_replaces_predicate_source() {
+        let message = try_expect!(
+            "not an email",
+            to_satisfy_named("is_valid_email", |s: &str| s.contains('@'))
+        )
+        .unwrap_err()
+        .to_string();
+        expect!(
+            &message,
+            to_contain_substr("did not satisfy is_valid_email")
+        );
+        expect!(&message, to_contain_substr("predicate: is_valid_email"));
+        expect!(&message, not, to_contain_substr("contains"));
+    }
+
+    #[test]
+    fn reason_is_failure_message() {
+        expect!(
+            try_expect!(
+                3,
+                to_satisfy_or_explain(|n| if n % 2 == 0 {
+                    Ok(())
+                } else {
+                    Err(format!("{n} is odd"))
+                })
+            ),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("to_satisfy_or_explain: 3 is odd"),
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::{thread::sleep, time::Duration};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject satisfies a predicate within a number of attempts.
+#[derive(Clone, Debug)]
+pub struct ToSatisfyEventually<F> {
+    predicate: Annotated<F>,
+    attempts: Annotated<usize>,
+    delay: Annotated<Duration>,
+}
+
+impl<F> ToSatisfyEventually<F> {
+    #[inline]
+    pub(crate) fn new(
+        predicate: Annotated<F>,
+        attempts: Annotated<usize>,
+        delay: Annotated<Duration>,
+    ) -> Self {
+        Self {
+            predicate,
+            attempts,
+            delay,
+        }
+    }
+}
+
+impl<F, T> Assertion<T> for ToSatisfyEventually<F>
+where
+    F: FnMut(&T) -> bool,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("predicate", &self.predicate);
+        let max_attempts = *self.attempts.inner();
+        cx.annotate("max attempts", self.attempts);
+        let delay = *self.delay.inner();
+        cx.annotate("delay", self.delay);
+
+        let mut predicate = self.predicate.into_inner();
+        for attempt in 1..=max_attempts {
+            if attempt > 1 {
+                sleep(delay);
+            }
+
+            if predicate(&subject) {
+                cx.annotate("attempts", attempt);
+                return cx.pass();
+            }
+        }
+
+        cx.annotate("attempts", max_attempts);
+        cx.fail("did not satisfy predicate")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, time::Duration};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn passes_on_later_attempt() {
+        let calls = Cell::new(0);
+        expect!(
+            &calls,
+            to_satisfy_eventually(
+                |calls: &&Cell<i32>| {
+                    calls.set(calls.get() + 1);
+                    calls.get() == 3
+                },
+                5,
+                Duration::ZERO,
+            ),
+        );
+        expect!(calls.get(), to_equal(3));
+    }
+
+    #[test]
+    fn fails_after_every_attempt() {
+        let calls = Cell::new(0);
+        expect!(
+            &calls,
+            not,
+            to_satisfy_eventually(
+                |calls: &&Cell<i32>| {
+                    calls.set(calls.get() + 1);
+                    false
+                },
+                4,
+                Duration::ZERO,
+            ),
+        );
+        expect!(calls.get(), to_equal(4));
+    }
+}
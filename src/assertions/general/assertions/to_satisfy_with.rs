@@ -32,6 +32,47 @@ where
     }
 }
 
+/// Asserts that the subject satisfies at least one of two series of
+/// assertions.
+#[derive(Clone, Debug)]
+pub struct ToSatisfyEither<F, G> {
+    first: Annotated<F>,
+    second: Annotated<G>,
+}
+
+impl<F, G> ToSatisfyEither<F, G> {
+    #[inline]
+    pub(crate) fn new(first: Annotated<F>, second: Annotated<G>) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<F, G, T> Assertion<T> for ToSatisfyEither<F, G>
+where
+    F: FnOnce(T) -> Result<(), AssertionError>,
+    G: FnOnce(T) -> Result<(), AssertionError>,
+    T: Clone,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("first", &self.first);
+        cx.annotate("second", &self.second);
+
+        // The second branch is only executed if the first one fails
+        let Err(first) = (self.first.into_inner())(subject.clone()) else {
+            return cx.pass();
+        };
+        let Err(second) = (self.second.into_inner())(subject) else {
+            return cx.pass();
+        };
+
+        cx.add_page("first", first);
+        cx.add_page("second", second);
+        cx.fail("neither branch passed")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -40,4 +81,40 @@ mod tests {
     fn vacuous() {
         expect!(1, to_satisfy_with(|_| Ok(())));
     }
+
+    #[test]
+    fn either_branch_can_pass() {
+        expect!(
+            "42",
+            to_satisfy_either(
+                |s| try_expect!(s, to_equal("forty-two")),
+                |s| try_expect!(s, try_map(str::parse::<i32>), to_equal(42)),
+            ),
+        );
+        expect!(
+            5,
+            to_satisfy_either(
+                |n| try_expect!(n, to_be_less_than(10)),
+                |_| panic!("second branch should not run"),
+            ),
+        );
+    }
+
+    #[test]
+    fn both_failures_are_reported() {
+        let message = try_expect!(
+            5,
+            to_satisfy_either(
+                |n| try_expect!(n, to_be_less_than(0)),
+                |n| try_expect!(n, to_be_greater_than(10)),
+            ),
+        )
+        .unwrap_err()
+        .to_string();
+        expect!(&message, to_contain_substr("neither branch passed"));
+        expect!(&message, to_contain_substr("----- first"));
+        expect!(&message, to_contain_substr("----- second"));
+        expect!(&message, to_contain_substr("to_be_less_than"));
+        expect!(&message, to_contain_substr("to_be_greater_than"));
+    }
 }
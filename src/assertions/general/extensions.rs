@@ -1,4 +1,8 @@
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    time::Duration,
+};
 
 use crate::{
     assertions::{AssertionBuilder, AssertionError},
@@ -6,8 +10,12 @@ use crate::{
 };
 
 use super::{
-    Float, MapModifier, NotModifier, ToBeOneOf, ToCmp, ToEqual, ToEqualApprox, ToSatisfy,
-    ToSatisfyWith,
+    AnnotateWithModifier, ApproxEq, AsF64, Float, Integer, IntegerProperty, MapKeepingModifier,
+    MapModifier, NotModifier, Sign, ToBeApproxEqual, ToBeApproxOneOf, ToBeApproxZero, ToBeOneOf,
+    ToBeValidIndexFor, ToBeWithinPercentage, ToCmp, ToCompareAs, ToDifferBy, ToEqual,
+    ToEqualApprox, ToEqualWithinUlps, ToHaveIntegerProperty, ToHaveSign, ToPartiallyCompareAs,
+    ToSatisfy, ToSatisfyEither, ToSatisfyEventually, ToSatisfyNamed, ToSatisfyOrExplain,
+    ToSatisfyWith, TryMapModifier, WithFloatToleranceModifier, WithTolerance,
 };
 
 /// General-purpose assertions and modifiers.
@@ -86,6 +94,158 @@ pub trait GeneralAssertions<T, M> {
     where
         F: FnOnce(T) -> U;
 
+    /// Same as [`map`](GeneralAssertions::map), but the output type is the only
+    /// generic parameter, so it can be specified with a turbofish. This is
+    /// useful for mapping with method references whose output type can't be
+    /// inferred, like [`Into::into`] or [`str::parse`].
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(5u8, map_as::<u64>(Into::into), to_equal(5));
+    /// expect!("5", map_as::<Result<i32, _>>(str::parse), to_be_ok_and, to_equal(5));
+    /// ```
+    ///
+    /// The assertion fails if the mapped subject does not satisfy the assertion:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(5u8, map_as::<u64>(Into::into), to_equal(6));
+    /// ```
+    fn map_as<U>(
+        self,
+        f: Annotated<impl FnOnce(T) -> U>,
+    ) -> AssertionBuilder<U, MapModifier<M, impl FnOnce(T) -> U>>;
+
+    /// Executes the rest of the assertion on both the subject and a value mapped
+    /// from it. The assertion succeeds only if it succeeds for both values. This
+    /// is useful for checking that a property holds for a value and one of its
+    /// transformations without writing two assertions.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     String::from(" Hi "),
+    ///     map_keeping(|s: &String| s.trim().to_string()),
+    ///     to_contain_substr("Hi"),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if either value does not satisfy the assertion.
+    /// Whether the original or the mapped value failed is included in the
+    /// failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     String::from(" Hi "),
+    ///     map_keeping(|s: &String| s.trim().to_string()),
+    ///     to_start_with("Hi"),
+    /// );
+    /// ```
+    ///
+    /// The mapped value may have a different type than the subject, as long as
+    /// the rest of the assertion can be executed on both types with the same
+    /// output type. Note that [`expect!`](crate::expect!) records the value
+    /// passed to each step of the chain for the subject's type only, so the
+    /// mapped value must have the same type when used in that macro.
+    ///
+    /// Like [`all`](crate::prelude::IteratorAssertions::all), this requires
+    /// that the rest of the assertion is [`Clone`].
+    fn map_keeping<U, F>(self, f: Annotated<F>) -> AssertionBuilder<T, MapKeepingModifier<M, F>>
+    where
+        F: FnOnce(&T) -> U;
+
+    /// Compares the subject within a tolerance in the rest of the assertion.
+    /// The subject is wrapped in a [`WithTolerance`], which implements
+    /// [`PartialEq`] approximately, so [`to_equal`] and [`to_be_one_of`]
+    /// compare the whole subject approximately instead of exactly.
+    ///
+    /// Other assertions aren't affected. For example, iterator assertions like
+    /// [`to_contain`] can't be used after this modifier since the wrapped
+    /// subject isn't an iterator.
+    ///
+    /// This works for any subject that implements [`ApproxEq`], including
+    /// floats, as well as arrays, tuples, slices, and [`Vec`]s of floats.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(0.1 + 0.2, with_float_tolerance(1e-9), to_equal(0.3));
+    /// expect!(
+    ///     vec![0.1 + 0.2, 1.0],
+    ///     with_float_tolerance(1e-9),
+    ///     to_equal(vec![0.3, 1.0]),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the values differ by more than the tolerance:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(1.0, with_float_tolerance(0.1), to_equal(1.5));
+    /// ```
+    ///
+    /// [`to_equal`]: GeneralAssertions::to_equal
+    /// [`to_be_one_of`]: GeneralAssertions::to_be_one_of
+    /// [`to_contain`]: crate::prelude::IteratorAssertions::to_contain
+    fn with_float_tolerance<D>(
+        self,
+        tolerance: Annotated<D>,
+    ) -> AssertionBuilder<WithTolerance<T, D>, WithFloatToleranceModifier<M, D>>;
+
+    /// Applies a fallible mapping function to the subject before executing an
+    /// assertion. This is useful for transformations that can fail, like
+    /// parsing.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!("42", try_map(str::parse::<i32>), to_equal(42));
+    /// ```
+    ///
+    /// The assertion fails if the mapping function returns an error. The error
+    /// is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!("forty-two", try_map(str::parse::<i32>), to_equal(42));
+    /// ```
+    fn try_map<U, E, F>(self, f: Annotated<F>) -> AssertionBuilder<U, TryMapModifier<M, F>>
+    where
+        E: Debug,
+        F: FnOnce(T) -> Result<U, E>;
+
+    /// Adds an annotation to the failure message that is computed from the
+    /// subject, then executes an assertion on the unchanged subject. This is
+    /// useful for including information derived from the subject in failure
+    /// messages, like a summary of a large value.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     [1, 2, 3],
+    ///     annotate_with("sum", |items: &[i32; 3]| items.iter().sum::<i32>().to_string()),
+    ///     to_contain(2),
+    /// );
+    /// ```
+    ///
+    /// The annotation appears in the failure message if the assertion fails:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// // The failure message includes "sum: 6"
+    /// expect!(
+    ///     [1, 2, 3],
+    ///     annotate_with("sum", |items: &[i32; 3]| items.iter().sum::<i32>().to_string()),
+    ///     to_contain(4),
+    /// );
+    /// ```
+    fn annotate_with<F>(
+        self,
+        key: Annotated<&'static str>,
+        f: Annotated<F>,
+    ) -> AssertionBuilder<T, AnnotateWithModifier<M, F>>
+    where
+        F: FnOnce(&T) -> String;
+
     /// Asserts that the subject matches the given predicate.
     ///
     /// ```
@@ -122,6 +282,80 @@ pub trait GeneralAssertions<T, M> {
         ToSatisfy::new(predicate)
     }
 
+    /// Asserts that the subject satisfies a predicate, describing the predicate
+    /// by the given name in failure messages instead of by its source. This is
+    /// useful for predicates that are shared between many tests.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// fn is_valid_email(s: &str) -> bool {
+    ///     s.contains('@')
+    /// }
+    ///
+    /// expect!("a@b.com", to_satisfy_named("is_valid_email", is_valid_email));
+    /// ```
+    ///
+    /// The assertion fails if the subject does not satisfy the predicate. The
+    /// name of the predicate is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// # fn is_valid_email(s: &str) -> bool {
+    /// #     s.contains('@')
+    /// # }
+    /// expect!("a.b.com", to_satisfy_named("is_valid_email", is_valid_email));
+    /// ```
+    #[inline]
+    fn to_satisfy_named<N, F>(
+        &self,
+        name: Annotated<N>,
+        predicate: Annotated<F>,
+    ) -> ToSatisfyNamed<N, F>
+    where
+        N: Display,
+        F: FnOnce(T) -> bool,
+    {
+        ToSatisfyNamed::new(name, predicate)
+    }
+
+    /// Asserts that the subject satisfies a predicate. If it doesn't, the
+    /// predicate returns the reason why, which becomes the failure message.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     "v1.2",
+    ///     to_satisfy_or_explain(|s: &str| match s.strip_prefix('v') {
+    ///         Some(_) => Ok(()),
+    ///         None => Err(format!("{s:?} is missing a 'v' prefix")),
+    ///     }),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the predicate returns an error:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     "1.2",
+    ///     to_satisfy_or_explain(|s: &str| match s.strip_prefix('v') {
+    ///         Some(_) => Ok(()),
+    ///         None => Err(format!("{s:?} is missing a 'v' prefix")),
+    ///     }),
+    /// );
+    /// ```
+    ///
+    /// Unlike [`to_satisfy_with`](GeneralAssertions::to_satisfy_with), the
+    /// predicate doesn't need to make assertions of its own.
+    #[inline]
+    fn to_satisfy_or_explain<F, E>(&self, predicate: Annotated<F>) -> ToSatisfyOrExplain<F>
+    where
+        F: FnOnce(T) -> Result<(), E>,
+        E: Display,
+    {
+        ToSatisfyOrExplain::new(predicate)
+    }
+
     /// Asserts that the subject matches a series of inner assertions. This
     /// "forks" the assertion, allowing an intermediate value to have several
     /// different assertions applied to it.
@@ -180,6 +414,109 @@ pub trait GeneralAssertions<T, M> {
         ToSatisfyWith::new(predicate)
     }
 
+    /// Asserts that the subject satisfies at least one of two series of
+    /// assertions. Each branch is a function that receives a copy of the
+    /// subject, like in [`to_satisfy_with`](GeneralAssertions::to_satisfy_with).
+    /// Unlike [`any`](crate::prelude::IteratorAssertions::any), the two branches
+    /// can perform completely different checks.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     "42",
+    ///     to_satisfy_either(
+    ///         |s| try_expect!(s, to_equal("forty-two")),
+    ///         |s| try_expect!(s, try_map(str::parse::<i32>), to_equal(42)),
+    ///     ),
+    /// );
+    /// ```
+    ///
+    /// The second branch is only executed if the first one fails. The
+    /// assertion fails if both branches fail, and each branch's failure is
+    /// included in the failure message as its own page:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     "42",
+    ///     to_satisfy_either(
+    ///         |s| try_expect!(s, to_equal("forty-two")),
+    ///         |s| try_expect!(s, try_map(str::parse::<i32>), to_equal(43)),
+    ///     ),
+    /// );
+    /// ```
+    #[inline]
+    fn to_satisfy_either<F, G>(
+        &self,
+        first: Annotated<F>,
+        second: Annotated<G>,
+    ) -> ToSatisfyEither<F, G>
+    where
+        F: FnOnce(T) -> Result<(), AssertionError>,
+        G: FnOnce(T) -> Result<(), AssertionError>,
+        T: Clone,
+    {
+        ToSatisfyEither::new(first, second)
+    }
+
+    /// Asserts that the subject satisfies a predicate within a number of
+    /// attempts. The predicate is called up to `attempts` times, waiting for
+    /// `delay` between each attempt, and the assertion passes as soon as the
+    /// predicate returns `true`.
+    ///
+    /// This is useful for polling state that changes over time, like shared
+    /// state updated by another thread. The number of attempts made is included
+    /// in the failure message.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::{
+    ///     sync::{
+    ///         atomic::{AtomicBool, Ordering},
+    ///         Arc,
+    ///     },
+    ///     thread::spawn,
+    ///     time::Duration,
+    /// };
+    ///
+    /// let ready = Arc::new(AtomicBool::new(false));
+    /// spawn({
+    ///     let ready = ready.clone();
+    ///     move || ready.store(true, Ordering::SeqCst)
+    /// });
+    /// expect!(
+    ///     ready,
+    ///     to_satisfy_eventually(
+    ///         |ready: &Arc<AtomicBool>| ready.load(Ordering::SeqCst),
+    ///         100,
+    ///         Duration::from_millis(10),
+    ///     ),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the predicate never returns `true`:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::time::Duration;
+    /// expect!(
+    ///     1,
+    ///     to_satisfy_eventually(|&n: &i32| n > 1, 3, Duration::from_millis(1)),
+    /// );
+    /// ```
+    #[inline]
+    fn to_satisfy_eventually<F>(
+        &self,
+        predicate: Annotated<F>,
+        attempts: Annotated<usize>,
+        delay: Annotated<Duration>,
+    ) -> ToSatisfyEventually<F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        ToSatisfyEventually::new(predicate, attempts, delay)
+    }
+
     /// Asserts that the subject is equal to the given value.
     ///
     /// ```
@@ -208,7 +545,9 @@ pub trait GeneralAssertions<T, M> {
     /// expect!(0.9, to_equal_approximately(1.0, 0.2));
     /// ```
     ///
-    /// The assertion fails if the subject is out of range:
+    /// The assertion fails if the subject is out of range. The acceptable
+    /// interval and how far outside of it the subject fell are included in the
+    /// failure message:
     ///
     /// ```should_panic
     /// # use expecters::prelude::*;
@@ -226,6 +565,209 @@ pub trait GeneralAssertions<T, M> {
         ToEqualApprox::new(expected, max_delta)
     }
 
+    /// Asserts that the subject is equal to an expected value within a
+    /// tolerance. Unlike
+    /// [`to_equal_approximately`](GeneralAssertions::to_equal_approximately),
+    /// this works on any type implementing [`ApproxEq`], including arrays and
+    /// tuples of floats.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!((1.0, 2.0), to_be_approximately_equal((1.05, 1.95), 0.1));
+    /// ```
+    ///
+    /// The assertion fails if the values are not equal within the tolerance:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1.0, 2.0], to_be_approximately_equal([1.0, 2.5], 0.1));
+    /// ```
+    #[inline]
+    fn to_be_approximately_equal<U, D>(
+        &self,
+        expected: Annotated<U>,
+        tolerance: Annotated<D>,
+    ) -> ToBeApproxEqual<U, D>
+    where
+        T: ApproxEq<U, Tolerance = D>,
+    {
+        ToBeApproxEqual::new(expected, tolerance)
+    }
+
+    /// Asserts that the subject differs from `expected` by at most `percent`
+    /// percent of `expected`. This is calculated as
+    /// `|subject - expected| / |expected| * 100`.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(104.0, to_be_within_percentage(100.0, 5.0));
+    /// ```
+    ///
+    /// The assertion fails if the subject is too far from `expected`. The
+    /// actual percentage is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(90.0, to_be_within_percentage(100.0, 5.0));
+    /// ```
+    ///
+    /// If `expected` is zero, then the assertion only passes if the subject is
+    /// also zero.
+    ///
+    /// This works for any primitive numeric subject. Integers are converted to
+    /// [`f64`] before comparing, so very large integers are rounded to the
+    /// nearest representable value:
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(104u32, to_be_within_percentage(100, 5.0));
+    /// expect!(104usize, to_be_within_percentage(100, 5.0));
+    /// ```
+    #[inline]
+    fn to_be_within_percentage(
+        &self,
+        expected: Annotated<T>,
+        percent: Annotated<f64>,
+    ) -> ToBeWithinPercentage<T>
+    where
+        T: AsF64,
+    {
+        ToBeWithinPercentage::new(expected, percent)
+    }
+
+    /// Asserts that the absolute value of the subject is at most `max_delta`.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(0.1 + 0.2 - 0.3, to_be_approximately_zero(1e-9));
+    /// ```
+    ///
+    /// The assertion fails if the subject is too far from zero, or if it is NaN:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(-0.5, to_be_approximately_zero(0.1));
+    /// ```
+    #[inline]
+    fn to_be_approximately_zero(&self, max_delta: Annotated<T>) -> ToBeApproxZero<T>
+    where
+        T: Float,
+    {
+        ToBeApproxZero::new(max_delta)
+    }
+
+    /// Asserts that the subject is within `max_ulps` units in the last place
+    /// (ULPs) of an expected value. This is the number of representable
+    /// floating-point values between the two, which scales with the magnitude
+    /// of the values being compared.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(0.1 + 0.2, to_equal_within_ulps(0.3, 1));
+    /// ```
+    ///
+    /// Positive and negative zero are considered equal. The assertion fails if
+    /// the values are too far apart, or if either value is NaN:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(1.0, to_equal_within_ulps(1.0 + 1e-12, 4));
+    /// ```
+    #[inline]
+    fn to_equal_within_ulps(
+        &self,
+        expected: Annotated<T>,
+        max_ulps: Annotated<u64>,
+    ) -> ToEqualWithinUlps<T>
+    where
+        T: Float,
+    {
+        ToEqualWithinUlps::new(expected, max_ulps)
+    }
+
+    /// Asserts that the subject differs from another value by at least a
+    /// minimum amount.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(1.5, to_differ_by_at_least(1.0, 0.2));
+    /// ```
+    ///
+    /// The assertion fails if the values are too close:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(1.1, to_differ_by_at_least(1.0, 0.2));
+    /// ```
+    #[inline]
+    fn to_differ_by_at_least(&self, other: Annotated<T>, min_delta: Annotated<T>) -> ToDifferBy<T>
+    where
+        T: Float,
+    {
+        ToDifferBy::new(other, min_delta)
+    }
+
+    /// Asserts that comparing the subject to another value with [`Ord::cmp`]
+    /// produces the given [`Ordering`]. This is useful for testing [`Ord`]
+    /// implementations directly, including when the values are expected to be
+    /// equal.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// expect!("apple", to_compare_as("banana", Ordering::Less));
+    /// ```
+    ///
+    /// The assertion fails if the comparison produces a different ordering.
+    /// The actual ordering is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// expect!("apple", to_compare_as("banana", Ordering::Greater));
+    /// ```
+    #[inline]
+    fn to_compare_as(&self, other: Annotated<T>, ordering: Annotated<Ordering>) -> ToCompareAs<T>
+    where
+        T: Ord,
+    {
+        ToCompareAs::new(other, ordering)
+    }
+
+    /// Asserts that comparing the subject to another value with
+    /// [`PartialOrd::partial_cmp`] produces the given result. Use [`None`] to
+    /// check that the values are incomparable.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// expect!(1.0, to_partially_compare_as(2.0, Some(Ordering::Less)));
+    /// expect!(f64::NAN, to_partially_compare_as(2.0, None));
+    /// ```
+    ///
+    /// The assertion fails if the comparison produces a different result:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// expect!(f64::NAN, to_partially_compare_as(2.0, Some(Ordering::Less)));
+    /// ```
+    #[inline]
+    fn to_partially_compare_as<U>(
+        &self,
+        other: Annotated<U>,
+        ordering: Annotated<Option<Ordering>>,
+    ) -> ToPartiallyCompareAs<U>
+    where
+        T: PartialOrd<U>,
+    {
+        ToPartiallyCompareAs::new(other, ordering)
+    }
+
     /// Asserts that the subject is less than the given value.
     ///
     /// ```
@@ -325,7 +867,9 @@ pub trait GeneralAssertions<T, M> {
     /// ```
     ///
     /// The assertion fails if the subject is not in the sequence or if the
-    /// sequence is empty:
+    /// sequence is empty. If the sequence is a list or set with a
+    /// [`Debug`] representation, each item is listed separately in the failure
+    /// message, and the item that looks most similar to the subject is marked:
     ///
     /// ```should_panic
     /// # use expecters::prelude::*;
@@ -339,6 +883,129 @@ pub trait GeneralAssertions<T, M> {
     {
         ToBeOneOf::new(items)
     }
+
+    /// Asserts that the subject is within a specified range of any of the given
+    /// candidates.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(2.05, to_be_approximately_one_of([1.0, 2.0, 3.0], 0.1));
+    /// ```
+    ///
+    /// The assertion fails if the subject is out of range of every candidate, or
+    /// if there are no candidates:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(2.5, to_be_approximately_one_of([1.0, 2.0, 3.0], 0.1));
+    /// ```
+    #[inline]
+    fn to_be_approximately_one_of<I>(
+        &self,
+        candidates: Annotated<I>,
+        max_delta: Annotated<T>,
+    ) -> ToBeApproxOneOf<I, T>
+    where
+        I: IntoIterator<Item = T>,
+        T: Float,
+    {
+        ToBeApproxOneOf::new(candidates, max_delta)
+    }
+
+    /// Asserts that the subject is greater than zero. Zero is the subject type's
+    /// [`Default`] value.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(1.5, to_be_positive);
+    /// ```
+    ///
+    /// The assertion fails if the subject is zero, negative, or not comparable
+    /// to zero (like [`f64::NAN`]):
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(0, to_be_positive);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_positive(&self) -> ToHaveSign
+    where
+        T: PartialOrd + Default,
+    {
+        ToHaveSign::new(Sign::Positive)
+    }
+
+    /// Asserts that the subject is less than zero. Zero is the subject type's
+    /// [`Default`] value.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(-1, to_be_negative);
+    /// ```
+    ///
+    /// The assertion fails if the subject is zero, positive, or not comparable
+    /// to zero (like [`f64::NAN`]):
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(0, to_be_negative);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_negative(&self) -> ToHaveSign
+    where
+        T: PartialOrd + Default,
+    {
+        ToHaveSign::new(Sign::Negative)
+    }
+
+    /// Asserts that the subject is greater than or equal to zero. Zero is the
+    /// subject type's [`Default`] value.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(0, to_be_non_negative);
+    /// ```
+    ///
+    /// The assertion fails if the subject is negative or not comparable to zero
+    /// (like [`f64::NAN`]):
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(-1, to_be_non_negative);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_non_negative(&self) -> ToHaveSign
+    where
+        T: PartialOrd + Default,
+    {
+        ToHaveSign::new(Sign::NonNegative)
+    }
+
+    /// Asserts that the subject is equal to zero. Zero is the subject type's
+    /// [`Default`] value.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(0.0, to_be_zero);
+    /// ```
+    ///
+    /// The assertion fails if the subject is not zero:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(1, to_be_zero);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_zero(&self) -> ToHaveSign
+    where
+        T: PartialOrd + Default,
+    {
+        ToHaveSign::new(Sign::Zero)
+    }
 }
 
 impl<T, M> GeneralAssertions<T, M> for AssertionBuilder<T, M> {
@@ -354,4 +1021,164 @@ impl<T, M> GeneralAssertions<T, M> for AssertionBuilder<T, M> {
     {
         AssertionBuilder::modify(self, move |prev| MapModifier::new(prev, f))
     }
+
+    #[inline]
+    fn map_as<U>(
+        self,
+        f: Annotated<impl FnOnce(T) -> U>,
+    ) -> AssertionBuilder<U, MapModifier<M, impl FnOnce(T) -> U>> {
+        AssertionBuilder::modify(self, move |prev| MapModifier::new(prev, f))
+    }
+
+    #[inline]
+    fn map_keeping<U, F>(self, f: Annotated<F>) -> AssertionBuilder<T, MapKeepingModifier<M, F>>
+    where
+        F: FnOnce(&T) -> U,
+    {
+        AssertionBuilder::modify(self, move |prev| MapKeepingModifier::new(prev, f))
+    }
+
+    #[inline]
+    fn with_float_tolerance<D>(
+        self,
+        tolerance: Annotated<D>,
+    ) -> AssertionBuilder<WithTolerance<T, D>, WithFloatToleranceModifier<M, D>> {
+        AssertionBuilder::modify(self, move |prev| {
+            WithFloatToleranceModifier::new(prev, tolerance)
+        })
+    }
+
+    #[inline]
+    fn try_map<U, E, F>(self, f: Annotated<F>) -> AssertionBuilder<U, TryMapModifier<M, F>>
+    where
+        E: Debug,
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        AssertionBuilder::modify(self, move |prev| TryMapModifier::new(prev, f))
+    }
+
+    #[inline]
+    fn annotate_with<F>(
+        self,
+        key: Annotated<&'static str>,
+        f: Annotated<F>,
+    ) -> AssertionBuilder<T, AnnotateWithModifier<M, F>>
+    where
+        F: FnOnce(&T) -> String,
+    {
+        AssertionBuilder::modify(self, move |prev| AnnotateWithModifier::new(prev, key, f))
+    }
 }
+
+/// Assertions for integers.
+pub trait IntegerAssertions<T, M>
+where
+    T: Integer,
+{
+    /// Asserts that the subject is a power of two.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(64, to_be_power_of_two);
+    /// ```
+    ///
+    /// The assertion fails if the subject is not a power of two. Zero and
+    /// negative numbers are never powers of two:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(0, to_be_power_of_two);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_power_of_two(&self) -> ToHaveIntegerProperty<T> {
+        ToHaveIntegerProperty::new(IntegerProperty::PowerOfTwo)
+    }
+
+    /// Asserts that the subject is even.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(-4, to_be_even);
+    /// ```
+    ///
+    /// The assertion fails if the subject is odd:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(3, to_be_even);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_even(&self) -> ToHaveIntegerProperty<T> {
+        ToHaveIntegerProperty::new(IntegerProperty::Even)
+    }
+
+    /// Asserts that the subject is odd.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(-3, to_be_odd);
+    /// ```
+    ///
+    /// The assertion fails if the subject is even:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(4, to_be_odd);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_odd(&self) -> ToHaveIntegerProperty<T> {
+        ToHaveIntegerProperty::new(IntegerProperty::Odd)
+    }
+
+    /// Asserts that the subject is a multiple of the given value. Only zero is
+    /// a multiple of zero.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(35, to_be_multiple_of(5));
+    /// ```
+    ///
+    /// The assertion fails if the subject is not a multiple of the value:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(37, to_be_multiple_of(5));
+    /// ```
+    #[inline]
+    fn to_be_multiple_of(&self, divisor: Annotated<T>) -> ToHaveIntegerProperty<T> {
+        ToHaveIntegerProperty::new(IntegerProperty::MultipleOf(divisor))
+    }
+
+    /// Asserts that the subject is a valid index into a collection with the
+    /// given length. This is useful for checking that computed indexes, like
+    /// coordinates into a grid, are in bounds.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let grid = vec![vec![0; 4]; 3];
+    /// let (row, col) = (2, 3);
+    /// expect!(row, to_be_valid_index_for(grid.len()));
+    /// expect!(col, to_be_valid_index_for(grid[row].len()));
+    /// ```
+    ///
+    /// The assertion fails if the subject is negative, or if it is not less
+    /// than the length. The length is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let grid = vec![vec![0; 4]; 3];
+    /// expect!(3, to_be_valid_index_for(grid.len()));
+    /// ```
+    #[inline]
+    fn to_be_valid_index_for(&self, len: Annotated<usize>) -> ToBeValidIndexFor
+    where
+        T: TryInto<usize>,
+    {
+        ToBeValidIndexFor::new(len)
+    }
+}
+
+impl<T, M> IntegerAssertions<T, M> for AssertionBuilder<T, M> where T: Integer {}
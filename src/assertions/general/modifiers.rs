@@ -1,9 +1,17 @@
 mod annotate;
+mod annotate_with;
 mod map;
+mod map_keeping;
 mod not;
 mod root;
+mod try_map;
+mod with_float_tolerance;
 
 pub use annotate::*;
+pub use annotate_with::*;
 pub use map::*;
+pub use map_keeping::*;
 pub use not::*;
 pub use root::*;
+pub use try_map::*;
+pub use with_float_tolerance::*;
@@ -5,6 +5,7 @@ use crate::{
         Assertion, AssertionBuilder, AssertionContext, AssertionContextBuilder, AssertionModifier,
     },
     metadata::Annotated,
+    trace,
 };
 
 #[doc(hidden)]
@@ -102,17 +103,22 @@ where
     A: Assertion<T>,
 {
     type Output = A::Output;
+    const CAN_SHORT_CIRCUIT: bool = A::CAN_SHORT_CIRCUIT;
 
     fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
         let mut cx = cx.next();
         let subject = (self.annotate)(subject);
 
         // Track the received value in the context
-        if let Some(debug) = subject.as_debug() {
-            cx.annotate("received", format_args!("{debug:?}"));
-        } else {
-            cx.annotate("received", "? (no debug representation)");
+        let received = match subject.as_debug() {
+            Some(debug) => format!("{debug:?}"),
+            None => "? (no debug representation)".to_string(),
+        };
+        if trace::is_tracing() {
+            let step = cx.visited.last().map_or("?", |frame| frame.assertion_name);
+            trace::trace_step(cx.source_loc, step, &received);
         }
+        cx.annotate("received", received);
 
         self.next.execute_annotated(cx, subject)
     }
@@ -124,4 +130,9 @@ where
     {
         unreachable!("call execute() instead")
     }
+
+    #[inline]
+    fn is_determined_by(&self, subject: &T) -> bool {
+        self.next.is_determined_by(subject)
+    }
 }
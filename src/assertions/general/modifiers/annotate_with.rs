@@ -0,0 +1,90 @@
+use crate::{
+    assertions::{Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier},
+    metadata::Annotated,
+};
+
+/// Adds an annotation computed from the subject to the assertion context.
+#[derive(Clone, Debug)]
+pub struct AnnotateWithModifier<M, F> {
+    prev: M,
+    key: Annotated<&'static str>,
+    f: Annotated<F>,
+}
+
+impl<M, F> AnnotateWithModifier<M, F> {
+    #[inline]
+    pub(crate) fn new(prev: M, key: Annotated<&'static str>, f: Annotated<F>) -> Self {
+        Self { prev, key, f }
+    }
+}
+
+impl<M, F, A> AssertionModifier<A> for AnnotateWithModifier<M, F>
+where
+    M: AssertionModifier<AnnotateWithAssertion<A, F>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            AnnotateWithAssertion {
+                next,
+                key: self.key,
+                f: self.f,
+            },
+        )
+    }
+}
+
+/// Annotates the context with a value computed from the subject, then executes
+/// the inner assertion on the unchanged subject.
+#[derive(Clone, Debug)]
+pub struct AnnotateWithAssertion<A, F> {
+    next: A,
+    key: Annotated<&'static str>,
+    f: Annotated<F>,
+}
+
+impl<A, F, T> Assertion<T> for AnnotateWithAssertion<A, F>
+where
+    A: Assertion<T>,
+    F: FnOnce(&T) -> String,
+{
+    type Output = A::Output;
+    const CAN_SHORT_CIRCUIT: bool = A::CAN_SHORT_CIRCUIT;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let f = self.f.into_inner();
+        cx.annotate(self.key.into_inner(), f(&subject));
+        self.next.execute(cx, subject)
+    }
+
+    #[inline]
+    fn is_determined_by(&self, subject: &T) -> bool {
+        self.next.is_determined_by(subject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn annotation_is_added() {
+        expect!(
+            try_expect!(
+                [1, 2, 3],
+                annotate_with("sum", |items: &[i32; 3]| items
+                    .iter()
+                    .sum::<i32>()
+                    .to_string()),
+                to_contain(4),
+            ),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("sum: 6"),
+        );
+    }
+}
@@ -57,3 +57,24 @@ where
         self.next.execute(cx, map(subject))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn map_as_infers_method_references() {
+        expect!(1u8, map_as::<u32>(Into::into), to_be_less_than(3));
+        expect!(
+            String::from("hi"),
+            map_as::<Box<str>>(String::into_boxed_str),
+            to_equal("hi".into()),
+        );
+        expect!(
+            "42",
+            map_as::<Result<u8, _>>(str::parse),
+            to_be_ok_and,
+            to_equal(42),
+        );
+    }
+}
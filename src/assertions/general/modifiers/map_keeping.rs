@@ -0,0 +1,140 @@
+use std::iter;
+
+use crate::{
+    assertions::{
+        iterators::{MergeStrategy, MergeableOutput},
+        Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier,
+    },
+    metadata::Annotated,
+};
+
+/// Forks the assertion, executing it on both the subject and a mapped value
+/// derived from the subject.
+#[derive(Clone, Debug)]
+pub struct MapKeepingModifier<M, F> {
+    prev: M,
+    map: Annotated<F>,
+}
+
+impl<M, F> MapKeepingModifier<M, F> {
+    #[inline]
+    pub(crate) fn new(prev: M, map: Annotated<F>) -> Self {
+        Self { prev, map }
+    }
+}
+
+impl<M, F, A> AssertionModifier<A> for MapKeepingModifier<M, F>
+where
+    M: AssertionModifier<MapKeepingAssertion<A, F>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            MapKeepingAssertion {
+                next,
+                map: self.map,
+            },
+        )
+    }
+}
+
+/// Executes the inner assertion on both the subject and a mapped value derived
+/// from the subject, succeeding only if both executions succeed.
+#[derive(Clone, Debug)]
+pub struct MapKeepingAssertion<A, F> {
+    next: A,
+    map: Annotated<F>,
+}
+
+impl<A, T, U, F, O> Assertion<T> for MapKeepingAssertion<A, F>
+where
+    A: Assertion<T, Output = O> + Assertion<U, Output = O> + Clone,
+    F: FnOnce(&T) -> U,
+    O: MergeableOutput,
+{
+    type Output = O::Merged;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("function", &self.map);
+
+        let mapped = (self.map.into_inner())(&subject);
+        let original = {
+            let mut cx = cx.clone();
+            let next = self.next.clone();
+            move || {
+                cx.annotate("fork", "original");
+                Assertion::<T>::execute(next, cx, subject)
+            }
+        };
+        let mapped = {
+            let mut cx = cx.clone();
+            let next = self.next;
+            move || {
+                cx.annotate("fork", "mapped");
+                Assertion::<U>::execute(next, cx, mapped)
+            }
+        };
+        let outputs = iter::once_with(original).chain(iter::once_with(mapped));
+
+        MergeableOutput::merge(cx, MergeStrategy::All, outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assertions::{general::__annotate, AssertionBuilder, AssertionContext},
+        metadata::Annotated,
+        prelude::*,
+    };
+
+    #[test]
+    fn both_values_are_checked() {
+        expect!(-3, map_keeping(|n: &i32| n.abs()), to_be_odd);
+        expect!(
+            try_expect!(-3, map_keeping(|n: &i32| n.abs()), to_be_negative),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("fork: mapped"),
+        );
+        expect!(
+            try_expect!(3, map_keeping(|n: &i32| -n), to_be_negative),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("fork: original"),
+        );
+    }
+
+    #[test]
+    fn mapped_value_can_change_type() {
+        // `expect!` annotates every step for a single subject type, so the
+        // assertion is built without the macro here, only annotating the
+        // subject before it is mapped
+        let run = |needle: &'static str| {
+            let builder = AssertionBuilder::__new(Annotated::__new("Hello", "\"Hello\""));
+            let builder = __annotate(builder, |subject| crate::annotated!(subject));
+            let builder = builder.map_keeping(Annotated::__new(
+                |s: &&str| s.to_uppercase(),
+                "|s: &&str| s.to_uppercase()",
+            ));
+            let assertion = builder.to_contain_substr(Annotated::__new(needle, "needle"));
+            let cx = AssertionContext::__new(
+                String::new(),
+                crate::source_loc!(),
+                &["map_keeping", "to_contain_substr"],
+            );
+            AssertionBuilder::__apply(builder, cx, assertion).into_result()
+        };
+
+        expect!(run("H"), to_be_ok);
+        expect!(
+            run("ll"),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("fork: mapped"),
+        );
+    }
+}
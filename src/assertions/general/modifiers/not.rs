@@ -39,10 +39,18 @@ where
     A: Assertion<T, Output: InvertibleOutput>,
 {
     type Output = <A::Output as InvertibleOutput>::Inverted;
+    const CAN_SHORT_CIRCUIT: bool = A::CAN_SHORT_CIRCUIT;
 
     #[inline]
     fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
-        self.next.execute(cx.clone(), subject).invert(cx)
+        let mut inner_cx = cx.clone();
+        inner_cx.inverted = !inner_cx.inverted;
+        self.next.execute(inner_cx, subject).invert(cx)
+    }
+
+    #[inline]
+    fn is_determined_by(&self, subject: &T) -> bool {
+        self.next.is_determined_by(subject)
     }
 }
 
@@ -0,0 +1,87 @@
+use std::fmt::Debug;
+
+use crate::{
+    assertions::{
+        general::IntoInitializableOutput, Assertion, AssertionContext, AssertionContextBuilder,
+        AssertionModifier,
+    },
+    metadata::Annotated,
+};
+
+/// Maps the subject to a new value using a fallible function.
+#[derive(Clone, Debug)]
+pub struct TryMapModifier<M, F> {
+    prev: M,
+    map: Annotated<F>,
+}
+
+impl<M, F> TryMapModifier<M, F> {
+    #[inline]
+    pub(crate) fn new(prev: M, map: Annotated<F>) -> Self {
+        Self { prev, map }
+    }
+}
+
+impl<M, F, A> AssertionModifier<A> for TryMapModifier<M, F>
+where
+    M: AssertionModifier<TryMapAssertion<A, F>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            TryMapAssertion {
+                next,
+                map: self.map,
+            },
+        )
+    }
+}
+
+/// Maps the subject to a new value and executes an inner assertion on it if the
+/// mapping succeeds.
+#[derive(Clone, Debug)]
+pub struct TryMapAssertion<A, F> {
+    next: A,
+    map: Annotated<F>,
+}
+
+impl<A, T, U, E, F> Assertion<T> for TryMapAssertion<A, F>
+where
+    A: Assertion<U, Output: IntoInitializableOutput>,
+    E: Debug,
+    F: FnOnce(T) -> Result<U, E>,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("function", &self.map);
+
+        let map = self.map.into_inner();
+        match map(subject) {
+            Ok(mapped) => self.next.execute(cx, mapped).into_initialized(),
+            Err(error) => {
+                cx.annotate("error", format!("{error:?}"));
+                cx.fail("mapping failed")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn error_is_annotated() {
+        expect!(
+            try_expect!("abc", try_map(str::parse::<i32>), to_equal(1)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("InvalidDigit"),
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    assertions::{
+        general::WithTolerance, Assertion, AssertionContext, AssertionContextBuilder,
+        AssertionModifier,
+    },
+    metadata::Annotated,
+};
+
+/// Compares the subject within a tolerance in later equality checks.
+#[derive(Clone, Debug)]
+pub struct WithFloatToleranceModifier<M, D> {
+    prev: M,
+    tolerance: Annotated<D>,
+}
+
+impl<M, D> WithFloatToleranceModifier<M, D> {
+    #[inline]
+    pub(crate) fn new(prev: M, tolerance: Annotated<D>) -> Self {
+        Self { prev, tolerance }
+    }
+}
+
+impl<M, D, A> AssertionModifier<A> for WithFloatToleranceModifier<M, D>
+where
+    M: AssertionModifier<WithFloatToleranceAssertion<A, D>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            WithFloatToleranceAssertion {
+                next,
+                tolerance: self.tolerance,
+            },
+        )
+    }
+}
+
+/// Wraps the subject in a [`WithTolerance`] and executes an inner assertion on
+/// it.
+#[derive(Clone, Debug)]
+pub struct WithFloatToleranceAssertion<A, D> {
+    next: A,
+    tolerance: Annotated<D>,
+}
+
+impl<A, T, D> Assertion<T> for WithFloatToleranceAssertion<A, D>
+where
+    A: Assertion<WithTolerance<T, D>>,
+{
+    type Output = A::Output;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("tolerance", &self.tolerance);
+
+        let tolerance = self.tolerance.into_inner();
+        self.next
+            .execute(cx, WithTolerance::new(subject, tolerance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equality_is_approximate() {
+        expect!(0.1 + 0.2, with_float_tolerance(1e-9), to_equal(0.3));
+        expect!(1.0f32, with_float_tolerance(0.1), not, to_equal(1.5));
+        expect!(
+            vec![0.1 + 0.2, 1.0],
+            with_float_tolerance(1e-9),
+            to_equal(vec![0.3, 1.0]),
+        );
+        expect!(
+            vec![0.3, 1.0],
+            with_float_tolerance(1e-9),
+            not,
+            to_equal([0.3]),
+        );
+        expect!(
+            (1.0, 2.0),
+            with_float_tolerance(0.5),
+            to_be_one_of([(0.0, 0.0), (1.1, 1.9)]),
+        );
+    }
+
+    #[test]
+    fn tolerance_is_annotated() {
+        expect!(
+            try_expect!(1.0, with_float_tolerance(0.25), to_equal(2.0)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("tolerance: 0.25"),
+        );
+    }
+}
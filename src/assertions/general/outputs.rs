@@ -1,7 +1,9 @@
 mod initializable;
 mod invert;
+mod message;
 mod unwrap;
 
 pub use initializable::*;
 pub use invert::*;
+pub use message::*;
 pub use unwrap::*;
@@ -0,0 +1,35 @@
+use crate::AssertionOutput;
+
+/// An assertion output that a custom failure message can be attached to.
+///
+/// This is used by [`expect!`](crate::expect!) to attach the message that
+/// follows the final assertion. The message is only generated if the output
+/// represents a failure.
+pub trait MessageOutput<F> {
+    /// The output with the message attached.
+    type WithMessage;
+
+    /// Attaches a custom message to this output.
+    ///
+    /// The message function is only called if the output represents a
+    /// failure. If it is not yet known whether the output represents a success
+    /// or failure, then a value is returned that attaches the message when it
+    /// is known.
+    fn with_message(self, message: F) -> Self::WithMessage;
+}
+
+impl<F> MessageOutput<F> for AssertionOutput
+where
+    F: FnOnce() -> String,
+{
+    type WithMessage = Self;
+
+    #[inline]
+    fn with_message(mut self, message: F) -> Self::WithMessage {
+        if !self.is_pass() {
+            self.set_message(message());
+        }
+
+        self
+    }
+}
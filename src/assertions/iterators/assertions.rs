@@ -1,5 +1,39 @@
+mod to_all_be_equal;
+mod to_all_be_finite;
+mod to_all_satisfy_with_context;
+mod to_be_sorted;
+mod to_be_sorted_by_key;
+mod to_be_strictly_monotonic;
+mod to_be_subsequence_of;
+mod to_be_subset_of;
+mod to_be_superset_of;
 mod to_contain;
+mod to_contain_at_least_counts;
 mod to_contain_exactly;
+mod to_contain_subslice;
+mod to_equal_slice;
+mod to_have_count_matching;
+mod to_have_distinct_count;
+mod to_have_unique_elements;
+mod to_have_unique_keys;
+mod to_yield_exactly;
 
+pub use to_all_be_equal::*;
+pub use to_all_be_finite::*;
+pub use to_all_satisfy_with_context::*;
+pub use to_be_sorted::*;
+pub use to_be_sorted_by_key::*;
+pub use to_be_strictly_monotonic::*;
+pub use to_be_subsequence_of::*;
+pub use to_be_subset_of::*;
+pub use to_be_superset_of::*;
 pub use to_contain::*;
+pub use to_contain_at_least_counts::*;
 pub use to_contain_exactly::*;
+pub use to_contain_subslice::*;
+pub use to_equal_slice::*;
+pub use to_have_count_matching::*;
+pub use to_have_distinct_count::*;
+pub use to_have_unique_elements::*;
+pub use to_have_unique_keys::*;
+pub use to_yield_exactly::*;
@@ -0,0 +1,71 @@
+use std::fmt::Debug;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// Asserts that every element of the subject is equal to each other.
+#[derive(Clone, Debug)]
+pub struct ToAllBeEqual {}
+
+impl ToAllBeEqual {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T> Assertion<T> for ToAllBeEqual
+where
+    T: IntoIterator<Item: PartialEq + Debug>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let mut subject = subject.into_iter();
+        let Some(first) = subject.next() else {
+            return cx.pass();
+        };
+
+        // Every element is compared to the first one
+        let divergent = subject.enumerate().find(|(_, item)| *item != first);
+        if let Some((idx, item)) = divergent {
+            cx.annotate("index", idx + 1);
+            cx.annotate("first element", format_args!("{first:?}"));
+            cx.annotate("divergent element", format_args!("{item:?}"));
+            return cx.fail("element not equal to the first element");
+        }
+
+        cx.pass()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(vec![]; "empty")]
+    #[test_case(vec![1]; "singleton")]
+    #[test_case(vec![1, 1, 1]; "equal elements")]
+    fn success_cases(subject: Vec<i32>) {
+        expect!(subject, to_all_be_equal);
+    }
+
+    #[test]
+    fn first_divergent_element_is_annotated() {
+        expect!(
+            try_expect!([1, 1, 2, 3], to_all_be_equal),
+            to_be_err_and,
+            as_display,
+            to_satisfy_with(|message| {
+                try_expect!(&message, to_contain_substr("index: 2"))?;
+                try_expect!(&message, to_contain_substr("first element: 1"))?;
+                try_expect!(&message, to_contain_substr("divergent element: 2"))?;
+                Ok(())
+            }),
+        );
+    }
+}
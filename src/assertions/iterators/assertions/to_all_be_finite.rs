@@ -0,0 +1,59 @@
+use crate::{
+    assertions::{general::Float, Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// Asserts that no element of the subject is NaN or infinite.
+#[derive(Clone, Debug)]
+pub struct ToAllBeFinite {}
+
+impl ToAllBeFinite {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T> Assertion<T> for ToAllBeFinite
+where
+    T: IntoIterator<Item: Float>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let non_finite = subject
+            .into_iter()
+            .enumerate()
+            .find(|(_, item)| !item.is_finite());
+        if let Some((idx, item)) = non_finite {
+            cx.annotate("index", idx);
+            cx.annotate("value", format_args!("{item:?}"));
+            return cx.fail("element is not finite");
+        }
+
+        cx.pass()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(vec![]; "empty")]
+    #[test_case(vec![0.0, -1.5, f64::MAX]; "finite elements")]
+    fn success_cases(subject: Vec<f64>) {
+        expect!(subject, to_all_be_finite);
+    }
+
+    #[test_case(vec![1.0, f64::NAN], "value: NaN"; "nan")]
+    #[test_case(vec![1.0, f64::NEG_INFINITY], "value: -inf"; "infinite")]
+    fn first_non_finite_value_is_annotated(subject: Vec<f64>, value: &str) {
+        let message = try_expect!(subject, to_all_be_finite)
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("index: 1"));
+        expect!(&message, to_contain_substr(value));
+    }
+}
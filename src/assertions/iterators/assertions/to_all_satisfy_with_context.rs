@@ -0,0 +1,93 @@
+use std::{cell::RefCell, fmt::Display};
+
+use crate::{
+    assertions::{
+        general::ToSatisfyOrExplain,
+        iterators::{MergeAssertion, Unindexed},
+        Assertion, AssertionContext,
+    },
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that every element of the subject satisfies a predicate. The
+/// predicate explains why each element that doesn't satisfy it failed.
+#[derive(Clone, Debug)]
+pub struct ToAllSatisfyWithContext<F> {
+    predicate: Annotated<F>,
+}
+
+impl<F> ToAllSatisfyWithContext<F> {
+    #[inline]
+    pub(crate) fn new(predicate: Annotated<F>) -> Self {
+        Self { predicate }
+    }
+}
+
+impl<F, T, E> Assertion<T> for ToAllSatisfyWithContext<F>
+where
+    F: FnMut(T::Item) -> Result<(), E>,
+    T: IntoIterator,
+    E: Display,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("predicate", &self.predicate);
+
+        // Each element is checked by `to_satisfy_or_explain` under
+        // `all_reporting`, but the predicate is shared so it can be stateful
+        let stringified = self.predicate.as_stringified();
+        let predicate = RefCell::new(self.predicate.into_inner());
+        let next = ToSatisfyOrExplain::new(Annotated::__new(
+            |item: T::Item| (predicate.borrow_mut())(item),
+            stringified,
+        ));
+        MergeAssertion::<_, Unindexed>::new_reporting(next).execute(cx, subject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn check_even(n: i32) -> Result<(), String> {
+        if n % 2 == 0 {
+            Ok(())
+        } else {
+            Err(format!("{n} is odd"))
+        }
+    }
+
+    #[test]
+    fn each_failure_is_explained() {
+        expect!([2, 4], to_all_satisfy_with_context(check_even));
+
+        let message = try_expect!([2, 3, 4, 5], to_all_satisfy_with_context(check_even))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("2 of 4 elements failed"));
+        expect!(&message, to_contain_substr("index: 1"));
+        expect!(
+            &message,
+            to_contain_substr("index 1: 3 is odd\nindex 3: 5 is odd")
+        );
+    }
+
+    #[test]
+    fn stateful_predicates_are_allowed() {
+        let mut seen = Vec::new();
+        expect!(
+            [1, 2, 1],
+            not,
+            to_all_satisfy_with_context(|n: i32| {
+                if seen.contains(&n) {
+                    return Err(format!("{n} was already seen"));
+                }
+
+                seen.push(n);
+                Ok(())
+            }),
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Write},
+};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// The maximum number of inversions listed in failure messages.
+const MAX_LISTED_INVERSIONS: usize = 10;
+
+/// Asserts that the elements of the subject are in non-decreasing order.
+#[derive(Clone, Debug)]
+pub struct ToBeSorted {}
+
+impl ToBeSorted {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T> Assertion<T> for ToBeSorted
+where
+    T: IntoIterator<Item: PartialOrd + Debug>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let mut items = subject.into_iter();
+        let Some(mut prev) = items.next() else {
+            return cx.pass();
+        };
+
+        // Every adjacent inversion is counted, but only the first few are
+        // formatted to keep the failure message readable
+        let mut inversions = 0;
+        let mut page = String::new();
+        for (idx, item) in items.enumerate() {
+            // Incomparable elements (like NaN) are treated as inversions
+            if let Some(Ordering::Greater) | None = prev.partial_cmp(&item) {
+                if inversions == 0 {
+                    cx.annotate("indexes", format_args!("{idx} and {}", idx + 1));
+                    cx.annotate("elements", format_args!("{prev:?} and {item:?}"));
+                }
+                if inversions < MAX_LISTED_INVERSIONS {
+                    writeln!(page, "[{idx}] {prev:?} > [{}] {item:?}", idx + 1).unwrap();
+                }
+
+                inversions += 1;
+            }
+
+            prev = item;
+        }
+
+        if inversions == 0 {
+            return cx.pass();
+        }
+
+        if inversions > MAX_LISTED_INVERSIONS {
+            writeln!(page, "... and {} more", inversions - MAX_LISTED_INVERSIONS).unwrap();
+        }
+
+        cx.annotate("inversions", inversions);
+        cx.add_page("inversions", page.trim_end());
+        cx.fail("elements are out of order")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(&[]; "empty")]
+    #[test_case(&[1, 1, 2, 3, 3]; "equal elements")]
+    fn success_cases(subject: &[i32]) {
+        expect!(subject, to_be_sorted);
+    }
+
+    #[test]
+    fn every_inversion_is_listed() {
+        let message = try_expect!([1, 3, 2, 4, 0], to_be_sorted)
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("indexes: 1 and 2"));
+        expect!(&message, to_contain_substr("inversions: 2"));
+        expect!(&message, to_contain_substr("[1] 3 > [2] 2\n[3] 4 > [4] 0"));
+    }
+
+    #[test]
+    fn many_inversions_are_summarized() {
+        let message = try_expect!((0..30).rev(), to_be_sorted)
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("inversions: 29"));
+        expect!(&message, to_contain_substr("... and 19 more"));
+        expect!(&message, not, to_contain_substr("[11] "));
+    }
+
+    #[test]
+    fn nan_is_out_of_order() {
+        expect!([1.0, f64::NAN, 2.0], not, to_be_sorted);
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt::Debug;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the keys extracted from the elements of the subject are in
+/// non-decreasing order.
+#[derive(Clone, Debug)]
+pub struct ToBeSortedByKey<F> {
+    key_fn: Annotated<F>,
+}
+
+impl<F> ToBeSortedByKey<F> {
+    #[inline]
+    pub(crate) fn new(key_fn: Annotated<F>) -> Self {
+        Self { key_fn }
+    }
+}
+
+impl<F, K, T> Assertion<T> for ToBeSortedByKey<F>
+where
+    F: FnMut(&T::Item) -> K,
+    K: Ord + Debug,
+    T: IntoIterator,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("key", &self.key_fn);
+
+        let mut key_fn = self.key_fn.into_inner();
+        let mut keys = subject.into_iter().map(|item| key_fn(&item));
+        let Some(mut prev) = keys.next() else {
+            return cx.pass();
+        };
+        for (idx, key) in keys.enumerate() {
+            if key < prev {
+                cx.annotate("indexes", format_args!("{idx} and {}", idx + 1));
+                cx.annotate("keys", format_args!("{prev:?} and {key:?}"));
+                return cx.fail("keys are out of order");
+            }
+
+            prev = key;
+        }
+
+        cx.pass()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn equal_keys_are_sorted() {
+        expect!(["b", "a", "cc"], to_be_sorted_by_key(|s: &&str| s.len()));
+        expect!(Vec::<&str>::new(), to_be_sorted_by_key(|s: &&str| s.len()));
+    }
+
+    #[test]
+    fn offending_pair_is_annotated() {
+        let result = try_expect!([3, 1, 2, 4], to_be_sorted_by_key(|&n: &i32| n % 3));
+        expect!(
+            &result,
+            to_be_err_and,
+            as_display,
+            to_contain_substr("indexes: 2 and 3"),
+        );
+        expect!(
+            result,
+            to_be_err_and,
+            as_display,
+            to_contain_substr("keys: 2 and 1"),
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt::Debug;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// Asserts that the elements of the subject are strictly increasing or
+/// strictly decreasing. Equal adjacent elements fail the assertion.
+#[derive(Clone, Debug)]
+pub struct ToBeStrictlyMonotonic {
+    direction: Direction,
+}
+
+impl ToBeStrictlyMonotonic {
+    #[inline]
+    pub(crate) fn new(direction: Direction) -> Self {
+        Self { direction }
+    }
+}
+
+/// The direction of a strictly monotonic sequence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Increasing,
+    Decreasing,
+}
+
+impl<T> Assertion<T> for ToBeStrictlyMonotonic
+where
+    T: IntoIterator<Item: PartialOrd + Debug>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let mut items = subject.into_iter();
+        let Some(mut prev) = items.next() else {
+            return cx.pass();
+        };
+        for (idx, item) in items.enumerate() {
+            let in_order = match self.direction {
+                Direction::Increasing => prev < item,
+                Direction::Decreasing => prev > item,
+            };
+            if !in_order {
+                cx.annotate("indexes", format_args!("{idx} and {}", idx + 1));
+                cx.annotate("elements", format_args!("{prev:?} and {item:?}"));
+                return if prev == item {
+                    cx.fail("adjacent elements are equal")
+                } else {
+                    cx.fail("elements are out of order")
+                };
+            }
+
+            prev = item;
+        }
+
+        cx.pass()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn short_sequences_pass() {
+        expect!(Vec::<i32>::new(), to_be_strictly_increasing);
+        expect!([1], to_be_strictly_decreasing);
+    }
+
+    #[test_case(&[1.0, 2.0, f64::NAN]; "nan")]
+    #[test_case(&[1.0, 3.0, 2.0]; "out of order")]
+    fn increasing_failure_cases(subject: &[f64]) {
+        expect!(subject, not, to_be_strictly_increasing);
+    }
+
+    #[test]
+    fn equal_pair_is_annotated() {
+        let message = try_expect!([5, 3, 3, 1], to_be_strictly_decreasing)
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("indexes: 1 and 2"));
+        expect!(&message, to_contain_substr("elements: 3 and 3"));
+        expect!(&message, to_contain_substr("adjacent elements are equal"));
+    }
+}
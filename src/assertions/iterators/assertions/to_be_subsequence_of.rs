@@ -0,0 +1,141 @@
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the elements of the subject appear in order within another
+/// sequence.
+#[derive(Clone, Debug)]
+pub struct ToBeSubsequenceOf<I> {
+    sequence: Annotated<I>,
+}
+
+impl<I> ToBeSubsequenceOf<I> {
+    #[inline]
+    pub(crate) fn new(sequence: Annotated<I>) -> Self {
+        Self { sequence }
+    }
+}
+
+impl<I, T> Assertion<T> for ToBeSubsequenceOf<I>
+where
+    I: IntoIterator,
+    T: IntoIterator<Item: PartialEq<I::Item>>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("sequence", &self.sequence);
+
+        let subject: Vec<_> = subject.into_iter().collect();
+        let matched = match_subsequence(&subject, self.sequence.into_inner(), |n, h| n == h);
+        if matched == subject.len() {
+            return cx.pass();
+        }
+
+        cx.annotate("matched", format_args!("{matched} of {}", subject.len()));
+        cx.annotate("unmatched index", matched);
+        cx.fail("subject is not a subsequence")
+    }
+}
+
+/// Asserts that the given markers appear in order within the subject.
+#[derive(Clone, Debug)]
+pub struct ToContainInOrder<I> {
+    markers: Annotated<I>,
+}
+
+impl<I> ToContainInOrder<I> {
+    #[inline]
+    pub(crate) fn new(markers: Annotated<I>) -> Self {
+        Self { markers }
+    }
+}
+
+impl<I, T> Assertion<T> for ToContainInOrder<I>
+where
+    I: IntoIterator,
+    T: IntoIterator<Item: PartialEq<I::Item>>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("markers", &self.markers);
+
+        let markers: Vec<_> = self.markers.into_inner().into_iter().collect();
+        let matched = match_subsequence(&markers, subject, |marker, item| item == marker);
+        if matched == markers.len() {
+            return cx.pass();
+        }
+
+        cx.annotate("matched", format_args!("{matched} of {}", markers.len()));
+        cx.annotate("unmatched marker index", matched);
+        cx.fail("markers not found in order")
+    }
+}
+
+/// Greedily matches the elements of `needle` in order against `haystack`,
+/// returning the number of elements of `needle` that were found.
+fn match_subsequence<N, H>(
+    needle: &[N],
+    haystack: H,
+    mut is_match: impl FnMut(&N, &H::Item) -> bool,
+) -> usize
+where
+    H: IntoIterator,
+{
+    let mut matched = 0;
+    for item in haystack {
+        let Some(next) = needle.get(matched) else {
+            break;
+        };
+
+        if is_match(next, &item) {
+            matched += 1;
+        }
+    }
+
+    matched
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(Vec::<i32>::new(), [1, 2]; "empty subject")]
+    #[test_case([1, 3], [1, 2, 3]; "gaps")]
+    #[test_case([1, 1], [1, 2, 1]; "duplicate elements")]
+    #[test_case([1, 2, 3], [1, 2, 3]; "equal")]
+    fn success_cases<A, B>(subject: A, sequence: B)
+    where
+        A: IntoIterator<Item: PartialEq<B::Item>>,
+        B: IntoIterator,
+    {
+        expect!(subject, to_be_subsequence_of(sequence));
+    }
+
+    #[test]
+    fn matched_prefix_is_annotated() {
+        let message = try_expect!([1, 3, 2], to_be_subsequence_of([1, 2, 3]))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("matched: 2 of 3"));
+        expect!(&message, to_contain_substr("unmatched index: 2"));
+    }
+
+    #[test]
+    fn markers_in_order() {
+        let log = ["start", "load", "run", "finish"];
+        expect!(log, to_contain_in_order(["start", "finish"]));
+        expect!(log, to_contain_in_order(Vec::<&str>::new()));
+
+        let message = try_expect!(log, to_contain_in_order(["start", "finish", "run"]))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("matched: 2 of 3"));
+        expect!(&message, to_contain_substr("unmatched marker index: 2"));
+    }
+}
@@ -0,0 +1,110 @@
+use std::{borrow::Borrow, collections::HashSet, fmt::Debug, hash::Hash, marker::PhantomData};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+use super::{ByEquality, ByHash};
+
+/// Asserts that every element of the subject appears in another sequence.
+///
+/// The type parameter `L` determines how elements are compared. See
+/// [`ByEquality`] and [`ByHash`].
+#[derive(Clone, Debug)]
+pub struct ToBeSubsetOf<I, L = ByEquality> {
+    superset: Annotated<I>,
+    lookup: PhantomData<fn() -> L>,
+}
+
+impl<I, L> ToBeSubsetOf<I, L> {
+    #[inline]
+    pub(crate) fn new(superset: Annotated<I>) -> Self {
+        Self {
+            superset,
+            lookup: PhantomData,
+        }
+    }
+}
+
+fn finish<T>(mut cx: AssertionContext, missing: &[T]) -> AssertionOutput
+where
+    T: Debug,
+{
+    if missing.is_empty() {
+        return cx.pass();
+    }
+
+    cx.annotate("missing elements", format_args!("{missing:?}"));
+    cx.fail("subject has elements not found in the superset")
+}
+
+impl<I, T> Assertion<T> for ToBeSubsetOf<I, ByEquality>
+where
+    I: IntoIterator,
+    T: IntoIterator<Item: PartialEq<I::Item> + Debug>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("superset", &self.superset);
+
+        let superset: Vec<_> = self.superset.into_inner().into_iter().collect();
+        let missing: Vec<_> = subject
+            .into_iter()
+            .filter(|item| !superset.iter().any(|other| item == other))
+            .collect();
+        finish(cx, &missing)
+    }
+}
+
+impl<I, T> Assertion<T> for ToBeSubsetOf<I, ByHash>
+where
+    I: IntoIterator<Item: Borrow<T::Item> + Hash + Eq>,
+    T: IntoIterator<Item: Hash + Eq + Debug>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("superset", &self.superset);
+
+        let superset: HashSet<_> = self.superset.into_inner().into_iter().collect();
+        let missing: Vec<_> = subject
+            .into_iter()
+            .filter(|item| !superset.contains(item))
+            .collect();
+        finish(cx, &missing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(&[], &[1, 2]; "empty subject")]
+    #[test_case(&[1, 2], &[2, 3, 1]; "different order")]
+    #[test_case(&[1, 1], &[1]; "duplicate elements")]
+    fn success_cases(subject: &[i32], superset: &[i32]) {
+        expect!(subject, to_be_subset_of(superset));
+        expect!(subject, to_be_subset_of_hashed(superset));
+    }
+
+    #[test]
+    fn missing_elements_are_listed() {
+        expect!(
+            try_expect!(["a", "b", "c", "d"], to_be_subset_of(["a", "c"])),
+            to_be_err_and,
+            as_display,
+            to_contain_substr(r#"missing elements: ["b", "d"]"#),
+        );
+        expect!(
+            try_expect!(["a", "b", "c", "d"], to_be_subset_of_hashed(["a", "c"])),
+            to_be_err_and,
+            as_display,
+            to_contain_substr(r#"missing elements: ["b", "d"]"#),
+        );
+    }
+}
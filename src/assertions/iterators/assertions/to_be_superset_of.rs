@@ -0,0 +1,114 @@
+use std::{borrow::Borrow, collections::HashSet, fmt::Debug, hash::Hash, marker::PhantomData};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+use super::{ByEquality, ByHash};
+
+/// Asserts that every element of another sequence appears in the subject.
+///
+/// The type parameter `L` determines how elements are compared. See
+/// [`ByEquality`] and [`ByHash`].
+#[derive(Clone, Debug)]
+pub struct ToBeSupersetOf<I, L = ByEquality> {
+    subset: Annotated<I>,
+    lookup: PhantomData<fn() -> L>,
+}
+
+impl<I, L> ToBeSupersetOf<I, L> {
+    #[inline]
+    pub(crate) fn new(subset: Annotated<I>) -> Self {
+        Self {
+            subset,
+            lookup: PhantomData,
+        }
+    }
+}
+
+fn finish<T>(mut cx: AssertionContext, missing: &[T]) -> AssertionOutput
+where
+    T: Debug,
+{
+    if missing.is_empty() {
+        return cx.pass();
+    }
+
+    cx.annotate("missing elements", format_args!("{missing:?}"));
+    cx.fail("subset has elements not found in the subject")
+}
+
+impl<I, T> Assertion<T> for ToBeSupersetOf<I, ByEquality>
+where
+    I: IntoIterator<Item: Debug>,
+    T: IntoIterator<Item: PartialEq<I::Item>>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("subset", &self.subset);
+
+        let subject: Vec<_> = subject.into_iter().collect();
+        let missing: Vec<_> = self
+            .subset
+            .into_inner()
+            .into_iter()
+            .filter(|other| !subject.iter().any(|item| item == other))
+            .collect();
+        finish(cx, &missing)
+    }
+}
+
+impl<I, T> Assertion<T> for ToBeSupersetOf<I, ByHash>
+where
+    I: IntoIterator<Item: Hash + Eq + Debug>,
+    T: IntoIterator<Item: Borrow<I::Item> + Hash + Eq>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("subset", &self.subset);
+
+        let subject: HashSet<_> = subject.into_iter().collect();
+        let missing: Vec<_> = self
+            .subset
+            .into_inner()
+            .into_iter()
+            .filter(|other| !subject.contains(other))
+            .collect();
+        finish(cx, &missing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(&[1, 2], &[]; "empty subset")]
+    #[test_case(&[2, 3, 1], &[1, 2]; "different order")]
+    #[test_case(&[1], &[1, 1]; "duplicate elements")]
+    fn success_cases(subject: &[i32], subset: &[i32]) {
+        expect!(subject, to_be_superset_of(subset));
+        expect!(subject, to_be_superset_of_hashed(subset));
+    }
+
+    #[test]
+    fn missing_elements_are_listed() {
+        expect!(
+            try_expect!(["a", "c"], to_be_superset_of(["a", "b", "c", "d"])),
+            to_be_err_and,
+            as_display,
+            to_contain_substr(r#"missing elements: ["b", "d"]"#),
+        );
+        expect!(
+            try_expect!(["a", "c"], to_be_superset_of_hashed(["a", "b", "c", "d"])),
+            to_be_err_and,
+            as_display,
+            to_contain_substr(r#"missing elements: ["b", "d"]"#),
+        );
+    }
+}
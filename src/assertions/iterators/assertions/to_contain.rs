@@ -33,3 +33,104 @@ where
         )
     }
 }
+
+/// Asserts that the subject does not contain an element.
+#[derive(Clone, Debug)]
+pub struct ToNotContain<U> {
+    unexpected: Annotated<U>,
+}
+
+impl<U> ToNotContain<U> {
+    #[inline]
+    pub(crate) fn new(unexpected: Annotated<U>) -> Self {
+        Self { unexpected }
+    }
+}
+
+impl<U, T> Assertion<T> for ToNotContain<U>
+where
+    T: IntoIterator<Item: PartialEq<U>>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("unexpected", &self.unexpected);
+
+        let Some(idx) = subject
+            .into_iter()
+            .position(|item| &item == self.unexpected.inner())
+        else {
+            return cx.pass();
+        };
+
+        cx.annotate("index", idx);
+        cx.fail(format!("unexpectedly found value at index {idx}"))
+    }
+}
+
+/// Asserts that the subject contains an expected element exactly once.
+#[derive(Clone, Debug)]
+pub struct ToContainExactlyOnce<U> {
+    expected: Annotated<U>,
+}
+
+impl<U> ToContainExactlyOnce<U> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<U>) -> Self {
+        Self { expected }
+    }
+}
+
+impl<U, T> Assertion<T> for ToContainExactlyOnce<U>
+where
+    T: IntoIterator<Item: PartialEq<U>>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.expected);
+
+        let count = subject
+            .into_iter()
+            .filter(|item| item == self.expected.inner())
+            .count();
+        if count == 1 {
+            return cx.pass();
+        }
+
+        cx.annotate("count", count);
+        cx.fail(if count == 0 {
+            "value not found"
+        } else {
+            "value found more than once"
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn first_found_index_is_annotated() {
+        expect!([1, 2, 3], to_not_contain(4));
+        expect!(
+            try_expect!([1, 2, 3, 2], to_not_contain(2)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("unexpectedly found value at index 1"),
+        );
+    }
+
+    #[test]
+    fn count_is_annotated() {
+        expect!([1, 2, 3], to_contain_exactly_once(2));
+        expect!([1, 2, 3], not, to_contain_exactly_once(4));
+        expect!(
+            try_expect!([1, 2, 1, 1], to_contain_exactly_once(1)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("count: 3"),
+        );
+    }
+}
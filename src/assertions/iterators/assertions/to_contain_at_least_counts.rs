@@ -0,0 +1,186 @@
+use std::{borrow::Borrow, collections::HashMap, hash::Hash, marker::PhantomData};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject contains each element of another sequence at least
+/// as many times as it appears in that sequence.
+///
+/// The type parameter `L` determines how elements are looked up. See
+/// [`ByEquality`] and [`ByHash`].
+#[derive(Clone, Debug)]
+pub struct ToContainAtLeastCounts<I, L = ByEquality> {
+    expected: Annotated<I>,
+    lookup: PhantomData<fn() -> L>,
+}
+
+impl<I, L> ToContainAtLeastCounts<I, L> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<I>) -> Self {
+        Self {
+            expected,
+            lookup: PhantomData,
+        }
+    }
+}
+
+/// Looks up elements by comparing them with [`PartialEq`]. This works for any
+/// comparable elements, but takes time proportional to the number of distinct
+/// expected elements for each element of the subject.
+#[derive(Clone, Copy, Debug)]
+pub enum ByEquality {}
+
+/// Looks up elements by hashing them. This requires the elements to be
+/// [`Hash`] and [`Eq`].
+#[derive(Clone, Copy, Debug)]
+pub enum ByHash {}
+
+impl<I, T> Assertion<T> for ToContainAtLeastCounts<I, ByEquality>
+where
+    I: IntoIterator<Item: PartialEq>,
+    T: IntoIterator<Item: PartialEq<I::Item>>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.expected);
+
+        let expected: Vec<_> = self.expected.into_inner().into_iter().collect();
+        let mut groups = group_by_equality(&expected);
+        for item in subject {
+            if let Some(group) = groups
+                .iter_mut()
+                .find(|group| item == expected[group.first_index])
+            {
+                group.found += 1;
+            }
+        }
+
+        finish(cx, &groups)
+    }
+}
+
+impl<I, T> Assertion<T> for ToContainAtLeastCounts<I, ByHash>
+where
+    I: IntoIterator<Item: Hash + Eq>,
+    T: IntoIterator<Item: Borrow<I::Item>>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.expected);
+
+        let expected: Vec<_> = self.expected.into_inner().into_iter().collect();
+        let mut groups: Vec<Group> = Vec::new();
+        let mut group_indexes = HashMap::new();
+        for (index, item) in expected.iter().enumerate() {
+            let group_index = *group_indexes.entry(item).or_insert_with(|| {
+                groups.push(Group::new(index));
+                groups.len() - 1
+            });
+            groups[group_index].required += 1;
+        }
+
+        for item in subject {
+            if let Some(&group_index) = group_indexes.get(item.borrow()) {
+                groups[group_index].found += 1;
+            }
+        }
+
+        finish(cx, &groups)
+    }
+}
+
+/// The counts for a distinct expected element.
+pub(super) struct Group {
+    pub first_index: usize,
+    pub required: usize,
+    pub found: usize,
+}
+
+impl Group {
+    fn new(first_index: usize) -> Self {
+        Self {
+            first_index,
+            required: 0,
+            found: 0,
+        }
+    }
+}
+
+/// Groups equal elements together, counting how many times each distinct
+/// element appears. Each group refers to the first occurrence of its element.
+pub(super) fn group_by_equality<E: PartialEq>(expected: &[E]) -> Vec<Group> {
+    let mut groups: Vec<Group> = Vec::new();
+    for (index, item) in expected.iter().enumerate() {
+        let group_index = groups
+            .iter()
+            .position(|group| expected[group.first_index] == *item)
+            .unwrap_or_else(|| {
+                groups.push(Group::new(index));
+                groups.len() - 1
+            });
+        groups[group_index].required += 1;
+    }
+
+    groups
+}
+
+fn finish(mut cx: AssertionContext, groups: &[Group]) -> AssertionOutput {
+    let shortfalls: Vec<_> = groups
+        .iter()
+        .filter(|group| group.found < group.required)
+        .map(|group| {
+            format!(
+                "expected[{}]: found {} of {} ({} short)",
+                group.first_index,
+                group.found,
+                group.required,
+                group.required - group.found,
+            )
+        })
+        .collect();
+    if shortfalls.is_empty() {
+        return cx.pass();
+    }
+
+    cx.annotate("shortfalls", shortfalls.join(", "));
+    cx.fail("subject does not contain enough of each element")
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(&[1, 1, 2, 3], &[1, 2, 1]; "exact counts")]
+    #[test_case(&[1, 1, 1, 2], &[1, 1]; "extra elements")]
+    #[test_case(&[1, 2], &[]; "empty expected")]
+    fn success_cases(subject: &[i32], expected: &[i32]) {
+        expect!(subject, to_contain_at_least_counts(expected));
+        expect!(subject, to_contain_at_least_counts_hashed(expected));
+    }
+
+    #[test]
+    fn shortfalls_are_annotated() {
+        let message = try_expect!([1, 2, 3], to_contain_at_least_counts([3, 1, 1, 4]))
+            .unwrap_err()
+            .to_string();
+        expect!(
+            &message,
+            to_contain_substr("expected[1]: found 1 of 2 (1 short), expected[3]: found 0 of 1"),
+        );
+
+        let hashed = try_expect!([1, 2, 3], to_contain_at_least_counts_hashed([3, 1, 1, 4]))
+            .unwrap_err()
+            .to_string();
+        expect!(
+            &hashed,
+            to_contain_substr("expected[1]: found 1 of 2 (1 short), expected[3]: found 0 of 1"),
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    assertions::{Assertion, AssertionContext},
+    assertions::{general::Float, Assertion, AssertionContext},
     metadata::Annotated,
     AssertionOutput,
 };
@@ -27,24 +27,110 @@ where
     fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
         cx.annotate("expected", &self.expected);
 
-        let mut subject = subject.into_iter();
-        let mut expected = self.expected.into_inner().into_iter();
-        let mut idx = 0;
-        loop {
-            let error = match (subject.next(), expected.next()) {
-                (None, None) => return cx.pass(),
-                (Some(left), Some(right)) if left == right => {
-                    idx += 1;
-                    continue;
-                }
-                (Some(_), Some(_)) => "values not equal",
-                (Some(_), None) => "subject has too many elements",
-                (None, Some(_)) => "subject has too few elements",
-            };
-
-            // Return a failure
-            cx.annotate("index", idx);
-            return cx.fail(error);
+        let expected = self.expected.into_inner();
+        let Err(mismatch) = compare_sequences(subject, expected, |left, right| left == right)
+        else {
+            return cx.pass();
+        };
+
+        cx.annotate("index", mismatch.index());
+        cx.fail(mismatch.message())
+    }
+}
+
+/// Asserts that the subject is approximately equal to the given sequence,
+/// comparing each element in order.
+#[derive(Clone, Debug)]
+pub struct ToContainExactlyApprox<I, F> {
+    expected: Annotated<I>,
+    max_delta: Annotated<F>,
+}
+
+impl<I, F> ToContainExactlyApprox<I, F> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<I>, max_delta: Annotated<F>) -> Self {
+        Self {
+            expected,
+            max_delta,
+        }
+    }
+}
+
+impl<I, T, F> Assertion<T> for ToContainExactlyApprox<I, F>
+where
+    I: IntoIterator<Item = F>,
+    T: IntoIterator<Item = F>,
+    F: Float,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.expected);
+        let max_delta = *self.max_delta.inner();
+        cx.annotate("max delta", self.max_delta);
+
+        let expected = self.expected.into_inner();
+        let Err(mismatch) = compare_sequences(subject, expected, |&left, &right| {
+            (left - right).abs() <= max_delta
+        }) else {
+            return cx.pass();
+        };
+
+        cx.annotate("index", mismatch.index());
+        if let Mismatch::NotEqual { left, right, .. } = mismatch {
+            let delta = (left - right).abs();
+            cx.annotate("delta", format_args!("{delta:?}"));
+        }
+
+        cx.fail(mismatch.message())
+    }
+}
+
+/// Compares two sequences element-by-element, returning the first mismatch.
+fn compare_sequences<L, R>(
+    left: impl IntoIterator<Item = L>,
+    right: impl IntoIterator<Item = R>,
+    mut matches: impl FnMut(&L, &R) -> bool,
+) -> Result<(), Mismatch<L, R>> {
+    let mut left = left.into_iter();
+    let mut right = right.into_iter();
+    let mut index = 0;
+    loop {
+        let mismatch = match (left.next(), right.next()) {
+            (None, None) => return Ok(()),
+            (Some(left), Some(right)) if matches(&left, &right) => {
+                index += 1;
+                continue;
+            }
+            (Some(left), Some(right)) => Mismatch::NotEqual { index, left, right },
+            (Some(_), None) => Mismatch::TooLong { index },
+            (None, Some(_)) => Mismatch::TooShort { index },
+        };
+
+        return Err(mismatch);
+    }
+}
+
+enum Mismatch<L, R> {
+    NotEqual { index: usize, left: L, right: R },
+    TooLong { index: usize },
+    TooShort { index: usize },
+}
+
+impl<L, R> Mismatch<L, R> {
+    fn index(&self) -> usize {
+        match self {
+            Mismatch::NotEqual { index, .. }
+            | Mismatch::TooLong { index }
+            | Mismatch::TooShort { index } => *index,
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            Mismatch::NotEqual { .. } => "values not equal",
+            Mismatch::TooLong { .. } => "subject has too many elements",
+            Mismatch::TooShort { .. } => "subject has too few elements",
         }
     }
 }
@@ -66,4 +152,30 @@ mod tests {
     {
         expect!(left, to_contain_exactly(right));
     }
+
+    #[test]
+    fn approx_delta_is_annotated() {
+        expect!(
+            vec![0.1 + 0.2, 1.0],
+            to_contain_exactly_approximately([0.3, 1.0], 1e-9)
+        );
+        expect!(
+            [1.0, f64::NAN],
+            not,
+            to_contain_exactly_approximately([1.0, f64::NAN], 1.0)
+        );
+        expect!(
+            try_expect!(
+                [1.0, 2.5],
+                to_contain_exactly_approximately([1.0, 2.0], 0.1)
+            ),
+            to_be_err_and,
+            as_display,
+            to_satisfy_with(|message| {
+                try_expect!(&message, to_contain_substr("index: 1"))?;
+                try_expect!(&message, to_contain_substr("delta: 0.5"))?;
+                Ok(())
+            }),
+        );
+    }
 }
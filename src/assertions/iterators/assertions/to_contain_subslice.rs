@@ -0,0 +1,82 @@
+use std::marker::PhantomData;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject contains a slice as a contiguous run of elements.
+#[derive(Clone, Debug)]
+pub struct ToContainSubslice<S, E> {
+    needle: Annotated<S>,
+    element: PhantomData<fn() -> E>,
+}
+
+impl<S, E> ToContainSubslice<S, E> {
+    #[inline]
+    pub(crate) fn new(needle: Annotated<S>) -> Self {
+        Self {
+            needle,
+            element: PhantomData,
+        }
+    }
+}
+
+impl<S, E, T> Assertion<T> for ToContainSubslice<S, E>
+where
+    S: AsRef<[E]>,
+    E: PartialEq,
+    T: AsRef<[E]>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("subslice", &self.needle);
+
+        let haystack = subject.as_ref();
+        let needle = self.needle.inner().as_ref();
+        let offset = if needle.is_empty() {
+            Some(0)
+        } else {
+            haystack
+                .windows(needle.len())
+                .position(|window| window == needle)
+        };
+
+        let Some(offset) = offset else {
+            return cx.fail("subslice not found");
+        };
+
+        cx.annotate("offset", offset);
+        cx.pass()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(&[1, 2, 3, 4], &[2, 3]; "middle")]
+    #[test_case(&[1, 2, 3, 4], &[3, 4]; "end")]
+    #[test_case(&[1, 2], &[]; "empty")]
+    fn found(subject: &[i32], needle: &[i32]) {
+        expect!(subject, to_contain_subslice(needle));
+    }
+
+    #[test_case(&[1, 2, 3, 4], &[1, 3]; "not contiguous")]
+    #[test_case(&[1, 2], &[1, 2, 3]; "too long")]
+    fn not_found(subject: &[i32], needle: &[i32]) {
+        expect!(subject, not, to_contain_subslice(needle));
+    }
+
+    #[test]
+    fn offset_is_annotated() {
+        let message = try_expect!(vec![1, 2, 3, 2, 3], not, to_contain_subslice([2, 3]))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("offset: 1"));
+    }
+}
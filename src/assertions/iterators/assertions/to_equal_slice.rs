@@ -0,0 +1,91 @@
+use std::fmt::{Debug, Write};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    diff::fmt_diff,
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject is equal to an expected sequence, comparing each
+/// element in order.
+#[derive(Clone, Debug)]
+pub struct ToEqualSlice<I> {
+    expected: Annotated<I>,
+}
+
+impl<I> ToEqualSlice<I> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<I>) -> Self {
+        Self { expected }
+    }
+}
+
+impl<I, T> Assertion<T> for ToEqualSlice<I>
+where
+    I: IntoIterator<Item: Debug>,
+    T: IntoIterator<Item: PartialEq<I::Item> + Debug>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.expected);
+
+        let subject: Vec<_> = subject.into_iter().collect();
+        let expected: Vec<_> = self.expected.into_inner().into_iter().collect();
+        let mut differing = Vec::new();
+        let mut differences = String::new();
+        for idx in 0..subject.len().max(expected.len()) {
+            match (subject.get(idx), expected.get(idx)) {
+                (Some(actual), Some(expected)) if actual == expected => continue,
+                (Some(actual), Some(expected)) => {
+                    let expected = format!("{expected:#?}");
+                    let actual = format!("{actual:#?}");
+                    writeln!(differences, "[{idx}]:").unwrap();
+                    if let Some(diff) = fmt_diff(&expected, &actual) {
+                        differences.push_str(&diff);
+                    } else {
+                        writeln!(differences, "  expected: {expected}").unwrap();
+                        writeln!(differences, "  received: {actual}").unwrap();
+                    }
+                }
+                (Some(actual), None) => {
+                    writeln!(differences, "[{idx}]: unexpected {actual:?}").unwrap();
+                }
+                (None, Some(expected)) => {
+                    writeln!(differences, "[{idx}]: missing {expected:?}").unwrap();
+                }
+                (None, None) => unreachable!(),
+            }
+
+            differing.push(idx);
+        }
+
+        if differing.is_empty() {
+            return cx.pass();
+        }
+
+        if subject.len() != expected.len() {
+            cx.annotate("subject length", subject.len());
+            cx.annotate("expected length", expected.len());
+        }
+
+        cx.annotate("differing indexes", format!("{differing:?}"));
+        cx.add_page("differences", differences);
+        cx.fail("elements not equal")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn differing_indexes_are_listed() {
+        expect!(vec![1, 2, 3], to_equal_slice([1, 2, 3]));
+        let error = try_expect!(vec![1, 5, 3, 4], to_equal_slice([1, 2, 3])).unwrap_err();
+        let message = error.to_string();
+        expect!(&message, to_contain_substr("differing indexes: [1, 3]"));
+        expect!(&message, to_contain_substr("[3]: unexpected 4"));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the number of elements in the subject that match a predicate
+/// is within a bound.
+#[derive(Clone, Debug)]
+pub struct ToHaveCountMatching<F> {
+    count: Annotated<usize>,
+    predicate: Annotated<F>,
+    bound: CountBound,
+}
+
+impl<F> ToHaveCountMatching<F> {
+    #[inline]
+    pub(crate) fn new(count: Annotated<usize>, predicate: Annotated<F>, bound: CountBound) -> Self {
+        Self {
+            count,
+            predicate,
+            bound,
+        }
+    }
+}
+
+impl<F, T> Assertion<T> for ToHaveCountMatching<F>
+where
+    F: FnMut(&T::Item) -> bool,
+    T: IntoIterator,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("predicate", &self.predicate);
+        let count = *self.count.inner();
+        let predicate = self.predicate.into_inner();
+        match self.bound {
+            CountBound::Exactly => {
+                cx.annotate("expected", self.count);
+                let actual = count_matching(subject, predicate, None);
+                cx.annotate("actual", actual);
+                cx.pass_if(actual == count, "a different number of elements matched")
+            }
+            CountBound::AtLeast => {
+                cx.annotate("min", self.count);
+                let reached = count_matching(subject, predicate, Some(count));
+                cx.annotate("count reached", reached);
+                cx.pass_if(reached >= count, "too few elements matched")
+            }
+            CountBound::AtMost => {
+                cx.annotate("max", self.count);
+                let reached = count_matching(subject, predicate, Some(count.saturating_add(1)));
+                cx.annotate("count reached", reached);
+                cx.pass_if(reached <= count, "too many elements matched")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum CountBound {
+    Exactly,
+    AtLeast,
+    AtMost,
+}
+
+/// Counts the elements that match a predicate, stopping early once `limit`
+/// matching elements have been found.
+fn count_matching<T, F>(subject: T, mut predicate: F, limit: Option<usize>) -> usize
+where
+    T: IntoIterator,
+    F: FnMut(&T::Item) -> bool,
+{
+    let matching = subject.into_iter().filter(|item| predicate(item));
+    match limit {
+        Some(limit) => matching.take(limit).count(),
+        None => matching.count(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn actual_count_is_annotated() {
+        expect!([1, 2, 3, 4], to_have_count_matching(2, |&n: &i32| n > 2));
+        expect!(
+            try_expect!([1, 2, 3, 4], to_have_count_matching(1, |&n: &i32| n > 2)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("actual: 2"),
+        );
+    }
+
+    #[test]
+    fn bounds_short_circuit() {
+        expect!(0.., to_have_at_least_matching(3, |&n: &i32| n % 2 == 0));
+        expect!(
+            try_expect!(0.., to_have_at_most_matching(3, |&n: &i32| n % 2 == 0)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("count reached: 4"),
+        );
+    }
+
+    #[test]
+    fn bounds_are_inclusive() {
+        expect!([1, 2, 3], to_have_at_least_matching(2, |&n: &i32| n > 1));
+        expect!([1, 2, 3], to_have_at_most_matching(2, |&n: &i32| n > 1));
+        expect!(
+            try_expect!([1, 2, 3], to_have_at_least_matching(3, |&n: &i32| n > 1)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("count reached: 2"),
+        );
+    }
+}
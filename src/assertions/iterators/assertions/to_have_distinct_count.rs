@@ -0,0 +1,89 @@
+use std::{collections::HashSet, hash::Hash, marker::PhantomData};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+use super::{ByEquality, ByHash};
+
+/// Asserts that the subject contains a number of distinct elements.
+///
+/// The type parameter `L` determines how elements are compared. See
+/// [`ByEquality`] and [`ByHash`].
+#[derive(Clone, Debug)]
+pub struct ToHaveDistinctCount<L = ByEquality> {
+    expected: Annotated<usize>,
+    lookup: PhantomData<fn() -> L>,
+}
+
+impl<L> ToHaveDistinctCount<L> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<usize>) -> Self {
+        Self {
+            expected,
+            lookup: PhantomData,
+        }
+    }
+
+    fn finish(self, mut cx: AssertionContext, distinct: usize) -> AssertionOutput {
+        let expected = *self.expected.inner();
+        cx.annotate("expected", self.expected);
+        cx.annotate("distinct count", distinct);
+        cx.pass_if(distinct == expected, "wrong number of distinct elements")
+    }
+}
+
+impl<T> Assertion<T> for ToHaveDistinctCount<ByEquality>
+where
+    T: IntoIterator<Item: PartialEq>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
+        let mut distinct = Vec::new();
+        for item in subject {
+            if !distinct.contains(&item) {
+                distinct.push(item);
+            }
+        }
+
+        self.finish(cx, distinct.len())
+    }
+}
+
+impl<T> Assertion<T> for ToHaveDistinctCount<ByHash>
+where
+    T: IntoIterator<Item: Hash + Eq>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
+        let distinct: HashSet<_> = subject.into_iter().collect();
+        self.finish(cx, distinct.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(&[], 0; "empty")]
+    #[test_case(&[1, 2, 3], 3; "all distinct")]
+    #[test_case(&[1, 1, 2, 1], 2; "duplicates")]
+    fn counts_distinct_elements(subject: &[i32], expected: usize) {
+        expect!(subject, to_have_distinct_count(expected));
+        expect!(subject, to_have_distinct_count_hashed(expected));
+    }
+
+    #[test]
+    fn distinct_count_is_annotated() {
+        let message = try_expect!([1.0, 1.0, 2.0], to_have_distinct_count(3))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("distinct count: 2"));
+    }
+}
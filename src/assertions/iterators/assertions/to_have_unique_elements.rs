@@ -0,0 +1,103 @@
+use std::{collections::HashMap, fmt::Debug, fmt::Write, hash::Hash};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// Asserts that the elements of the subject are all different.
+#[derive(Clone, Debug)]
+pub struct ToHaveUniqueElements {
+    report_all: bool,
+}
+
+impl ToHaveUniqueElements {
+    #[inline]
+    pub(crate) fn new(report_all: bool) -> Self {
+        Self { report_all }
+    }
+}
+
+impl<T> Assertion<T> for ToHaveUniqueElements
+where
+    T: IntoIterator<Item: Hash + Eq + Debug>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        // Maps each element to the index it first appeared at and the number
+        // of times it appeared
+        let mut seen: HashMap<_, (usize, usize)> = HashMap::new();
+        for (idx, item) in subject.into_iter().enumerate() {
+            if let Some((first_idx, count)) = seen.get_mut(&item) {
+                if !self.report_all {
+                    cx.annotate("indexes", format_args!("{first_idx} and {idx}"));
+                    cx.annotate("duplicate", format_args!("{item:?}"));
+                    return cx.fail("elements are not unique");
+                }
+
+                *count += 1;
+                continue;
+            }
+
+            let _ = seen.insert(item, (idx, 1));
+        }
+
+        let mut duplicates: Vec<_> = seen
+            .into_iter()
+            .filter(|(_, (_, count))| *count > 1)
+            .collect();
+        if duplicates.is_empty() {
+            return cx.pass();
+        }
+
+        duplicates.sort_by_key(|(_, (first_idx, _))| *first_idx);
+        let mut page = String::new();
+        for (item, (first_idx, count)) in &duplicates {
+            writeln!(
+                page,
+                "{item:?}: {count} occurrences, first at index {first_idx}"
+            )
+            .unwrap();
+        }
+
+        cx.annotate("duplicated values", duplicates.len());
+        cx.add_page("duplicates", page);
+        cx.fail("elements are not unique")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn first_duplicate_is_annotated() {
+        expect!([1, 2, 3], to_have_unique_elements);
+        expect!(
+            try_expect!([1, 2, 3, 2, 1], to_have_unique_elements),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("indexes: 1 and 3"),
+        );
+    }
+
+    #[test]
+    fn reporting_lists_every_duplicate() {
+        expect!([1, 2, 3], to_have_unique_elements_reporting);
+
+        let message = try_expect!(
+            ["a", "b", "a", "c", "b", "a"],
+            to_have_unique_elements_reporting
+        )
+        .unwrap_err()
+        .to_string();
+        expect!(&message, to_contain_substr("duplicated values: 2"));
+        expect!(
+            &message,
+            to_contain_substr(
+                "\"a\": 3 occurrences, first at index 0\n\"b\": 2 occurrences, first at index 1"
+            ),
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::{collections::HashMap, fmt::Debug, hash::Hash, marker::PhantomData};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+use super::{ByEquality, ByHash};
+
+/// Asserts that the keys extracted from the elements of the subject are all
+/// different.
+///
+/// The type parameter `L` determines how keys are compared. See [`ByEquality`]
+/// and [`ByHash`].
+#[derive(Clone, Debug)]
+pub struct ToHaveUniqueKeys<F, L = ByEquality> {
+    key_fn: Annotated<F>,
+    lookup: PhantomData<fn() -> L>,
+}
+
+impl<F, L> ToHaveUniqueKeys<F, L> {
+    #[inline]
+    pub(crate) fn new(key_fn: Annotated<F>) -> Self {
+        Self {
+            key_fn,
+            lookup: PhantomData,
+        }
+    }
+}
+
+impl<F, K, T> Assertion<T> for ToHaveUniqueKeys<F, ByEquality>
+where
+    F: FnMut(&T::Item) -> K,
+    K: PartialEq + Debug,
+    T: IntoIterator,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("key", &self.key_fn);
+
+        let mut key_fn = self.key_fn.into_inner();
+        let mut seen: Vec<K> = Vec::new();
+        for (idx, item) in subject.into_iter().enumerate() {
+            let key = key_fn(&item);
+            if let Some(first_idx) = seen.iter().position(|seen| *seen == key) {
+                return fail(cx, first_idx, idx, &key);
+            }
+
+            seen.push(key);
+        }
+
+        cx.pass()
+    }
+}
+
+impl<F, K, T> Assertion<T> for ToHaveUniqueKeys<F, ByHash>
+where
+    F: FnMut(&T::Item) -> K,
+    K: Hash + Eq + Debug,
+    T: IntoIterator,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("key", &self.key_fn);
+
+        let mut key_fn = self.key_fn.into_inner();
+        let mut seen = HashMap::new();
+        for (idx, item) in subject.into_iter().enumerate() {
+            let key = key_fn(&item);
+            if let Some(&first_idx) = seen.get(&key) {
+                return fail(cx, first_idx, idx, &key);
+            }
+
+            let _ = seen.insert(key, idx);
+        }
+
+        cx.pass()
+    }
+}
+
+fn fail<K>(mut cx: AssertionContext, first_idx: usize, idx: usize, key: &K) -> AssertionOutput
+where
+    K: Debug,
+{
+    cx.annotate("indexes", format_args!("{first_idx} and {idx}"));
+    cx.annotate("duplicate key", format_args!("{key:?}"));
+    cx.fail("keys are not unique")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn first_duplicate_is_annotated() {
+        expect!(["a", "bc", "def"], to_have_unique_keys(|s: &&str| s.len()));
+        expect!(
+            ["a", "bc", "def"],
+            to_have_unique_keys_hashed(|s: &&str| s.len())
+        );
+        expect!(
+            try_expect!(
+                ["a", "bc", "de", "f"],
+                to_have_unique_keys(|s: &&str| s.len())
+            ),
+            to_be_err_and,
+            as_display,
+            to_satisfy_with(|message| {
+                try_expect!(&message, to_contain_substr("indexes: 1 and 2"))?;
+                try_expect!(&message, to_contain_substr("duplicate key: 2"))?;
+                Ok(())
+            }),
+        );
+        expect!(
+            try_expect!(
+                ["a", "bc", "de", "f"],
+                to_have_unique_keys_hashed(|s: &&str| s.len())
+            ),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("indexes: 1 and 2"),
+        );
+    }
+
+    #[test]
+    fn unhashable_keys_are_compared() {
+        expect!([1.5, 2.5], to_have_unique_keys(|&n: &f64| n * 2.0));
+        expect!([1.5, 1.5], not, to_have_unique_keys(|&n: &f64| n * 2.0));
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::{Debug, Write};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+use super::to_contain_at_least_counts::group_by_equality;
+
+/// Asserts that the subject yields exactly the elements of another sequence,
+/// in any order. The subject and the other sequence are compared as multisets.
+#[derive(Clone, Debug)]
+pub struct ToYieldExactly<I> {
+    expected: Annotated<I>,
+}
+
+impl<I> ToYieldExactly<I> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<I>) -> Self {
+        Self { expected }
+    }
+}
+
+impl<I, T> Assertion<T> for ToYieldExactly<I>
+where
+    I: IntoIterator<Item: PartialEq + Debug>,
+    T: IntoIterator<Item: PartialEq<I::Item> + Debug>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.expected);
+
+        let expected: Vec<_> = self.expected.into_inner().into_iter().collect();
+        let mut groups = group_by_equality(&expected);
+
+        // Any element that doesn't fit into a group is extra
+        let mut extra = Vec::new();
+        for (index, item) in subject.into_iter().enumerate() {
+            match groups
+                .iter_mut()
+                .find(|group| item == expected[group.first_index])
+            {
+                Some(group) if group.found < group.required => group.found += 1,
+                _ => extra.push((index, format!("{item:?}"))),
+            }
+        }
+
+        let missing: Vec<_> = groups
+            .iter()
+            .filter(|group| group.found < group.required)
+            .map(|group| (group.first_index, group.required - group.found))
+            .collect();
+        if missing.is_empty() && extra.is_empty() {
+            return cx.pass();
+        }
+
+        let mut differences = String::new();
+        for &(index, count) in &missing {
+            let item = &expected[index];
+            write!(differences, "- expected[{index}]: {item:?}").unwrap();
+            if count > 1 {
+                write!(differences, " (x{count})").unwrap();
+            }
+            writeln!(differences).unwrap();
+        }
+        for (index, repr) in &extra {
+            writeln!(differences, "+ subject[{index}]: {repr}").unwrap();
+        }
+
+        cx.annotate(
+            "missing",
+            missing.iter().map(|&(_, count)| count).sum::<usize>(),
+        );
+        cx.annotate("extra", extra.len());
+        cx.add_page("differences", differences.trim_end());
+        cx.fail("subject does not yield exactly the expected elements")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(&[1, 2, 3], &[3, 1, 2]; "reordered")]
+    #[test_case(&[1, 1, 2], &[1, 2, 1]; "duplicates")]
+    #[test_case(&[], &[]; "empty")]
+    fn success_cases(subject: &[i32], expected: &[i32]) {
+        expect!(subject, to_yield_exactly(expected));
+    }
+
+    #[test_case(&[1, 2], &[1, 2, 3]; "missing")]
+    #[test_case(&[1, 2, 3], &[1, 2]; "extra")]
+    #[test_case(&[1, 1, 2], &[1, 2, 2]; "wrong counts")]
+    fn failure_cases(subject: &[i32], expected: &[i32]) {
+        expect!(subject, not, to_yield_exactly(expected));
+    }
+
+    #[test]
+    fn differences_are_listed() {
+        let message = try_expect!([4, 1, 5, 1], to_yield_exactly([1, 2, 2, 4]))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("missing: 2"));
+        expect!(&message, to_contain_substr("extra: 2"));
+        expect!(&message, to_contain_substr("- expected[1]: 2 (x2)"));
+        expect!(&message, to_contain_substr("+ subject[2]: 5"));
+        expect!(&message, to_contain_substr("+ subject[3]: 1"));
+    }
+}
@@ -1,11 +1,29 @@
-use crate::{assertions::AssertionBuilder, metadata::Annotated};
+use std::{
+    borrow::Borrow,
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use crate::{
+    assertions::{general::Float, AssertionBuilder},
+    metadata::Annotated,
+};
 
 use super::{
-    AsUtf8Modifier, CountModifier, MergeModifier, MergeStrategy, NthModifier, ToContain,
-    ToContainExactly,
+    AsUtf8Modifier, BoundedModifier, ByEquality, ByHash, ChunksModifier, CountBound, CountModifier,
+    Direction, Indexed, LenModifier, MergeModifier, MergeStrategy, NthModifier, PartitionModifier,
+    RetainingModifier, ScanModifier, ToAllBeEqual, ToAllBeFinite, ToAllSatisfyWithContext,
+    ToBeSorted, ToBeSortedByKey, ToBeStrictlyMonotonic, ToBeSubsequenceOf, ToBeSubsetOf,
+    ToBeSupersetOf, ToContain, ToContainAtLeastCounts, ToContainExactly, ToContainExactlyApprox,
+    ToContainExactlyOnce, ToContainInOrder, ToContainSubslice, ToEqualSlice, ToHaveCountMatching,
+    ToHaveDistinctCount, ToHaveUniqueElements, ToHaveUniqueKeys, ToNotContain, ToYieldExactly,
+    WindowsModifier,
 };
 
 /// Assertions and modifiers for [Iterator]s.
+// Clippy reports a missing `is_empty` on the trait rather than on `len`, so the
+// lint can't be allowed on the method itself
+#[allow(clippy::len_without_is_empty)]
 pub trait IteratorAssertions<T, M>
 where
     T: IntoIterator,
@@ -48,6 +66,28 @@ where
     /// ```
     fn all(self) -> AssertionBuilder<T::Item, MergeModifier<M>>;
 
+    /// Executes an assertion on every value within the subject, and succeeds if and
+    /// only if none of the assertions fail. Unlike [`all`](Self::all), this does
+    /// not stop at the first failure, and every failing index is reported.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([1, 3, 5], all_reporting, to_be_less_than(10));
+    /// ```
+    ///
+    /// The assertion fails if any element does not satisfy the assertion:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// // Reports that both indexes 0 and 1 failed
+    /// expect!([1, 3, 5], all_reporting, to_equal(5));
+    /// ```
+    ///
+    /// Since every element is checked, this does not complete on unbounded
+    /// iterators. Like [`all`](Self::all), this requires that the rest of the
+    /// assertion is [`Clone`].
+    fn all_reporting(self) -> AssertionBuilder<T::Item, MergeModifier<M>>;
+
     /// Executes an assertion on every value within the subject, and succeeds if and
     /// only if an assertion succeeds.
     ///
@@ -57,7 +97,8 @@ where
     /// expect!([] as [i32; 0], not, any, to_equal(1));
     /// ```
     ///
-    /// The assertion fails if no element satisfies the assertion:
+    /// The assertion fails if no element satisfies the assertion. Every
+    /// element's failure is listed in the failure message:
     ///
     /// ```should_panic
     /// # use expecters::prelude::*;
@@ -86,6 +127,47 @@ where
     /// ```
     fn any(self) -> AssertionBuilder<T::Item, MergeModifier<M>>;
 
+    /// Like [`all`](Self::all), but executes the assertion on each element
+    /// paired with its index. This is useful for assertions that depend on the
+    /// position of each element.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     [0usize, 2, 4],
+    ///     all_indexed,
+    ///     to_satisfy(|(idx, n): (usize, usize)| n == 2 * idx),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if any element does not satisfy the assertion:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     [0usize, 2, 5],
+    ///     all_indexed,
+    ///     to_satisfy(|(idx, n): (usize, usize)| n == 2 * idx),
+    /// );
+    /// ```
+    fn all_indexed(self) -> AssertionBuilder<(usize, T::Item), MergeModifier<M, Indexed>>;
+
+    /// Like [`any`](Self::any), but executes the assertion on each element
+    /// paired with its index.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(["a", "b", "c"], any_indexed, to_equal((1, "b")));
+    /// ```
+    ///
+    /// The assertion fails if no element satisfies the assertion:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(["a", "b", "c"], any_indexed, to_equal((0, "b")));
+    /// ```
+    fn any_indexed(self) -> AssertionBuilder<(usize, T::Item), MergeModifier<M, Indexed>>;
+
     /// Counts the length of the subject, and executes an assertion on the result.
     ///
     /// ```
@@ -93,12 +175,145 @@ where
     /// expect!([1, 2, 3], count, to_equal(3));
     /// ```
     ///
-    /// This uses the [`Iterator::count`] method to determine the number of elements
-    /// in the subject. If the subject is an unbounded iterator, then the assertion
-    /// will not complete (unless it panics for another reason). See the iterator
-    /// method for more information.
+    /// If the rest of the assertion is a comparison (like
+    /// [`to_be_greater_than`]), then counting stops as soon as the result of the
+    /// comparison can no longer change:
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// // Stops after 6 items
+    /// expect!(std::iter::repeat(1), count, to_be_greater_than(5));
+    /// ```
+    ///
+    /// Otherwise, every element in the subject is counted. If the subject is an
+    /// unbounded iterator, then the assertion will not complete (unless it panics
+    /// for another reason). Use [`bounded`](Self::bounded) to guard against this.
+    ///
+    /// [`to_be_greater_than`]: crate::prelude::GeneralAssertions::to_be_greater_than
     fn count(self) -> AssertionBuilder<usize, CountModifier<M>>;
 
+    /// Gets the exact length of the subject, and executes an assertion on the
+    /// result. Unlike [`count`](Self::count), this does not iterate over the
+    /// subject, so it's faster for subjects with a known length, like
+    /// [`Vec`]s and slices.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(vec![1, 2, 3], len, to_equal(3));
+    /// ```
+    ///
+    /// The subject's iterator must implement [`ExactSizeIterator`]. Use
+    /// [`count`](Self::count) for other subjects.
+    fn len(self) -> AssertionBuilder<usize, LenModifier<M>>
+    where
+        T::IntoIter: ExactSizeIterator;
+
+    /// Collects at most `max` elements from the subject, then executes an
+    /// assertion on the collected elements. If the subject has more than `max`
+    /// elements, then the assertion fails instead.
+    ///
+    /// This is useful for guarding assertions like [`count`](Self::count) that
+    /// would otherwise never complete on unbounded iterators.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], bounded(5), count, to_equal(3));
+    /// ```
+    ///
+    /// The assertion fails if the subject has too many elements:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(std::iter::repeat(1), bounded(5), count, to_equal(5));
+    /// ```
+    fn bounded(self, max: Annotated<usize>) -> AssertionBuilder<Vec<T::Item>, BoundedModifier<M>>;
+
+    /// Splits the subject into chunks of `size` elements, then executes an
+    /// assertion on the chunks. The last chunk may have fewer elements than the
+    /// others.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     1..=5,
+    ///     chunks(2),
+    ///     to_equal(vec![vec![1, 2], vec![3, 4], vec![5]]),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if `size` is zero:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], chunks(0), count, to_equal(0));
+    /// ```
+    fn chunks(
+        self,
+        size: Annotated<usize>,
+    ) -> AssertionBuilder<Vec<Vec<T::Item>>, ChunksModifier<M>>;
+
+    /// Splits the subject into overlapping windows of `size` elements, then
+    /// executes an assertion on the windows. This is useful for checking how
+    /// adjacent elements relate to each other. If the subject has fewer than
+    /// `size` elements, then there are no windows.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     [1, 3, 4, 8],
+    ///     windows(2),
+    ///     all,
+    ///     to_satisfy(|pair: Vec<i32>| pair[0] < pair[1]),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if `size` is zero:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], windows(0), count, to_equal(0));
+    /// ```
+    fn windows(
+        self,
+        size: Annotated<usize>,
+    ) -> AssertionBuilder<Vec<Vec<T::Item>>, WindowsModifier<M>>
+    where
+        T::Item: Clone;
+
+    /// Folds the elements of the subject into a sequence of running aggregates,
+    /// then executes an assertion on the aggregates. Each aggregate is computed
+    /// from the previous aggregate (or `init` for the first element) and the
+    /// next element. The initial value is not included in the aggregates.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     [1, 2, 3, 4],
+    ///     scan(0, |sum: &i32, n| sum + n),
+    ///     to_equal(vec![1, 3, 6, 10]),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the aggregates do not satisfy the assertion. The
+    /// initial value and function are included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     [1, 2, -5],
+    ///     scan(0, |sum: &i32, n| sum + n),
+    ///     all,
+    ///     to_be_greater_than(0),
+    /// );
+    /// ```
+    fn scan<S, F>(
+        self,
+        init: Annotated<S>,
+        f: Annotated<F>,
+    ) -> AssertionBuilder<Vec<S>, ScanModifier<M, S, F>>
+    where
+        F: FnMut(&S, T::Item) -> S;
+
     /// Applies an assertion to a specific element in the target. If the element
     /// does not exist or does not satisfy the assertion, then the result is
     /// treated as a failure. The index is zero-based.
@@ -123,6 +338,65 @@ where
     /// ```
     fn nth(self, index: Annotated<usize>) -> AssertionBuilder<T::Item, NthModifier<M>>;
 
+    /// Splits the subject into the elements that satisfy a predicate and the
+    /// elements that don't, then executes an assertion on both groups. The
+    /// elements in each group stay in the same order.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     [1, 2, 3, 4],
+    ///     partition(|&n: &i32| n % 2 == 0),
+    ///     to_equal((vec![2, 4], vec![1, 3])),
+    /// );
+    /// ```
+    ///
+    /// This can be combined with other modifiers to check only one of the
+    /// groups:
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     [1, 2, 3, 4],
+    ///     partition(|&n: &i32| n > 1),
+    ///     map(|(matching, _): (Vec<_>, Vec<_>)| matching.len()),
+    ///     to_equal(3),
+    /// );
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn partition<F>(
+        self,
+        predicate: Annotated<F>,
+    ) -> AssertionBuilder<(Vec<T::Item>, Vec<T::Item>), PartitionModifier<M, F>>
+    where
+        F: FnMut(&T::Item) -> bool;
+
+    /// Keeps only the elements of the subject that match a predicate, then
+    /// continues the assertion with the retained elements in their original
+    /// order.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     [1, 2, 3, 4],
+    ///     retaining(|&n: &i32| n % 2 == 0),
+    ///     to_equal(vec![2, 4]),
+    /// );
+    /// ```
+    ///
+    /// This is useful for checking that no elements match a predicate:
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([-1, 0, -2], retaining(|&n: &i32| n > 0), count, to_equal(0));
+    /// ```
+    fn retaining<F>(
+        self,
+        predicate: Annotated<F>,
+    ) -> AssertionBuilder<Vec<T::Item>, RetainingModifier<M, F>>
+    where
+        F: FnMut(&T::Item) -> bool;
+
     /// Reads the subject as a UTF-8 encoded string.
     ///
     /// ```
@@ -162,6 +436,50 @@ where
         ToContain::new(expected)
     }
 
+    /// Asserts that the subject does not contain an element.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], to_not_contain(4));
+    /// ```
+    ///
+    /// The assertion fails if the element is found. The index where it was
+    /// first found is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], to_not_contain(2));
+    /// ```
+    #[inline]
+    fn to_not_contain<U>(&self, unexpected: Annotated<U>) -> ToNotContain<U>
+    where
+        T::Item: PartialEq<U>,
+    {
+        ToNotContain::new(unexpected)
+    }
+
+    /// Asserts that the subject contains an element exactly once.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], to_contain_exactly_once(3));
+    /// ```
+    ///
+    /// This assertion fails if the element is not found, or if it is found more
+    /// than once:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1, 3, 3], to_contain_exactly_once(3));
+    /// ```
+    #[inline]
+    fn to_contain_exactly_once<U>(&self, expected: Annotated<U>) -> ToContainExactlyOnce<U>
+    where
+        T::Item: PartialEq<U>,
+    {
+        ToContainExactlyOnce::new(expected)
+    }
+
     /// Asserts that the subject is equal to the given sequence.
     ///
     /// ```
@@ -184,30 +502,882 @@ where
     {
         ToContainExactly::new(expected)
     }
-}
 
-impl<T, M> IteratorAssertions<T, M> for AssertionBuilder<T, M>
-where
-    T: IntoIterator,
-{
+    /// Asserts that the subject contains the given slice as a contiguous run of
+    /// elements. This is the slice equivalent of
+    /// [`to_contain_substr`](crate::prelude::StringAssertions::to_contain_substr).
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let packet = vec![0x7e, 0x01, 0x02, 0x03, 0x7e];
+    /// expect!(&packet, to_contain_subslice([0x01, 0x02]));
+    /// ```
+    ///
+    /// The assertion fails if the elements of the slice do not appear next to
+    /// each other in the subject. If the slice is found, the offset of its
+    /// first occurrence is included in failure messages:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let packet = vec![0x7e, 0x01, 0x02, 0x03, 0x7e];
+    /// expect!(&packet, to_contain_subslice([0x01, 0x03]));
+    /// ```
+    ///
+    /// Both the subject and the slice must be convertible to slices, like
+    /// arrays, [`Vec`]s, or slices themselves. An empty slice is found at
+    /// offset zero.
     #[inline]
-    fn all(self) -> AssertionBuilder<T::Item, MergeModifier<M>> {
-        AssertionBuilder::modify(self, |prev| MergeModifier::new(prev, MergeStrategy::All))
+    fn to_contain_subslice<S, E>(&self, subslice: Annotated<S>) -> ToContainSubslice<S, E>
+    where
+        T: AsRef<[E]>,
+        S: AsRef<[E]>,
+        E: PartialEq,
+    {
+        ToContainSubslice::new(subslice)
     }
 
+    /// Asserts that the subject is approximately equal to the given sequence.
+    /// Each element of the subject must be within `max_delta` of the element
+    /// at the same index in the expected sequence.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     vec![0.1 + 0.2, 1.0 / 3.0],
+    ///     to_contain_exactly_approximately([0.3, 0.333], 0.001),
+    /// );
+    /// ```
+    ///
+    /// This assertion fails if the sequences are different lengths, or if any
+    /// elements differ by more than `max_delta`. The first index exceeding the
+    /// tolerance and its delta are included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1.0, 2.5], to_contain_exactly_approximately([1.0, 2.0], 0.1));
+    /// ```
     #[inline]
-    fn any(self) -> AssertionBuilder<T::Item, MergeModifier<M>> {
-        AssertionBuilder::modify(self, |prev| MergeModifier::new(prev, MergeStrategy::Any))
+    fn to_contain_exactly_approximately<I>(
+        &self,
+        expected: Annotated<I>,
+        max_delta: Annotated<T::Item>,
+    ) -> ToContainExactlyApprox<I, T::Item>
+    where
+        I: IntoIterator<Item = T::Item>,
+        T::Item: Float,
+    {
+        ToContainExactlyApprox::new(expected, max_delta)
     }
 
+    /// Asserts that the subject is equal to the given sequence, comparing each
+    /// element in order.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(vec![1, 2, 3], to_equal_slice([1, 2, 3]));
+    /// ```
+    ///
+    /// Unlike [`to_equal`], a failure lists every index where the sequences
+    /// differ, along with a diff of the elements at those indexes:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(vec![1, 2, 3, 4], to_equal_slice([1, 5, 3]));
+    /// ```
+    ///
+    /// [`to_equal`]: crate::prelude::GeneralAssertions::to_equal
     #[inline]
-    fn count(self) -> AssertionBuilder<usize, CountModifier<M>> {
-        AssertionBuilder::modify(self, CountModifier::new)
+    fn to_equal_slice<I>(&self, expected: Annotated<I>) -> ToEqualSlice<I>
+    where
+        I: IntoIterator<Item: Debug>,
+        T::Item: PartialEq<I::Item> + Debug,
+    {
+        ToEqualSlice::new(expected)
     }
 
+    /// Asserts that every element of the subject appears in another sequence.
+    /// Duplicate elements and the order of the elements are ignored.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([3, 1], to_be_subset_of([1, 2, 3]));
+    /// ```
+    ///
+    /// This assertion fails if any element of the subject is not found. The
+    /// missing elements are included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1, 4], to_be_subset_of([1, 2, 3]));
+    /// ```
+    ///
+    /// Elements are compared with [`PartialEq`]. For large sequences of
+    /// hashable elements, use
+    /// [`to_be_subset_of_hashed`](IteratorAssertions::to_be_subset_of_hashed)
+    /// instead.
     #[inline]
-    fn nth(self, index: Annotated<usize>) -> AssertionBuilder<T::Item, NthModifier<M>> {
-        AssertionBuilder::modify(self, move |prev| NthModifier::new(prev, index))
+    fn to_be_subset_of<I>(&self, superset: Annotated<I>) -> ToBeSubsetOf<I, ByEquality>
+    where
+        I: IntoIterator,
+        T::Item: PartialEq<I::Item> + Debug,
+    {
+        ToBeSubsetOf::new(superset)
+    }
+
+    /// Same as [`to_be_subset_of`](IteratorAssertions::to_be_subset_of), but
+    /// compares elements by hashing them. This is faster for large sequences.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([3, 1], to_be_subset_of_hashed([1, 2, 3]));
+    /// ```
+    #[inline]
+    fn to_be_subset_of_hashed<I>(&self, superset: Annotated<I>) -> ToBeSubsetOf<I, ByHash>
+    where
+        I: IntoIterator<Item: Borrow<T::Item> + Hash + Eq>,
+        T::Item: Hash + Eq + Debug,
+    {
+        ToBeSubsetOf::new(superset)
+    }
+
+    /// Asserts that the elements of the subject appear in the same order
+    /// within another sequence. Other elements may appear between them.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(["b", "d"], to_be_subsequence_of(["a", "b", "c", "d"]));
+    /// ```
+    ///
+    /// This assertion fails if the elements can't all be found in order. The
+    /// number of elements that were matched is included in the failure
+    /// message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(["d", "b"], to_be_subsequence_of(["a", "b", "c", "d"]));
+    /// ```
+    #[inline]
+    fn to_be_subsequence_of<I>(&self, sequence: Annotated<I>) -> ToBeSubsequenceOf<I>
+    where
+        I: IntoIterator,
+        T::Item: PartialEq<I::Item>,
+    {
+        ToBeSubsequenceOf::new(sequence)
+    }
+
+    /// Asserts that the given markers appear in the subject in the same order.
+    /// Other elements may appear between the markers. This is useful for
+    /// checking the relative order of events, like entries in a log.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let log = ["start", "load", "run", "finish"];
+    /// expect!(log, to_contain_in_order(["start", "run", "finish"]));
+    /// ```
+    ///
+    /// This assertion fails if the markers can't all be found in order. The
+    /// index of the first marker that wasn't found is included in the failure
+    /// message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let log = ["start", "finish", "run"];
+    /// expect!(log, to_contain_in_order(["start", "run", "finish"]));
+    /// ```
+    #[inline]
+    fn to_contain_in_order<I>(&self, markers: Annotated<I>) -> ToContainInOrder<I>
+    where
+        I: IntoIterator,
+        T::Item: PartialEq<I::Item>,
+    {
+        ToContainInOrder::new(markers)
+    }
+
+    /// Asserts that the subject contains each element of another sequence at
+    /// least as many times as it appears in that sequence. In other words, the
+    /// subject must be a superset of the other sequence when both are treated
+    /// as multisets.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let inventory = ["apple", "pear", "apple", "plum"];
+    /// expect!(inventory, to_contain_at_least_counts(["apple", "apple", "pear"]));
+    /// ```
+    ///
+    /// This assertion fails if any element appears fewer times in the subject
+    /// than in the other sequence. Each element that falls short is included
+    /// in the failure message, along with how many are missing:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let inventory = ["apple", "pear", "plum"];
+    /// expect!(inventory, to_contain_at_least_counts(["apple", "apple", "pear"]));
+    /// ```
+    ///
+    /// Elements are compared with [`PartialEq`]. For large sequences of
+    /// hashable elements, use
+    /// [`to_contain_at_least_counts_hashed`](IteratorAssertions::to_contain_at_least_counts_hashed)
+    /// instead.
+    #[inline]
+    fn to_contain_at_least_counts<I>(
+        &self,
+        expected: Annotated<I>,
+    ) -> ToContainAtLeastCounts<I, ByEquality>
+    where
+        I: IntoIterator<Item: PartialEq>,
+        T::Item: PartialEq<I::Item>,
+    {
+        ToContainAtLeastCounts::new(expected)
+    }
+
+    /// Same as
+    /// [`to_contain_at_least_counts`](IteratorAssertions::to_contain_at_least_counts),
+    /// but looks up elements by hashing them. This is faster for large
+    /// sequences.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let inventory = ["apple", "pear", "apple", "plum"];
+    /// expect!(
+    ///     inventory,
+    ///     to_contain_at_least_counts_hashed(["apple", "apple", "pear"]),
+    /// );
+    /// ```
+    #[inline]
+    fn to_contain_at_least_counts_hashed<I>(
+        &self,
+        expected: Annotated<I>,
+    ) -> ToContainAtLeastCounts<I, ByHash>
+    where
+        I: IntoIterator<Item: Hash + Eq>,
+        T::Item: Borrow<I::Item>,
+    {
+        ToContainAtLeastCounts::new(expected)
+    }
+
+    /// Asserts that the subject yields exactly the elements of another
+    /// sequence, in any order. Each element must appear the same number of
+    /// times in both sequences.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let results = vec!["b", "a", "c", "a"];
+    /// expect!(results, to_yield_exactly(["a", "a", "b", "c"]));
+    /// ```
+    ///
+    /// This assertion fails if any element is missing from the subject, or if
+    /// the subject has any extra elements. Both the missing and extra elements
+    /// are listed in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let results = vec!["b", "d", "a"];
+    /// expect!(results, to_yield_exactly(["a", "a", "b"]));
+    /// ```
+    #[inline]
+    fn to_yield_exactly<I>(&self, expected: Annotated<I>) -> ToYieldExactly<I>
+    where
+        I: IntoIterator<Item: PartialEq + Debug>,
+        T::Item: PartialEq<I::Item> + Debug,
+    {
+        ToYieldExactly::new(expected)
+    }
+
+    /// Asserts that every element of another sequence appears in the subject.
+    /// Duplicate elements and the order of the elements are ignored.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], to_be_superset_of([3, 1]));
+    /// ```
+    ///
+    /// This assertion fails if any element of the other sequence is not found.
+    /// The missing elements are included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], to_be_superset_of([1, 4]));
+    /// ```
+    ///
+    /// Elements are compared with [`PartialEq`]. For large sequences of
+    /// hashable elements, use
+    /// [`to_be_superset_of_hashed`](IteratorAssertions::to_be_superset_of_hashed)
+    /// instead.
+    #[inline]
+    fn to_be_superset_of<I>(&self, subset: Annotated<I>) -> ToBeSupersetOf<I, ByEquality>
+    where
+        I: IntoIterator<Item: Debug>,
+        T::Item: PartialEq<I::Item>,
+    {
+        ToBeSupersetOf::new(subset)
+    }
+
+    /// Same as [`to_be_superset_of`](IteratorAssertions::to_be_superset_of),
+    /// but compares elements by hashing them. This is faster for large
+    /// sequences.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], to_be_superset_of_hashed([3, 1]));
+    /// ```
+    #[inline]
+    fn to_be_superset_of_hashed<I>(&self, subset: Annotated<I>) -> ToBeSupersetOf<I, ByHash>
+    where
+        I: IntoIterator<Item: Hash + Eq + Debug>,
+        T::Item: Borrow<I::Item> + Hash + Eq,
+    {
+        ToBeSupersetOf::new(subset)
+    }
+
+    /// Asserts that the elements of the subject are in non-decreasing order.
+    /// Equal adjacent elements are allowed.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 2, 5], to_be_sorted);
+    /// ```
+    ///
+    /// This assertion fails if any element is less than the element before it.
+    /// The indexes and values of the first pair of elements that are out of
+    /// order are included in the failure message, along with a page listing
+    /// every adjacent pair that is out of order. If there are many such pairs,
+    /// only the first few are listed:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1, 3, 2, 5, 4], to_be_sorted);
+    /// ```
+    ///
+    /// Elements that can't be compared, like NaN, are treated as being out of
+    /// order.
+    #[inline]
+    #[must_use]
+    fn to_be_sorted(&self) -> ToBeSorted
+    where
+        T::Item: PartialOrd + Debug,
+    {
+        ToBeSorted::new()
+    }
+
+    /// Asserts that the subject is sorted by a key extracted from each element.
+    /// Elements with equal keys may appear in any order.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(["a", "bc", "de"], to_be_sorted_by_key(|s: &&str| s.len()));
+    /// ```
+    ///
+    /// This assertion fails if the keys are not in non-decreasing order. The
+    /// indexes and keys of the first pair of elements that are out of order are
+    /// included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(["bc", "a"], to_be_sorted_by_key(|s: &&str| s.len()));
+    /// ```
+    #[inline]
+    fn to_be_sorted_by_key<F, K>(&self, key_fn: Annotated<F>) -> ToBeSortedByKey<F>
+    where
+        F: FnMut(&T::Item) -> K,
+        K: Ord + Debug,
+    {
+        ToBeSortedByKey::new(key_fn)
+    }
+
+    /// Asserts that each element of the subject is strictly greater than the
+    /// element before it. Unlike [`to_be_sorted`](Self::to_be_sorted), equal
+    /// adjacent elements fail the assertion.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let timestamps = [10, 15, 30];
+    /// expect!(timestamps, to_be_strictly_increasing);
+    /// ```
+    ///
+    /// The indexes and values of the first offending pair of elements are
+    /// included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let timestamps = [10, 15, 15, 30];
+    /// expect!(timestamps, to_be_strictly_increasing);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_strictly_increasing(&self) -> ToBeStrictlyMonotonic
+    where
+        T::Item: PartialOrd + Debug,
+    {
+        ToBeStrictlyMonotonic::new(Direction::Increasing)
+    }
+
+    /// Asserts that each element of the subject is strictly less than the
+    /// element before it. Equal adjacent elements fail the assertion.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([3, 2, 1], to_be_strictly_decreasing);
+    /// ```
+    ///
+    /// The indexes and values of the first offending pair of elements are
+    /// included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([3, 3, 1], to_be_strictly_decreasing);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_strictly_decreasing(&self) -> ToBeStrictlyMonotonic
+    where
+        T::Item: PartialOrd + Debug,
+    {
+        ToBeStrictlyMonotonic::new(Direction::Decreasing)
+    }
+
+    /// Asserts that exactly `count` elements of the subject match a predicate.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let log = ["INFO start", "ERROR disk full", "INFO retry", "ERROR disk full"];
+    /// expect!(log, to_have_count_matching(2, |line: &&str| line.starts_with("ERROR")));
+    /// ```
+    ///
+    /// This assertion fails if a different number of elements match. The
+    /// actual number of matching elements is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let log = ["INFO start", "ERROR disk full", "INFO retry"];
+    /// expect!(log, to_have_count_matching(2, |line: &&str| line.starts_with("ERROR")));
+    /// ```
+    #[inline]
+    fn to_have_count_matching<F>(
+        &self,
+        count: Annotated<usize>,
+        predicate: Annotated<F>,
+    ) -> ToHaveCountMatching<F>
+    where
+        F: FnMut(&T::Item) -> bool,
+    {
+        ToHaveCountMatching::new(count, predicate, CountBound::Exactly)
+    }
+
+    /// Asserts that at least `count` elements of the subject match a predicate.
+    /// This stops reading the subject once enough matching elements are found,
+    /// so it can be used on unbounded iterators.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(0.., to_have_at_least_matching(3, |&n: &i32| n % 10 == 0));
+    /// ```
+    ///
+    /// This assertion fails if too few elements match. The number of matching
+    /// elements found is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], to_have_at_least_matching(2, |&n: &i32| n > 2));
+    /// ```
+    #[inline]
+    fn to_have_at_least_matching<F>(
+        &self,
+        count: Annotated<usize>,
+        predicate: Annotated<F>,
+    ) -> ToHaveCountMatching<F>
+    where
+        F: FnMut(&T::Item) -> bool,
+    {
+        ToHaveCountMatching::new(count, predicate, CountBound::AtLeast)
+    }
+
+    /// Asserts that at most `count` elements of the subject match a predicate.
+    /// This stops reading the subject once too many matching elements are
+    /// found.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], to_have_at_most_matching(1, |&n: &i32| n > 2));
+    /// ```
+    ///
+    /// This assertion fails if too many elements match. The number of matching
+    /// elements found is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1, 2, 3], to_have_at_most_matching(1, |&n: &i32| n > 1));
+    /// ```
+    #[inline]
+    fn to_have_at_most_matching<F>(
+        &self,
+        count: Annotated<usize>,
+        predicate: Annotated<F>,
+    ) -> ToHaveCountMatching<F>
+    where
+        F: FnMut(&T::Item) -> bool,
+    {
+        ToHaveCountMatching::new(count, predicate, CountBound::AtMost)
+    }
+
+    /// Asserts that the subject contains exactly the given number of distinct
+    /// elements. This is the same as counting the elements after removing
+    /// duplicates.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let colors = ["red", "blue", "red", "green"];
+    /// expect!(colors, to_have_distinct_count(3));
+    /// ```
+    ///
+    /// This assertion fails if the number of distinct elements is different.
+    /// The actual number of distinct elements is included in the failure
+    /// message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let colors = ["red", "blue", "red"];
+    /// expect!(colors, to_have_distinct_count(3));
+    /// ```
+    ///
+    /// Elements are compared with [`PartialEq`]. For large sequences of
+    /// hashable elements, use
+    /// [`to_have_distinct_count_hashed`](IteratorAssertions::to_have_distinct_count_hashed)
+    /// instead.
+    #[inline]
+    fn to_have_distinct_count(&self, expected: Annotated<usize>) -> ToHaveDistinctCount<ByEquality>
+    where
+        T::Item: PartialEq,
+    {
+        ToHaveDistinctCount::new(expected)
+    }
+
+    /// Same as
+    /// [`to_have_distinct_count`](IteratorAssertions::to_have_distinct_count),
+    /// but compares elements by hashing them. This is faster for large
+    /// sequences.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let colors = ["red", "blue", "red", "green"];
+    /// expect!(colors, to_have_distinct_count_hashed(3));
+    /// ```
+    #[inline]
+    fn to_have_distinct_count_hashed(
+        &self,
+        expected: Annotated<usize>,
+    ) -> ToHaveDistinctCount<ByHash>
+    where
+        T::Item: Hash + Eq,
+    {
+        ToHaveDistinctCount::new(expected)
+    }
+
+    /// Asserts that no two elements of the subject are equal.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(["a", "b", "c"], to_have_unique_elements);
+    /// ```
+    ///
+    /// This assertion fails at the first duplicated element. The duplicated
+    /// element and the indexes it was found at are included in the failure
+    /// message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(["a", "b", "a"], to_have_unique_elements);
+    /// ```
+    ///
+    /// To report every duplicated element instead, use
+    /// [`to_have_unique_elements_reporting`](Self::to_have_unique_elements_reporting).
+    #[inline]
+    #[must_use]
+    fn to_have_unique_elements(&self) -> ToHaveUniqueElements
+    where
+        T::Item: Hash + Eq + Debug,
+    {
+        ToHaveUniqueElements::new(false)
+    }
+
+    /// Asserts that no two elements of the subject are equal. Unlike
+    /// [`to_have_unique_elements`](Self::to_have_unique_elements), this reads
+    /// the whole subject and reports every duplicated element, along with the
+    /// number of times it appears.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(["a", "b", "c"], to_have_unique_elements_reporting);
+    /// ```
+    ///
+    /// This assertion fails if any element appears more than once:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// // Reports that "a" appears 3 times and "b" appears twice
+    /// expect!(["a", "b", "a", "b", "a"], to_have_unique_elements_reporting);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_have_unique_elements_reporting(&self) -> ToHaveUniqueElements
+    where
+        T::Item: Hash + Eq + Debug,
+    {
+        ToHaveUniqueElements::new(true)
+    }
+
+    /// Asserts that no two elements of the subject share the same key, where
+    /// the key is extracted from each element. This is useful for checking that
+    /// identifiers are unique across a collection of records.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([(1, "a"), (2, "a")], to_have_unique_keys(|&(id, _): &(i32, &str)| id));
+    /// ```
+    ///
+    /// This assertion fails if any two elements have the same key. The
+    /// duplicated key and the indexes of the elements are included in the
+    /// failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([(1, "a"), (1, "b")], to_have_unique_keys(|&(id, _): &(i32, &str)| id));
+    /// ```
+    ///
+    /// Keys are compared with [`PartialEq`]. For large sequences with hashable
+    /// keys, use
+    /// [`to_have_unique_keys_hashed`](IteratorAssertions::to_have_unique_keys_hashed)
+    /// instead.
+    #[inline]
+    fn to_have_unique_keys<F, K>(&self, key_fn: Annotated<F>) -> ToHaveUniqueKeys<F, ByEquality>
+    where
+        F: FnMut(&T::Item) -> K,
+        K: PartialEq + Debug,
+    {
+        ToHaveUniqueKeys::new(key_fn)
+    }
+
+    /// Same as [`to_have_unique_keys`](IteratorAssertions::to_have_unique_keys),
+    /// but compares keys by hashing them. This is faster for large sequences.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     [(1, "a"), (2, "a")],
+    ///     to_have_unique_keys_hashed(|&(id, _): &(i32, &str)| id),
+    /// );
+    /// ```
+    #[inline]
+    fn to_have_unique_keys_hashed<F, K>(&self, key_fn: Annotated<F>) -> ToHaveUniqueKeys<F, ByHash>
+    where
+        F: FnMut(&T::Item) -> K,
+        K: Hash + Eq + Debug,
+    {
+        ToHaveUniqueKeys::new(key_fn)
+    }
+
+    /// Asserts that every element of the subject satisfies a predicate. If an
+    /// element doesn't satisfy it, the predicate returns the reason why. Every
+    /// element is checked, and the reason for each failure is listed in the
+    /// failure message.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let ports = [80, 443, 8080];
+    /// expect!(
+    ///     ports,
+    ///     to_all_satisfy_with_context(|port: u16| match port {
+    ///         0 => Err("port 0 is reserved".to_string()),
+    ///         _ => Ok(()),
+    ///     }),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if any element doesn't satisfy the predicate. The
+    /// index of the first failing element is included in the failure message,
+    /// along with a page listing the index and reason for each failure:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let ports = [80, 0, 70000];
+    /// expect!(
+    ///     ports,
+    ///     to_all_satisfy_with_context(|port: u32| match port {
+    ///         0 => Err("port 0 is reserved".to_string()),
+    ///         65536.. => Err(format!("port {port} is out of range")),
+    ///         _ => Ok(()),
+    ///     }),
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to combining
+    /// [`all_reporting`](IteratorAssertions::all_reporting) with
+    /// [`to_satisfy_or_explain`](crate::prelude::GeneralAssertions::to_satisfy_or_explain),
+    /// except the predicate is shared between elements. This means it may be
+    /// [`FnMut`] and doesn't need to be [`Clone`].
+    #[inline]
+    fn to_all_satisfy_with_context<F, E>(
+        &self,
+        predicate: Annotated<F>,
+    ) -> ToAllSatisfyWithContext<F>
+    where
+        F: FnMut(T::Item) -> Result<(), E>,
+        E: Display,
+    {
+        ToAllSatisfyWithContext::new(predicate)
+    }
+
+    /// Asserts that every element of the subject is equal to each other. This
+    /// passes if the subject has at most one element.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([1, 1, 1], to_all_be_equal);
+    /// ```
+    ///
+    /// This assertion fails if any element is not equal to the first element.
+    /// The first element, along with the index and value of the first element
+    /// that differs from it, is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1, 1, 2], to_all_be_equal);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_all_be_equal(&self) -> ToAllBeEqual
+    where
+        T::Item: PartialEq + Debug,
+    {
+        ToAllBeEqual::new()
+    }
+
+    /// Asserts that no element of the subject is NaN or infinite.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!([0.0, -1.5, 2.25], to_all_be_finite);
+    /// ```
+    ///
+    /// This assertion fails at the first element that is not finite. Its index
+    /// and value are included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!([1.0, f64::NAN, 3.0], to_all_be_finite);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_all_be_finite(&self) -> ToAllBeFinite
+    where
+        T::Item: Float,
+    {
+        ToAllBeFinite::new()
+    }
+}
+
+impl<T, M> IteratorAssertions<T, M> for AssertionBuilder<T, M>
+where
+    T: IntoIterator,
+{
+    #[inline]
+    fn all(self) -> AssertionBuilder<T::Item, MergeModifier<M>> {
+        AssertionBuilder::modify(self, |prev| MergeModifier::new(prev, MergeStrategy::All))
+    }
+
+    #[inline]
+    fn all_reporting(self) -> AssertionBuilder<T::Item, MergeModifier<M>> {
+        AssertionBuilder::modify(self, MergeModifier::new_reporting)
+    }
+
+    #[inline]
+    fn any(self) -> AssertionBuilder<T::Item, MergeModifier<M>> {
+        AssertionBuilder::modify(self, |prev| MergeModifier::new(prev, MergeStrategy::Any))
+    }
+
+    #[inline]
+    fn all_indexed(self) -> AssertionBuilder<(usize, T::Item), MergeModifier<M, Indexed>> {
+        AssertionBuilder::modify(self, |prev| MergeModifier::new(prev, MergeStrategy::All))
+    }
+
+    #[inline]
+    fn any_indexed(self) -> AssertionBuilder<(usize, T::Item), MergeModifier<M, Indexed>> {
+        AssertionBuilder::modify(self, |prev| MergeModifier::new(prev, MergeStrategy::Any))
+    }
+
+    #[inline]
+    fn count(self) -> AssertionBuilder<usize, CountModifier<M>> {
+        AssertionBuilder::modify(self, CountModifier::new)
+    }
+
+    #[inline]
+    fn len(self) -> AssertionBuilder<usize, LenModifier<M>>
+    where
+        T::IntoIter: ExactSizeIterator,
+    {
+        AssertionBuilder::modify(self, LenModifier::new)
+    }
+
+    #[inline]
+    fn bounded(self, max: Annotated<usize>) -> AssertionBuilder<Vec<T::Item>, BoundedModifier<M>> {
+        AssertionBuilder::modify(self, move |prev| BoundedModifier::new(prev, max))
+    }
+
+    #[inline]
+    fn chunks(
+        self,
+        size: Annotated<usize>,
+    ) -> AssertionBuilder<Vec<Vec<T::Item>>, ChunksModifier<M>> {
+        AssertionBuilder::modify(self, move |prev| ChunksModifier::new(prev, size))
+    }
+
+    #[inline]
+    fn windows(
+        self,
+        size: Annotated<usize>,
+    ) -> AssertionBuilder<Vec<Vec<T::Item>>, WindowsModifier<M>>
+    where
+        T::Item: Clone,
+    {
+        AssertionBuilder::modify(self, move |prev| WindowsModifier::new(prev, size))
+    }
+
+    #[inline]
+    fn scan<S, F>(
+        self,
+        init: Annotated<S>,
+        f: Annotated<F>,
+    ) -> AssertionBuilder<Vec<S>, ScanModifier<M, S, F>>
+    where
+        F: FnMut(&S, T::Item) -> S,
+    {
+        AssertionBuilder::modify(self, move |prev| ScanModifier::new(prev, init, f))
+    }
+
+    #[inline]
+    fn nth(self, index: Annotated<usize>) -> AssertionBuilder<T::Item, NthModifier<M>> {
+        AssertionBuilder::modify(self, move |prev| NthModifier::new(prev, index))
+    }
+
+    #[inline]
+    fn partition<F>(
+        self,
+        predicate: Annotated<F>,
+    ) -> AssertionBuilder<(Vec<T::Item>, Vec<T::Item>), PartitionModifier<M, F>>
+    where
+        F: FnMut(&T::Item) -> bool,
+    {
+        AssertionBuilder::modify(self, move |prev| PartitionModifier::new(prev, predicate))
+    }
+
+    #[inline]
+    fn retaining<F>(
+        self,
+        predicate: Annotated<F>,
+    ) -> AssertionBuilder<Vec<T::Item>, RetainingModifier<M, F>>
+    where
+        F: FnMut(&T::Item) -> bool,
+    {
+        AssertionBuilder::modify(self, move |prev| RetainingModifier::new(prev, predicate))
     }
 
     #[inline]
@@ -1,9 +1,23 @@
 mod as_utf8;
+mod bounded;
+mod chunks;
 mod count;
+mod len;
 mod merge;
 mod nth;
+mod partition;
+mod retaining;
+mod scan;
+mod windows;
 
 pub use as_utf8::*;
+pub use bounded::*;
+pub use chunks::*;
 pub use count::*;
+pub use len::*;
 pub use merge::*;
 pub use nth::*;
+pub use partition::*;
+pub use retaining::*;
+pub use scan::*;
+pub use windows::*;
@@ -0,0 +1,86 @@
+use crate::{
+    assertions::{
+        general::IntoInitializableOutput, Assertion, AssertionContext, AssertionContextBuilder,
+        AssertionModifier,
+    },
+    metadata::Annotated,
+};
+
+/// Limits the number of items that can be read from the subject.
+#[derive(Clone, Debug)]
+pub struct BoundedModifier<M> {
+    prev: M,
+    max: Annotated<usize>,
+}
+
+impl<M> BoundedModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M, max: Annotated<usize>) -> Self {
+        Self { prev, max }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for BoundedModifier<M>
+where
+    M: AssertionModifier<BoundedAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            BoundedAssertion {
+                next,
+                max: self.max,
+            },
+        )
+    }
+}
+
+/// Collects at most a fixed number of items from the subject, then executes the
+/// inner assertion on the collected items. Fails if the subject has more items
+/// than that.
+#[derive(Clone, Debug)]
+pub struct BoundedAssertion<A> {
+    next: A,
+    max: Annotated<usize>,
+}
+
+impl<A, T> Assertion<T> for BoundedAssertion<A>
+where
+    A: Assertion<Vec<T::Item>, Output: IntoInitializableOutput>,
+    T: IntoIterator,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let max = *self.max.inner();
+        cx.annotate("max", self.max);
+
+        // Take one extra item to detect if the subject has too many
+        let items: Vec<_> = subject.into_iter().take(max.saturating_add(1)).collect();
+        if items.len() > max {
+            return cx.fail(format!("iterator exceeded {max} elements"));
+        }
+
+        self.next.execute(cx, items).into_initialized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn unbounded_iterators_fail() {
+        expect!(std::iter::repeat(1), not, bounded(10), count, to_equal(10));
+        expect!((0..).cycle(), not, bounded(10), all, to_be_less_than(100));
+    }
+
+    #[test]
+    fn bounded_iterators_pass() {
+        expect!(0..10, bounded(10), count, to_equal(10));
+        expect!(0..10, bounded(10), to_contain_exactly(0..10));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{
+    assertions::{
+        general::IntoInitializableOutput, Assertion, AssertionContext, AssertionContextBuilder,
+        AssertionModifier,
+    },
+    metadata::Annotated,
+};
+
+/// Splits the subject into chunks of a fixed size.
+#[derive(Clone, Debug)]
+pub struct ChunksModifier<M> {
+    prev: M,
+    size: Annotated<usize>,
+}
+
+impl<M> ChunksModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M, size: Annotated<usize>) -> Self {
+        Self { prev, size }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for ChunksModifier<M>
+where
+    M: AssertionModifier<ChunksAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            ChunksAssertion {
+                next,
+                size: self.size,
+            },
+        )
+    }
+}
+
+/// Splits the subject into chunks of a fixed size, then executes the inner
+/// assertion on the chunks. The last chunk may be shorter than the others.
+/// Fails if the chunk size is zero.
+#[derive(Clone, Debug)]
+pub struct ChunksAssertion<A> {
+    next: A,
+    size: Annotated<usize>,
+}
+
+impl<A, T> Assertion<T> for ChunksAssertion<A>
+where
+    A: Assertion<Vec<Vec<T::Item>>, Output: IntoInitializableOutput>,
+    T: IntoIterator,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let size = *self.size.inner();
+        cx.annotate("size", self.size);
+        if size == 0 {
+            return cx.fail("chunk size must be greater than zero");
+        }
+
+        let mut subject = subject.into_iter();
+        let mut chunks = Vec::new();
+        loop {
+            let chunk: Vec<_> = subject.by_ref().take(size).collect();
+            if chunk.is_empty() {
+                break;
+            }
+
+            chunks.push(chunk);
+        }
+
+        self.next.execute(cx, chunks).into_initialized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn last_chunk_is_shorter() {
+        expect!(
+            1..=5,
+            chunks(2),
+            to_equal(vec![vec![1, 2], vec![3, 4], vec![5]])
+        );
+        expect!(Vec::<i32>::new(), chunks(2), count, to_equal(0));
+    }
+
+    #[test]
+    fn zero_size_fails() {
+        expect!(
+            try_expect!([1, 2, 3], chunks(0), count, to_equal(0)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("chunk size must be greater than zero"),
+        );
+    }
+}
@@ -38,8 +38,64 @@ where
 {
     type Output = A::Output;
 
-    #[inline]
-    fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
-        self.next.execute(cx, subject.into_iter().count())
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        // Iterators may be able to count their items without iterating them
+        let mut items = subject.into_iter();
+        if !A::CAN_SHORT_CIRCUIT {
+            return self.next.execute(cx, items.count());
+        }
+
+        // Stop counting once the result of the next assertion can't change
+        let mut count = 0;
+        while !self.next.is_determined_by(&count) {
+            if items.next().is_none() {
+                return self.next.execute(cx, count);
+            }
+            count += 1;
+        }
+
+        cx.annotate("stopped early", format_args!("after {count} items"));
+        self.next.execute(cx, count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter::repeat;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn unbounded_comparisons_stop_early() {
+        expect!(repeat(1), count, to_be_greater_than(5));
+        expect!(repeat(1), count, to_be_greater_than_or_equal_to(5));
+        expect!(repeat(1), count, not, to_be_less_than(5));
+        expect!(repeat(1), count, not, to_be_less_than_or_equal_to(5));
+    }
+
+    #[test]
+    fn bounded_comparisons_count_everything() {
+        expect!(0..5, count, to_be_less_than_or_equal_to(5));
+        expect!(0..5, count, not, to_be_greater_than(5));
+        expect!(0..5, count, to_equal(5));
+    }
+
+    #[test]
+    fn other_assertions_use_iterator_count() {
+        struct CountOnly;
+
+        impl Iterator for CountOnly {
+            type Item = ();
+
+            fn next(&mut self) -> Option<Self::Item> {
+                panic!("iterated the subject")
+            }
+
+            fn count(self) -> usize {
+                3
+            }
+        }
+
+        expect!(CountOnly, count, to_equal(3));
     }
 }
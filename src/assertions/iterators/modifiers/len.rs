@@ -0,0 +1,62 @@
+use crate::assertions::{Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier};
+
+/// Gets the exact length of a subject without iterating over it.
+#[derive(Clone, Debug)]
+pub struct LenModifier<M> {
+    prev: M,
+}
+
+impl<M> LenModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M) -> Self {
+        Self { prev }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for LenModifier<M>
+where
+    M: AssertionModifier<LenAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(cx, LenAssertion { next })
+    }
+}
+
+/// Executes the inner assertion on the exact length of the subject.
+#[derive(Clone, Debug)]
+pub struct LenAssertion<A> {
+    next: A,
+}
+
+impl<A, T> Assertion<T> for LenAssertion<A>
+where
+    A: Assertion<usize>,
+    T: IntoIterator<IntoIter: ExactSizeIterator>,
+{
+    type Output = A::Output;
+
+    #[inline]
+    fn execute(self, cx: AssertionContext, subject: T) -> Self::Output {
+        self.next.execute(cx, subject.into_iter().len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn does_not_iterate() {
+        let items = [1, 2, 3].map(|n| n * 2);
+        expect!(items, len, to_equal(3));
+        let items = vec![1; 1000];
+        expect!(&items, len, to_be_greater_than(999));
+
+        // Mapping is lazy, so the function is never called
+        let lazy = (0..5).map(|_: i32| -> i32 { panic!("iterated") });
+        expect!(lazy, len, to_equal(5));
+    }
+}
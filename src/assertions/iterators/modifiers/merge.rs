@@ -1,3 +1,5 @@
+use std::marker::PhantomData;
+
 use crate::assertions::{
     iterators::{MergeStrategy, MergeableOutput},
     Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier,
@@ -5,21 +7,37 @@ use crate::assertions::{
 
 /// Forks an assertion, executing it for each element of the subject.
 #[derive(Clone, Debug)]
-pub struct MergeModifier<M> {
+pub struct MergeModifier<M, I = Unindexed> {
     prev: M,
     strategy: MergeStrategy,
+    reporting: bool,
+    indexing: PhantomData<fn() -> I>,
 }
 
-impl<M> MergeModifier<M> {
+impl<M, I> MergeModifier<M, I> {
     #[inline]
     pub(crate) fn new(prev: M, strategy: MergeStrategy) -> Self {
-        Self { prev, strategy }
+        Self {
+            prev,
+            strategy,
+            reporting: false,
+            indexing: PhantomData,
+        }
+    }
+
+    /// Creates a modifier that checks every element and reports each failure.
+    #[inline]
+    pub(crate) fn new_reporting(prev: M) -> Self {
+        Self {
+            reporting: true,
+            ..Self::new(prev, MergeStrategy::All)
+        }
     }
 }
 
-impl<M, A> AssertionModifier<A> for MergeModifier<M>
+impl<M, I, A> AssertionModifier<A> for MergeModifier<M, I>
 where
-    M: AssertionModifier<MergeAssertion<A>>,
+    M: AssertionModifier<MergeAssertion<A, I>>,
 {
     type Output = M::Output;
 
@@ -30,6 +48,8 @@ where
             MergeAssertion {
                 next,
                 strategy: self.strategy,
+                reporting: self.reporting,
+                indexing: PhantomData,
             },
         )
     }
@@ -37,14 +57,31 @@ where
 
 /// Forks the inner assertion, executing it for each element of the subject.
 #[derive(Clone, Debug)]
-pub struct MergeAssertion<A> {
+pub struct MergeAssertion<A, I = Unindexed> {
     next: A,
     strategy: MergeStrategy,
+    reporting: bool,
+    indexing: PhantomData<fn() -> I>,
+}
+
+impl<A, I> MergeAssertion<A, I> {
+    /// Creates an assertion that checks every element and reports each
+    /// failure.
+    #[inline]
+    pub(crate) fn new_reporting(next: A) -> Self {
+        Self {
+            next,
+            strategy: MergeStrategy::All,
+            reporting: true,
+            indexing: PhantomData,
+        }
+    }
 }
 
-impl<A, T> Assertion<T> for MergeAssertion<A>
+impl<A, I, T> Assertion<T> for MergeAssertion<A, I>
 where
-    A: Assertion<T::Item, Output: MergeableOutput> + Clone,
+    A: Assertion<I::Item<T::Item>, Output: MergeableOutput> + Clone,
+    I: MergeIndexing,
     T: IntoIterator,
 {
     type Output = <A::Output as MergeableOutput>::Merged;
@@ -61,12 +98,53 @@ where
                 cx.annotate("index", idx);
 
                 // Call the next assertion
-                self.next.clone().execute(cx, item)
+                self.next.clone().execute(cx, I::wrap(idx, item))
             }
         });
 
         // Merge the outputs
-        MergeableOutput::merge(cx, self.strategy, outputs)
+        if self.reporting {
+            MergeableOutput::merge_reporting(cx, outputs)
+        } else {
+            MergeableOutput::merge(cx, self.strategy, outputs)
+        }
+    }
+}
+
+/// Determines what [`MergeModifier`] passes to the inner assertion for each
+/// element of the subject.
+pub trait MergeIndexing {
+    /// The value passed to the inner assertion for an element.
+    type Item<T>;
+
+    /// Creates the value passed to the inner assertion from an element and its
+    /// index.
+    fn wrap<T>(idx: usize, item: T) -> Self::Item<T>;
+}
+
+/// Passes each element to the inner assertion as-is.
+#[derive(Clone, Copy, Debug)]
+pub enum Unindexed {}
+
+impl MergeIndexing for Unindexed {
+    type Item<T> = T;
+
+    #[inline]
+    fn wrap<T>(_idx: usize, item: T) -> Self::Item<T> {
+        item
+    }
+}
+
+/// Passes each element to the inner assertion along with its index.
+#[derive(Clone, Copy, Debug)]
+pub enum Indexed {}
+
+impl MergeIndexing for Indexed {
+    type Item<T> = (usize, T);
+
+    #[inline]
+    fn wrap<T>(idx: usize, item: T) -> Self::Item<T> {
+        (idx, item)
     }
 }
 
@@ -100,6 +178,57 @@ mod tests {
         let success = with_timeout(Duration::from_secs(1), f);
         expect!(success, to_equal(should_pass));
     }
+
+    #[test]
+    fn all_reporting_lists_every_failure() {
+        expect!([1, 2, 3], all_reporting, to_be_less_than(4));
+
+        let error = try_expect!([1, 5, 2, 6], all_reporting, to_be_less_than(4)).unwrap_err();
+        let message = error.to_string();
+        expect!(&message, to_contain_substr("2 of 4 elements failed"));
+        expect!(&message, to_contain_substr("index: 1"));
+        expect!(&message, to_contain_substr("index 1: "));
+        expect!(&message, to_contain_substr("index 3: "));
+        expect!(&message, not, to_contain_substr("index 0: "));
+    }
+
+    #[test]
+    fn indexed_elements_include_index() {
+        expect!(
+            [0usize, 2, 4],
+            all_indexed,
+            to_satisfy(|(idx, n): (usize, usize)| n == 2 * idx),
+        );
+        expect!(["a", "b", "c"], any_indexed, to_equal((1, "b")));
+        expect!(
+            try_expect!(
+                [0usize, 1, 1],
+                all_indexed,
+                to_satisfy(|(idx, n): (usize, usize)| n == idx)
+            ),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("index: 2"),
+        );
+    }
+
+    #[test]
+    fn any_lists_every_failure() {
+        let error = try_expect!([5, 6, 7], any, to_be_less_than(4)).unwrap_err();
+        let message = error.to_string();
+        expect!(&message, to_contain_substr("none of the 3 elements passed"));
+        expect!(&message, to_contain_substr("index 0: "));
+        expect!(&message, to_contain_substr("index 1: "));
+        expect!(&message, to_contain_substr("index 2: "));
+    }
+
+    #[test]
+    fn negated_all_reports_last_element() {
+        let error = try_expect!([1, 2], not, all, to_be_less_than(5)).unwrap_err();
+        let message = error.to_string();
+        expect!(&message, to_contain_substr("index: 1"));
+        expect!(&message, to_contain_substr("to_be_less_than"));
+    }
 }
 
 #[cfg(all(test, feature = "futures"))]
@@ -170,6 +299,22 @@ mod async_tests {
         expect!(success, to_equal(should_pass));
     }
 
+    #[tokio::test]
+    async fn all_reporting_lists_every_failure() {
+        let error = try_expect!(
+            [ready(1), ready(5), ready(6)],
+            all_reporting,
+            when_ready,
+            to_be_less_than(4)
+        )
+        .await
+        .unwrap_err();
+        let message = error.to_string();
+        expect!(&message, to_contain_substr("2 of 3 elements failed"));
+        expect!(&message, to_contain_substr("index 1: "));
+        expect!(&message, to_contain_substr("index 2: "));
+    }
+
     /// Ensures that assertions that use non-Clone opaque features can still be
     /// executed with the merging modifiers. This means the assertion executed
     /// after the merging modifier must be Clone even if the subject passed into
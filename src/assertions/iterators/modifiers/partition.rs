@@ -0,0 +1,83 @@
+use crate::{
+    assertions::{Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier},
+    metadata::Annotated,
+};
+
+/// Splits the subject into the elements that match a predicate and the
+/// elements that don't.
+#[derive(Clone, Debug)]
+pub struct PartitionModifier<M, F> {
+    prev: M,
+    predicate: Annotated<F>,
+}
+
+impl<M, F> PartitionModifier<M, F> {
+    #[inline]
+    pub(crate) fn new(prev: M, predicate: Annotated<F>) -> Self {
+        Self { prev, predicate }
+    }
+}
+
+impl<M, F, A> AssertionModifier<A> for PartitionModifier<M, F>
+where
+    M: AssertionModifier<PartitionAssertion<A, F>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            PartitionAssertion {
+                next,
+                predicate: self.predicate,
+            },
+        )
+    }
+}
+
+/// Partitions the subject using a predicate, then executes the inner assertion
+/// on the matching and non-matching elements.
+#[derive(Clone, Debug)]
+pub struct PartitionAssertion<A, F> {
+    next: A,
+    predicate: Annotated<F>,
+}
+
+impl<A, F, T> Assertion<T> for PartitionAssertion<A, F>
+where
+    A: Assertion<(Vec<T::Item>, Vec<T::Item>)>,
+    F: FnMut(&T::Item) -> bool,
+    T: IntoIterator,
+{
+    type Output = A::Output;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("predicate", &self.predicate);
+
+        let predicate = self.predicate.into_inner();
+        self.next
+            .execute(cx, subject.into_iter().partition(predicate))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn partitions_are_in_order() {
+        expect!(
+            1..=6,
+            partition(|&n: &i32| n % 2 == 0),
+            to_equal((vec![2, 4, 6], vec![1, 3, 5])),
+        );
+        expect!(
+            1..=6,
+            partition(|&n: &i32| n > 3),
+            map(|(matching, _): (Vec<_>, Vec<_>)| matching.len()),
+            to_equal(3),
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    assertions::{Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier},
+    metadata::Annotated,
+};
+
+/// Keeps only the elements of the subject that match a predicate.
+#[derive(Clone, Debug)]
+pub struct RetainingModifier<M, F> {
+    prev: M,
+    predicate: Annotated<F>,
+}
+
+impl<M, F> RetainingModifier<M, F> {
+    #[inline]
+    pub(crate) fn new(prev: M, predicate: Annotated<F>) -> Self {
+        Self { prev, predicate }
+    }
+}
+
+impl<M, F, A> AssertionModifier<A> for RetainingModifier<M, F>
+where
+    M: AssertionModifier<RetainingAssertion<A, F>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            RetainingAssertion {
+                next,
+                predicate: self.predicate,
+            },
+        )
+    }
+}
+
+/// Collects the elements of the subject that match a predicate, then executes
+/// the inner assertion on them.
+#[derive(Clone, Debug)]
+pub struct RetainingAssertion<A, F> {
+    next: A,
+    predicate: Annotated<F>,
+}
+
+impl<A, F, T> Assertion<T> for RetainingAssertion<A, F>
+where
+    A: Assertion<Vec<T::Item>>,
+    F: FnMut(&T::Item) -> bool,
+    T: IntoIterator,
+{
+    type Output = A::Output;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("predicate", &self.predicate);
+
+        let predicate = self.predicate.into_inner();
+        self.next
+            .execute(cx, subject.into_iter().filter(predicate).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn matching_elements_are_retained() {
+        expect!(
+            [1, 2, 3, 4],
+            retaining(|n: &i32| n % 2 == 0),
+            to_equal(vec![2, 4]),
+        );
+        expect!(
+            [1, 2, 3, 4],
+            retaining(|n: &i32| n % 2 == 0),
+            all,
+            to_be_even
+        );
+    }
+
+    #[test]
+    fn other_elements_are_removed() {
+        expect!(
+            [1, 2, 3, 4],
+            retaining(|n: &i32| n % 2 == 0),
+            not,
+            to_contain(1),
+        );
+        expect!(
+            try_expect!([1, 2, 3], retaining(|n: &i32| *n > 1), to_contain(1)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("predicate: |n: &i32| *n > 1"),
+        );
+    }
+
+    #[test]
+    fn no_matches_retains_nothing() {
+        expect!(
+            [1, 3, 5],
+            retaining(|n: &i32| n % 2 == 0),
+            count,
+            to_equal(0)
+        );
+        expect!(
+            Vec::<i32>::new(),
+            retaining(|_: &i32| true),
+            to_equal(Vec::<i32>::new()),
+        );
+    }
+}
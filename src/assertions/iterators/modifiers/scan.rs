@@ -0,0 +1,104 @@
+use crate::{
+    assertions::{Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier},
+    metadata::Annotated,
+};
+
+/// Folds the elements of the subject into a sequence of running aggregates.
+#[derive(Clone, Debug)]
+pub struct ScanModifier<M, S, F> {
+    prev: M,
+    init: Annotated<S>,
+    f: Annotated<F>,
+}
+
+impl<M, S, F> ScanModifier<M, S, F> {
+    #[inline]
+    pub(crate) fn new(prev: M, init: Annotated<S>, f: Annotated<F>) -> Self {
+        Self { prev, init, f }
+    }
+}
+
+impl<M, S, F, A> AssertionModifier<A> for ScanModifier<M, S, F>
+where
+    M: AssertionModifier<ScanAssertion<A, S, F>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            ScanAssertion {
+                next,
+                init: self.init,
+                f: self.f,
+            },
+        )
+    }
+}
+
+/// Folds the elements of the subject into a sequence of running aggregates,
+/// then executes the inner assertion on the aggregates. The initial value is
+/// not included in the aggregates.
+#[derive(Clone, Debug)]
+pub struct ScanAssertion<A, S, F> {
+    next: A,
+    init: Annotated<S>,
+    f: Annotated<F>,
+}
+
+impl<A, T, S, F> Assertion<T> for ScanAssertion<A, S, F>
+where
+    A: Assertion<Vec<S>>,
+    T: IntoIterator,
+    F: FnMut(&S, T::Item) -> S,
+{
+    type Output = A::Output;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("initial", &self.init);
+        cx.annotate("function", &self.f);
+
+        let init = self.init.into_inner();
+        let mut f = self.f.into_inner();
+        let mut aggregates = Vec::new();
+        for item in subject {
+            let next = f(aggregates.last().unwrap_or(&init), item);
+            aggregates.push(next);
+        }
+
+        self.next.execute(cx, aggregates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn running_values_are_collected() {
+        expect!(
+            [3, 1, 4, 1],
+            scan(0, |&max: &i32, n: i32| n.max(max)),
+            to_equal(vec![3, 3, 4, 4]),
+        );
+        expect!(
+            Vec::<i32>::new(),
+            scan(0, |sum: &i32, n| sum + n),
+            count,
+            to_equal(0),
+        );
+    }
+
+    #[test]
+    fn initial_value_is_annotated() {
+        let message = try_expect!(
+            [1, 2],
+            scan(10, |sum: &i32, n| sum + n),
+            to_equal(vec![1, 3]),
+        )
+        .unwrap_err()
+        .to_string();
+        expect!(&message, to_contain_substr("initial: 10"));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    assertions::{
+        general::IntoInitializableOutput, Assertion, AssertionContext, AssertionContextBuilder,
+        AssertionModifier,
+    },
+    metadata::Annotated,
+};
+
+/// Splits the subject into overlapping windows of a fixed size.
+#[derive(Clone, Debug)]
+pub struct WindowsModifier<M> {
+    prev: M,
+    size: Annotated<usize>,
+}
+
+impl<M> WindowsModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M, size: Annotated<usize>) -> Self {
+        Self { prev, size }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for WindowsModifier<M>
+where
+    M: AssertionModifier<WindowsAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            WindowsAssertion {
+                next,
+                size: self.size,
+            },
+        )
+    }
+}
+
+/// Splits the subject into overlapping windows of a fixed size, then executes
+/// the inner assertion on the windows. If the subject has fewer elements than
+/// the window size, then there are no windows. Fails if the window size is
+/// zero.
+#[derive(Clone, Debug)]
+pub struct WindowsAssertion<A> {
+    next: A,
+    size: Annotated<usize>,
+}
+
+impl<A, T> Assertion<T> for WindowsAssertion<A>
+where
+    A: Assertion<Vec<Vec<T::Item>>, Output: IntoInitializableOutput>,
+    T: IntoIterator<Item: Clone>,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let size = *self.size.inner();
+        cx.annotate("size", self.size);
+        if size == 0 {
+            return cx.fail("window size must be greater than zero");
+        }
+
+        let items: Vec<_> = subject.into_iter().collect();
+        let windows = items.windows(size).map(<[_]>::to_vec).collect();
+        self.next.execute(cx, windows).into_initialized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn windows_overlap() {
+        expect!(
+            1..=4,
+            windows(3),
+            to_equal(vec![vec![1, 2, 3], vec![2, 3, 4]])
+        );
+        expect!([1, 2], windows(3), count, to_equal(0));
+    }
+
+    #[test]
+    fn zero_size_fails() {
+        expect!(
+            try_expect!([1, 2, 3], windows(0), count, to_equal(0)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("window size must be greater than zero"),
+        );
+    }
+}
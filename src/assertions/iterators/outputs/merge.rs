@@ -5,7 +5,7 @@ use crate::{assertions::AssertionContext, AssertionOutput};
 ///
 /// This is the core of how modifiers like [`all`] and [`any`] work. Outputs
 /// that implement this trait can be collected from an iterator into a new
-/// output following one of two [merge strategies](MergeStrategy):
+/// output following one of the [merge strategies](MergeStrategy):
 ///
 /// - [`All`](MergeStrategy::All): the merged output succeeds if none of the
 ///   original outputs were failures.
@@ -31,6 +31,21 @@ pub trait MergeableOutput {
     fn merge<I>(cx: AssertionContext, strategy: MergeStrategy, outputs: I) -> Self::Merged
     where
         I: IntoIterator<Item = Self>;
+
+    /// Merges an iterator of assertion outputs into a single output following
+    /// the [`All`](MergeStrategy::All) strategy, but checks every output and
+    /// reports each failure. This is used by [`all_reporting`].
+    ///
+    /// By default, this is the same as merging with the `All` strategy.
+    ///
+    /// [`all_reporting`]: crate::prelude::IteratorAssertions::all_reporting
+    #[inline]
+    fn merge_reporting<I>(cx: AssertionContext, outputs: I) -> Self::Merged
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        Self::merge(cx, MergeStrategy::All, outputs)
+    }
 }
 
 impl MergeableOutput for AssertionOutput {
@@ -41,16 +56,112 @@ impl MergeableOutput for AssertionOutput {
     where
         I: IntoIterator<Item = Self>,
     {
-        let mut result = cx.pass_if(strategy == MergeStrategy::All, "no outputs");
+        if strategy == MergeStrategy::Any {
+            return merge_any(cx, outputs);
+        }
+
+        let mut result = cx.pass();
         for output in outputs {
-            match (strategy, output.is_pass()) {
-                (MergeStrategy::Any, true) | (MergeStrategy::All, false) => return output,
-                _ => result = output,
+            if !output.is_pass() {
+                return output;
             }
+
+            result = output;
         }
 
         result
     }
+
+    #[inline]
+    fn merge_reporting<I>(cx: AssertionContext, outputs: I) -> Self::Merged
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        merge_all_reporting(cx, outputs)
+    }
+}
+
+fn merge_all_reporting<I>(cx: AssertionContext, outputs: I) -> AssertionOutput
+where
+    I: IntoIterator<Item = AssertionOutput>,
+{
+    let mut total = 0;
+    let mut failures = Vec::new();
+    for (position, output) in outputs.into_iter().enumerate() {
+        total += 1;
+        if !output.is_pass() {
+            failures.push((position, output));
+        }
+    }
+
+    if failures.is_empty() {
+        return cx.pass();
+    }
+
+    // Report the first failure's execution path along with the list of failures
+    let message = format!("{} of {total} elements failed", failures.len());
+    report_failures(cx, failures, 0, message)
+}
+
+fn merge_any<I>(cx: AssertionContext, outputs: I) -> AssertionOutput
+where
+    I: IntoIterator<Item = AssertionOutput>,
+{
+    let mut failures = Vec::new();
+    for (position, output) in outputs.into_iter().enumerate() {
+        if output.is_pass() {
+            return output;
+        }
+
+        failures.push((position, output));
+    }
+
+    if failures.is_empty() {
+        return cx.fail("no outputs");
+    }
+
+    // Report the last failure's execution path along with the list of failures
+    let message = format!("none of the {} elements passed", failures.len());
+    let last = failures.len() - 1;
+    report_failures(cx, failures, last, message)
+}
+
+fn report_failures(
+    mut cx: AssertionContext,
+    mut failures: Vec<(usize, AssertionOutput)>,
+    reported: usize,
+    message: String,
+) -> AssertionOutput {
+    // The merge frame is the last frame visited before the outputs were forked
+    let depth = cx.visited.len();
+
+    // Outputs may be merged out of order, so use the annotated index if present
+    let indexes: Vec<_> = failures
+        .iter()
+        .map(|(position, output)| {
+            depth
+                .checked_sub(1)
+                .and_then(|idx| output.cx().visited.get(idx))
+                .and_then(|frame| frame.annotations.iter().find(|(key, _)| *key == "index"))
+                .map_or_else(|| position.to_string(), |(_, index)| index.clone())
+        })
+        .collect();
+    let lines: Vec<_> = failures
+        .iter()
+        .zip(&indexes)
+        .map(|((_, output), index)| {
+            let message = output.error().unwrap_or_default();
+            format!("index {index}: {message}")
+        })
+        .collect();
+
+    // The reported output's frames up to the merge frame are replaced, so keep
+    // its index on the merge frame
+    cx.annotate("index", &indexes[reported]);
+    let (_, mut result) = failures.swap_remove(reported);
+    cx.add_page("failures", lines.join("\n"));
+    result.set_fail(cx, message);
+    result
 }
 
 /// A strategy for merging outputs.
@@ -66,6 +177,7 @@ pub enum MergeStrategy {
     /// original outputs represented a success.
     ///
     /// On success, the success represents one or more of the original
-    /// successes.
+    /// successes. On failure, every original failure is listed in the merged
+    /// failure.
     Any,
 }
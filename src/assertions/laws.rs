@@ -0,0 +1,17 @@
+//! Assertions for validating that trait implementations follow the laws their
+//! traits require.
+//!
+//! These are useful for library authors who implement comparison traits like
+//! [`PartialEq`] and [`PartialOrd`] by hand.
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! expect!(1, to_be_equal_reflexively);
+//! expect!(1, to_be_consistent_ordering(2));
+//! ```
+
+mod assertions;
+mod extensions;
+
+pub use assertions::*;
+pub use extensions::*;
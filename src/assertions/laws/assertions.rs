@@ -0,0 +1,5 @@
+mod to_be_consistent_ordering;
+mod to_be_equal_reflexively;
+
+pub use to_be_consistent_ordering::*;
+pub use to_be_equal_reflexively::*;
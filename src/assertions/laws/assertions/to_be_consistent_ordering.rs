@@ -0,0 +1,100 @@
+use std::cmp::Ordering;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that comparing the subject to another value is consistent with
+/// comparing that value to the subject.
+#[derive(Clone, Debug)]
+pub struct ToBeConsistentOrdering<U> {
+    other: Annotated<U>,
+}
+
+impl<U> ToBeConsistentOrdering<U> {
+    #[inline]
+    pub(crate) fn new(other: Annotated<U>) -> Self {
+        Self { other }
+    }
+}
+
+impl<T, U> Assertion<T> for ToBeConsistentOrdering<U>
+where
+    T: PartialOrd<U>,
+    U: PartialOrd<T>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("other", &self.other);
+
+        let other = self.other.inner();
+        let forward = subject.partial_cmp(other);
+        let backward = other.partial_cmp(&subject);
+        cx.annotate("a.partial_cmp(b)", format_args!("{forward:?}"));
+        cx.annotate("b.partial_cmp(a)", format_args!("{backward:?}"));
+        if forward != backward.map(Ordering::reverse) {
+            cx.annotate(
+                "law",
+                "a.partial_cmp(b) == b.partial_cmp(a).map(Ordering::reverse)",
+            );
+            return cx.fail("inconsistent ordering");
+        }
+
+        // The comparison operators must agree with partial_cmp
+        let operators = [
+            (subject < *other, forward == Some(Ordering::Less), "a < b"),
+            (
+                subject > *other,
+                forward == Some(Ordering::Greater),
+                "a > b",
+            ),
+            (
+                subject == *other,
+                forward == Some(Ordering::Equal),
+                "a == b",
+            ),
+        ];
+        for (actual, expected, operator) in operators {
+            if actual != expected {
+                cx.annotate(
+                    "law",
+                    format_args!("{operator} agrees with a.partial_cmp(b)"),
+                );
+                return cx.fail("inconsistent ordering");
+            }
+        }
+
+        cx.pass()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::prelude::*;
+
+    #[derive(PartialEq, Debug)]
+    struct AlwaysLess;
+
+    impl PartialOrd for AlwaysLess {
+        fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+            Some(Ordering::Less)
+        }
+    }
+
+    #[test]
+    fn valid_orderings_pass() {
+        expect!(1, to_be_consistent_ordering(2));
+        expect!(2, to_be_consistent_ordering(2));
+        expect!(f64::NAN, to_be_consistent_ordering(1.0));
+    }
+
+    #[test]
+    fn invalid_orderings_fail() {
+        expect!(AlwaysLess, not, to_be_consistent_ordering(AlwaysLess));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// Asserts that the subject is equal to itself.
+#[derive(Clone, Debug)]
+pub struct ToBeEqualReflexively {}
+
+impl ToBeEqualReflexively {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T> Assertion<T> for ToBeEqualReflexively
+where
+    T: PartialEq,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("law", "a == a");
+        cx.pass_if(PartialEq::eq(&subject, &subject), "not equal to itself")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn nan_is_not_reflexive() {
+        expect!(1.0, to_be_equal_reflexively);
+        expect!(f64::NAN, not, to_be_equal_reflexively);
+    }
+}
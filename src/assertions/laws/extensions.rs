@@ -0,0 +1,69 @@
+use crate::{assertions::AssertionBuilder, metadata::Annotated};
+
+use super::{ToBeConsistentOrdering, ToBeEqualReflexively};
+
+/// Assertions for validating implementations of comparison traits.
+pub trait LawAssertions<T, M> {
+    /// Asserts that the subject is equal to itself.
+    ///
+    /// This is required by [`Eq`], but not by [`PartialEq`].
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(1, to_be_equal_reflexively);
+    /// ```
+    ///
+    /// The assertion fails if the subject is not equal to itself:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(f64::NAN, to_be_equal_reflexively);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_equal_reflexively(&self) -> ToBeEqualReflexively
+    where
+        T: PartialEq,
+    {
+        ToBeEqualReflexively::new()
+    }
+
+    /// Asserts that the ordering between the subject and another value is
+    /// consistent. Comparing the subject to the other value must give the
+    /// reverse of comparing the other value to the subject, and the comparison
+    /// operators (`<`, `>`, `==`) must agree with [`PartialOrd::partial_cmp`].
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(1, to_be_consistent_ordering(2));
+    /// ```
+    ///
+    /// The assertion fails if the ordering is inconsistent:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// #[derive(PartialEq)]
+    /// struct AlwaysLess;
+    ///
+    /// impl PartialOrd for AlwaysLess {
+    ///     fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+    ///         Some(Ordering::Less)
+    ///     }
+    /// }
+    ///
+    /// expect!(AlwaysLess, to_be_consistent_ordering(AlwaysLess));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_consistent_ordering<U>(&self, other: Annotated<U>) -> ToBeConsistentOrdering<U>
+    where
+        T: PartialOrd<U>,
+        U: PartialOrd<T>,
+    {
+        ToBeConsistentOrdering::new(other)
+    }
+}
+
+impl<T, M> LawAssertions<T, M> for AssertionBuilder<T, M> {}
@@ -0,0 +1,19 @@
+//! Assertions for tests that involve maps.
+//!
+//! The subject of these assertions can be any collection of key-value pairs,
+//! like a [`HashMap`](std::collections::HashMap),
+//! [`BTreeMap`](std::collections::BTreeMap), or [`Vec`] of pairs. Keys and
+//! values are compared using [`PartialEq`].
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! use std::collections::HashMap;
+//! let map = HashMap::from([("a", 1), ("b", 2)]);
+//! expect!(map, to_contain_entry("a", 1));
+//! ```
+
+mod assertions;
+mod extensions;
+
+pub use assertions::*;
+pub use extensions::*;
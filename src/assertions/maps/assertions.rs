@@ -0,0 +1,5 @@
+mod to_contain_entries;
+mod to_have_key_with;
+
+pub use to_contain_entries::*;
+pub use to_have_key_with::*;
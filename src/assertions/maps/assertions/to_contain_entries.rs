@@ -0,0 +1,122 @@
+use std::fmt::{Debug, Write};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject contains an entry with a key and value.
+#[derive(Clone, Debug)]
+pub struct ToContainEntry<K, V> {
+    key: Annotated<K>,
+    value: Annotated<V>,
+}
+
+impl<K, V> ToContainEntry<K, V> {
+    #[inline]
+    pub(crate) fn new(key: Annotated<K>, value: Annotated<V>) -> Self {
+        Self { key, value }
+    }
+}
+
+impl<T, K, V, EK, EV> Assertion<T> for ToContainEntry<EK, EV>
+where
+    T: IntoIterator<Item = (K, V)>,
+    K: PartialEq<EK>,
+    V: PartialEq<EV>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("key", &self.key);
+        cx.annotate("value", &self.value);
+
+        let Some((_, value)) = subject.into_iter().find(|(key, _)| key == self.key.inner()) else {
+            return cx.fail("key not found");
+        };
+
+        cx.pass_if(&value == self.value.inner(), "value not equal")
+    }
+}
+
+/// Asserts that the subject contains every entry in a collection of key-value
+/// pairs.
+#[derive(Clone, Debug)]
+pub struct ToContainEntries<I> {
+    expected: Annotated<I>,
+}
+
+impl<I> ToContainEntries<I> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<I>) -> Self {
+        Self { expected }
+    }
+}
+
+impl<I, T, K, V, EK, EV> Assertion<T> for ToContainEntries<I>
+where
+    I: IntoIterator<Item = (EK, EV)>,
+    T: IntoIterator<Item = (K, V)>,
+    K: PartialEq<EK>,
+    V: PartialEq<EV> + Debug,
+    EK: Debug,
+    EV: Debug,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.expected);
+
+        let subject: Vec<_> = subject.into_iter().collect();
+        let mut failures = String::new();
+        let mut missing = 0;
+        let mut mismatched = 0;
+        for (expected_key, expected_value) in self.expected.into_inner() {
+            match subject.iter().find(|(key, _)| key == &expected_key) {
+                None => {
+                    missing += 1;
+                    writeln!(failures, "{expected_key:?}: missing").unwrap();
+                }
+                Some((_, value)) if value == &expected_value => {}
+                Some((_, value)) => {
+                    mismatched += 1;
+                    writeln!(
+                        failures,
+                        "{expected_key:?}: expected {expected_value:?}, received {value:?}",
+                    )
+                    .unwrap();
+                }
+            }
+        }
+
+        if failures.is_empty() {
+            return cx.pass();
+        }
+
+        cx.annotate("missing", missing);
+        cx.annotate("mismatched", mismatched);
+        cx.add_page("entries", failures);
+        cx.fail("entries not found")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn every_failure_is_listed() {
+        let map = BTreeMap::from([("a", 1), ("b", 2), ("c", 3)]);
+        expect!(map.clone(), to_contain_entries([("c", 3), ("a", 1)]));
+
+        let message = try_expect!(map, to_contain_entries([("a", 2), ("b", 2), ("d", 4)]))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("\"a\": expected 2, received 1"));
+        expect!(&message, to_contain_substr("\"d\": missing"));
+        expect!(&message, not, to_contain_substr("\"b\": expected"));
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::Debug;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject contains an entry with a key, and that the entry's
+/// value satisfies a predicate.
+#[derive(Clone, Debug)]
+pub struct ToHaveKeyWith<K, F> {
+    key: Annotated<K>,
+    predicate: Annotated<F>,
+}
+
+impl<K, F> ToHaveKeyWith<K, F> {
+    #[inline]
+    pub(crate) fn new(key: Annotated<K>, predicate: Annotated<F>) -> Self {
+        Self { key, predicate }
+    }
+}
+
+impl<T, K, V, EK, F> Assertion<T> for ToHaveKeyWith<EK, F>
+where
+    T: IntoIterator<Item = (K, V)>,
+    K: PartialEq<EK>,
+    V: Debug,
+    F: FnOnce(V) -> bool,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("key", &self.key);
+        cx.annotate("predicate", &self.predicate);
+
+        let Some((_, value)) = subject.into_iter().find(|(key, _)| key == self.key.inner()) else {
+            return cx.fail("key not found");
+        };
+
+        let repr = format!("{value:?}");
+        if (self.predicate.into_inner())(value) {
+            return cx.pass();
+        }
+
+        cx.annotate("value", repr);
+        cx.fail("value did not satisfy predicate")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn missing_key_and_failed_predicate_differ() {
+        let map = HashMap::from([("a", 1), ("b", -2)]);
+        expect!(&map, to_have_key_with(&"a", |n: &i32| *n > 0));
+
+        let message = try_expect!(&map, to_have_key_with(&"c", |n: &i32| *n > 0))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("key not found"));
+
+        let message = try_expect!(&map, to_have_key_with(&"b", |n: &i32| *n > 0))
+            .unwrap_err()
+            .to_string();
+        expect!(
+            &message,
+            to_contain_substr("value did not satisfy predicate")
+        );
+        expect!(&message, to_contain_substr("value: -2"));
+    }
+}
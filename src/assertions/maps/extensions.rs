@@ -0,0 +1,110 @@
+use std::fmt::Debug;
+
+use crate::{assertions::AssertionBuilder, metadata::Annotated};
+
+use super::{ToContainEntries, ToContainEntry, ToHaveKeyWith};
+
+/// Assertions for collections of key-value pairs.
+pub trait MapAssertions<T, M> {
+    /// Asserts that the subject contains an entry with the given key, and that
+    /// the entry's value is equal to the given value.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::collections::HashMap;
+    /// let map = HashMap::from([("a", 1), ("b", 2)]);
+    /// expect!(map, to_contain_entry("b", 2));
+    /// ```
+    ///
+    /// The assertion fails if the key is missing or its value is different:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::collections::HashMap;
+    /// let map = HashMap::from([("a", 1), ("b", 2)]);
+    /// expect!(map, to_contain_entry("b", 3));
+    /// ```
+    #[inline]
+    fn to_contain_entry<K, V, EK, EV>(
+        &self,
+        key: Annotated<EK>,
+        value: Annotated<EV>,
+    ) -> ToContainEntry<EK, EV>
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: PartialEq<EK>,
+        V: PartialEq<EV>,
+    {
+        ToContainEntry::new(key, value)
+    }
+
+    /// Asserts that the subject contains every entry in a collection of
+    /// key-value pairs. The subject may contain additional entries.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::collections::HashMap;
+    /// let map = HashMap::from([("a", 1), ("b", 2), ("c", 3)]);
+    /// expect!(map, to_contain_entries([("a", 1), ("c", 3)]));
+    /// ```
+    ///
+    /// The assertion fails if any of the keys are missing or have different
+    /// values. Every missing or mismatched entry is included in the failure
+    /// message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::collections::HashMap;
+    /// let map = HashMap::from([("a", 1), ("b", 2)]);
+    /// expect!(map, to_contain_entries([("a", 2), ("c", 3)]));
+    /// ```
+    #[inline]
+    fn to_contain_entries<I, K, V, EK, EV>(&self, expected: Annotated<I>) -> ToContainEntries<I>
+    where
+        I: IntoIterator<Item = (EK, EV)>,
+        T: IntoIterator<Item = (K, V)>,
+        K: PartialEq<EK>,
+        V: PartialEq<EV> + Debug,
+        EK: Debug,
+        EV: Debug,
+    {
+        ToContainEntries::new(expected)
+    }
+
+    /// Asserts that the subject contains an entry with the given key, and that
+    /// the entry's value satisfies a predicate.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::collections::HashMap;
+    /// let map = HashMap::from([("a", 1), ("b", 2)]);
+    /// expect!(map, to_have_key_with("b", |n| n % 2 == 0));
+    /// ```
+    ///
+    /// The assertion fails if the key is missing or its value does not satisfy
+    /// the predicate. If the predicate fails, the value is included in the
+    /// failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::collections::HashMap;
+    /// let map = HashMap::from([("a", 1), ("b", 2)]);
+    /// expect!(map, to_have_key_with("a", |n| n % 2 == 0));
+    /// ```
+    #[inline]
+    fn to_have_key_with<K, V, EK, F>(
+        &self,
+        key: Annotated<EK>,
+        predicate: Annotated<F>,
+    ) -> ToHaveKeyWith<EK, F>
+    where
+        T: IntoIterator<Item = (K, V)>,
+        K: PartialEq<EK>,
+        V: Debug,
+        F: FnOnce(V) -> bool,
+    {
+        ToHaveKeyWith::new(key, predicate)
+    }
+}
+
+impl<T, M> MapAssertions<T, M> for AssertionBuilder<T, M> {}
@@ -0,0 +1,59 @@
+use crate::{
+    assertions::{options::Optionish, Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject is [`None`], or that its value satisfies a
+/// predicate.
+#[derive(Clone, Debug)]
+pub struct ToSatisfyIfSome<F> {
+    predicate: Annotated<F>,
+}
+
+impl<F> ToSatisfyIfSome<F> {
+    #[inline]
+    pub(crate) fn new(predicate: Annotated<F>) -> Self {
+        Self { predicate }
+    }
+}
+
+impl<O, F> Assertion<O> for ToSatisfyIfSome<F>
+where
+    O: Optionish,
+    F: FnOnce(O::OutT) -> bool,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: O) -> Self::Output {
+        cx.annotate("predicate", &self.predicate);
+
+        let Some(value) = subject.some() else {
+            cx.annotate("present", false);
+            return cx.pass();
+        };
+
+        cx.annotate("present", true);
+        cx.pass_if(
+            (self.predicate.into_inner())(value),
+            "value did not satisfy predicate",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn none_always_passes() {
+        expect!(None::<i32>, to_satisfy_if_some(|n| n > 0));
+        expect!(&Some(1), to_satisfy_if_some(|n: &i32| *n > 0));
+        expect!(
+            try_expect!(Some(-1), to_satisfy_if_some(|n| n > 0)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("present: true"),
+        );
+    }
+}
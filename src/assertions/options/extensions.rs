@@ -1,6 +1,9 @@
-use crate::assertions::AssertionBuilder;
+use crate::{assertions::AssertionBuilder, metadata::Annotated};
 
-use super::{OptionVariant, Optionish, SomeAndModifier, ToBeOptionVariant};
+use super::{
+    FlattenOptionModifier, OptionVariant, Optionish, SomeAndModifier, ToBeOptionVariant,
+    ToSatisfyIfSome, UnwrapOrDefaultModifier,
+};
 
 /// Assertions and modifiers for [`Option`]s.
 pub trait OptionAssertions<T, M>
@@ -23,6 +26,39 @@ where
     /// ```
     fn to_be_some_and(self) -> AssertionBuilder<T::OutT, SomeAndModifier<M>>;
 
+    /// Asserts that both layers of a nested option hold a value, then continues
+    /// the assertion with the innermost value.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(Some(Some(1)), flatten, to_equal(1));
+    /// ```
+    ///
+    /// The assertion fails if either layer is [`None`]:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(Some(None::<i32>), flatten, to_equal(1));
+    /// ```
+    fn flatten<U>(self) -> AssertionBuilder<U::OutT, FlattenOptionModifier<M>>
+    where
+        T: Optionish<OutT = U>,
+        U: Optionish;
+
+    /// Continues the assertion with the subject's value, or the default value if
+    /// the subject does not hold a value.
+    ///
+    /// This is useful for treating an absent value the same as an empty one:
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(None::<Vec<i32>>, unwrap_or_default, count, to_equal(0));
+    /// expect!(Some(vec![1, 2]), unwrap_or_default, count, to_equal(2));
+    /// ```
+    fn unwrap_or_default(self) -> AssertionBuilder<T::OutT, UnwrapOrDefaultModifier<M>>
+    where
+        T::OutT: Default;
+
     /// Asserts that the subject holds a value.
     ///
     /// ```
@@ -60,6 +96,31 @@ where
     fn to_be_none(&self) -> ToBeOptionVariant {
         ToBeOptionVariant::new(OptionVariant::None)
     }
+
+    /// Asserts that the subject either does not hold a value, or holds a value
+    /// that satisfies a predicate. This is useful for validating optional
+    /// fields that must satisfy a condition when present.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(Some(3), to_satisfy_if_some(|n| n > 0));
+    /// expect!(None::<i32>, to_satisfy_if_some(|n| n > 0));
+    /// ```
+    ///
+    /// The assertion fails if the subject holds a value that does not satisfy
+    /// the predicate:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(Some(-3), to_satisfy_if_some(|n| n > 0));
+    /// ```
+    #[inline]
+    fn to_satisfy_if_some<F>(&self, predicate: Annotated<F>) -> ToSatisfyIfSome<F>
+    where
+        F: FnOnce(T::OutT) -> bool,
+    {
+        ToSatisfyIfSome::new(predicate)
+    }
 }
 
 impl<T, M> OptionAssertions<T, M> for AssertionBuilder<T, M>
@@ -70,4 +131,21 @@ where
     fn to_be_some_and(self) -> AssertionBuilder<T::OutT, SomeAndModifier<M>> {
         AssertionBuilder::modify(self, SomeAndModifier::new)
     }
+
+    #[inline]
+    fn flatten<U>(self) -> AssertionBuilder<U::OutT, FlattenOptionModifier<M>>
+    where
+        T: Optionish<OutT = U>,
+        U: Optionish,
+    {
+        AssertionBuilder::modify(self, FlattenOptionModifier::new)
+    }
+
+    #[inline]
+    fn unwrap_or_default(self) -> AssertionBuilder<T::OutT, UnwrapOrDefaultModifier<M>>
+    where
+        T::OutT: Default,
+    {
+        AssertionBuilder::modify(self, UnwrapOrDefaultModifier::new)
+    }
 }
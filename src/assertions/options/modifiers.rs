@@ -1,3 +1,7 @@
+mod flatten;
 mod some_and;
+mod unwrap_or_default;
 
+pub use flatten::*;
 pub use some_and::*;
+pub use unwrap_or_default::*;
@@ -0,0 +1,80 @@
+use crate::assertions::{
+    general::IntoInitializableOutput, options::Optionish, Assertion, AssertionContext,
+    AssertionContextBuilder, AssertionModifier,
+};
+
+/// Maps a nested optional subject to its innermost value.
+#[derive(Clone, Debug)]
+pub struct FlattenOptionModifier<M> {
+    prev: M,
+}
+
+impl<M> FlattenOptionModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M) -> Self {
+        Self { prev }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for FlattenOptionModifier<M>
+where
+    M: AssertionModifier<FlattenOptionAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(cx, FlattenOptionAssertion { next })
+    }
+}
+
+/// Executes the inner assertion on the subject's innermost value.
+#[derive(Clone, Debug)]
+pub struct FlattenOptionAssertion<A> {
+    next: A,
+}
+
+impl<A, O, I> Assertion<O> for FlattenOptionAssertion<A>
+where
+    A: Assertion<I::OutT, Output: IntoInitializableOutput>,
+    O: Optionish<OutT = I>,
+    I: Optionish,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: O) -> Self::Output {
+        let Some(subject) = subject.some() else {
+            cx.annotate("layer", "outer");
+            return cx.fail("received None");
+        };
+        let Some(subject) = subject.some() else {
+            cx.annotate("layer", "inner");
+            return cx.fail("received None");
+        };
+        self.next.execute(cx, subject).into_initialized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn missing_layer_is_annotated() {
+        expect!(Some(Some(1)), flatten, to_equal(1));
+        expect!(&Some(Some(1)), flatten, to_equal(&1));
+        expect!(
+            try_expect!(None::<Option<i32>>, flatten, to_equal(1)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("layer: outer"),
+        );
+        expect!(
+            try_expect!(Some(None::<i32>), flatten, to_equal(1)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("layer: inner"),
+        );
+    }
+}
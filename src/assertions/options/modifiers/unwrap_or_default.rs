@@ -0,0 +1,67 @@
+use crate::assertions::{
+    options::Optionish, Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier,
+};
+
+/// Maps the subject to its inner value, or the default value if it has none.
+#[derive(Clone, Debug)]
+pub struct UnwrapOrDefaultModifier<M> {
+    prev: M,
+}
+
+impl<M> UnwrapOrDefaultModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M) -> Self {
+        Self { prev }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for UnwrapOrDefaultModifier<M>
+where
+    M: AssertionModifier<UnwrapOrDefaultAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(cx, UnwrapOrDefaultAssertion { next })
+    }
+}
+
+/// Executes the inner assertion on the subject's inner value, or the default
+/// value if the subject has no inner value.
+#[derive(Clone, Debug)]
+pub struct UnwrapOrDefaultAssertion<A> {
+    next: A,
+}
+
+impl<A, O> Assertion<O> for UnwrapOrDefaultAssertion<A>
+where
+    A: Assertion<O::OutT>,
+    O: Optionish<OutT: Default>,
+{
+    type Output = A::Output;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: O) -> Self::Output {
+        let subject = subject.some();
+        cx.annotate("used default", subject.is_none());
+        self.next.execute(cx, subject.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn absent_and_empty_are_equivalent() {
+        expect!(None::<Vec<i32>>, unwrap_or_default, count, to_equal(0));
+        expect!(
+            Some(Vec::<i32>::new()),
+            unwrap_or_default,
+            count,
+            to_equal(0)
+        );
+        expect!(Some(vec![1, 2]), unwrap_or_default, count, to_equal(2));
+    }
+}
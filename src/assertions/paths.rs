@@ -0,0 +1,20 @@
+//! Assertions for filesystem paths, like [`Path`](std::path::Path) and
+//! [`PathBuf`](std::path::PathBuf).
+//!
+//! These assertions only inspect the path itself, and never access the
+//! filesystem. Any type implementing [`AsRef<Path>`](std::path::Path) can be
+//! used as a subject.
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! use std::path::Path;
+//!
+//! expect!(Path::new("src/lib.rs"), to_have_extension("rs"));
+//! expect!(Path::new("src/lib.rs"), to_be_relative);
+//! ```
+
+mod assertions;
+mod extensions;
+
+pub use assertions::*;
+pub use extensions::*;
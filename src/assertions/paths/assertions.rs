@@ -0,0 +1,7 @@
+mod to_end_with_components;
+mod to_have_kind;
+mod to_have_part;
+
+pub use to_end_with_components::*;
+pub use to_have_kind::*;
+pub use to_have_part::*;
@@ -0,0 +1,67 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    assertions::{paths::annotate_components, Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject ends with the given components.
+#[derive(Clone, Debug)]
+pub struct ToEndWithComponents<I> {
+    components: Annotated<I>,
+}
+
+impl<I> ToEndWithComponents<I> {
+    #[inline]
+    pub(crate) fn new(components: Annotated<I>) -> Self {
+        Self { components }
+    }
+}
+
+impl<T, I> Assertion<T> for ToEndWithComponents<I>
+where
+    T: AsRef<Path>,
+    I: IntoIterator<Item: AsRef<Path>>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.components);
+
+        let subject = subject.as_ref();
+        annotate_components(&mut cx, subject);
+
+        let suffix: PathBuf = self.components.into_inner().into_iter().collect();
+        cx.pass_if(
+            subject.ends_with(suffix),
+            "path does not end with components",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn whole_components_are_matched() {
+        expect!(
+            "src/assertions/mod.rs",
+            to_end_with_components(["assertions", "mod.rs"])
+        );
+        expect!(
+            "src/assertions/mod.rs",
+            not,
+            to_end_with_components(["ons", "mod.rs"])
+        );
+
+        let message = try_expect!("src/lib.rs", to_end_with_components(["main.rs"]))
+            .unwrap_err()
+            .to_string();
+        expect!(
+            &message,
+            to_contain_substr(r#"components: ["src", "lib.rs"]"#)
+        );
+    }
+}
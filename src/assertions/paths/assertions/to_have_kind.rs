@@ -0,0 +1,64 @@
+use std::path::{Component, Path};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// Asserts that the subject is an absolute or relative path.
+#[derive(Clone, Debug)]
+pub struct ToHavePathKind {
+    kind: PathKind,
+}
+
+impl ToHavePathKind {
+    #[inline]
+    pub(crate) fn new(kind: PathKind) -> Self {
+        Self { kind }
+    }
+}
+
+impl<T> Assertion<T> for ToHavePathKind
+where
+    T: AsRef<Path>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let subject = subject.as_ref();
+        annotate_components(&mut cx, subject);
+
+        match self.kind {
+            PathKind::Absolute => cx.pass_if(subject.is_absolute(), "path is not absolute"),
+            PathKind::Relative => cx.pass_if(subject.is_relative(), "path is not relative"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum PathKind {
+    Absolute,
+    Relative,
+}
+
+/// Annotates the components of a path.
+pub(crate) fn annotate_components(cx: &mut AssertionContext, path: &Path) {
+    let components: Vec<_> = path.components().map(Component::as_os_str).collect();
+    cx.annotate("components", format_args!("{components:?}"));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::current_dir;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn absolute_and_relative() {
+        let absolute = current_dir().unwrap();
+        expect!(&absolute, to_be_absolute);
+        expect!(&absolute, not, to_be_relative);
+        expect!("src/lib.rs", to_be_relative);
+        expect!("src/lib.rs", not, to_be_absolute);
+    }
+}
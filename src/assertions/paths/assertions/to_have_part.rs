@@ -0,0 +1,75 @@
+use std::{ffi::OsStr, path::Path};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that a part of the subject, like its extension, is equal to an
+/// expected value.
+#[derive(Clone, Debug)]
+pub struct ToHavePathPart<U> {
+    expected: Annotated<U>,
+    part: PathPart,
+}
+
+impl<U> ToHavePathPart<U> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<U>, part: PathPart) -> Self {
+        Self { expected, part }
+    }
+}
+
+impl<T, U> Assertion<T> for ToHavePathPart<U>
+where
+    T: AsRef<Path>,
+    U: AsRef<OsStr>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.expected);
+
+        let subject = subject.as_ref();
+        let (actual, name) = match self.part {
+            PathPart::Extension => (subject.extension(), "extension"),
+            PathPart::FileName => (subject.file_name(), "file name"),
+        };
+        let Some(actual) = actual else {
+            return cx.fail(format_args!("path has no {name}"));
+        };
+
+        cx.annotate("actual", format_args!("{actual:?}"));
+        cx.pass_if(
+            actual == self.expected.inner().as_ref(),
+            format_args!("{name}s not equal"),
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum PathPart {
+    Extension,
+    FileName,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn parts_are_compared() {
+        let path = PathBuf::from("src/assertions/mod.rs");
+        expect!(&path, to_have_extension("rs"));
+        expect!(&path, to_have_file_name("mod.rs"));
+        expect!(&path, not, to_have_extension("txt"));
+
+        let message = try_expect!("src/main", to_have_extension("rs"))
+            .unwrap_err()
+            .to_string();
+        expect!(&message, to_contain_substr("path has no extension"));
+    }
+}
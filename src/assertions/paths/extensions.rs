@@ -0,0 +1,142 @@
+use std::{ffi::OsStr, path::Path};
+
+use crate::{assertions::AssertionBuilder, metadata::Annotated};
+
+use super::{PathKind, PathPart, ToEndWithComponents, ToHavePathKind, ToHavePathPart};
+
+/// Assertions for filesystem paths.
+pub trait PathAssertions<T, M>
+where
+    T: AsRef<Path>,
+{
+    /// Asserts that the subject has the given extension.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// expect!(Path::new("src/lib.rs"), to_have_extension("rs"));
+    /// ```
+    ///
+    /// The assertion fails if the subject has a different extension, or no
+    /// extension at all:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// expect!(Path::new("src/lib"), to_have_extension("rs"));
+    /// ```
+    #[inline]
+    fn to_have_extension<U>(&self, expected: Annotated<U>) -> ToHavePathPart<U>
+    where
+        U: AsRef<OsStr>,
+    {
+        ToHavePathPart::new(expected, PathPart::Extension)
+    }
+
+    /// Asserts that the final component of the subject is the given file name.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// expect!(Path::new("src/lib.rs"), to_have_file_name("lib.rs"));
+    /// ```
+    ///
+    /// The assertion fails if the subject has a different file name, or no
+    /// file name at all:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// expect!(Path::new("src/.."), to_have_file_name("src"));
+    /// ```
+    #[inline]
+    fn to_have_file_name<U>(&self, expected: Annotated<U>) -> ToHavePathPart<U>
+    where
+        U: AsRef<OsStr>,
+    {
+        ToHavePathPart::new(expected, PathPart::FileName)
+    }
+
+    /// Asserts that the subject ends with the given components. Only whole
+    /// components are matched.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// expect!(
+    ///     Path::new("src/assertions/mod.rs"),
+    ///     to_end_with_components(["assertions", "mod.rs"]),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the subject ends with different components. The
+    /// components of the subject are included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// expect!(
+    ///     Path::new("src/assertions/mod.rs"),
+    ///     to_end_with_components(["ions", "mod.rs"]),
+    /// );
+    /// ```
+    #[inline]
+    fn to_end_with_components<I>(&self, components: Annotated<I>) -> ToEndWithComponents<I>
+    where
+        I: IntoIterator<Item: AsRef<Path>>,
+    {
+        ToEndWithComponents::new(components)
+    }
+
+    /// Asserts that the subject is an absolute path.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let path = std::env::current_dir().unwrap();
+    /// expect!(path, to_be_absolute);
+    /// ```
+    ///
+    /// The assertion fails if the subject is a relative path:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// expect!(Path::new("src/lib.rs"), to_be_absolute);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_absolute(&self) -> ToHavePathKind {
+        ToHavePathKind::new(PathKind::Absolute)
+    }
+
+    /// Asserts that the subject is a relative path.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// expect!(Path::new("src/lib.rs"), to_be_relative);
+    /// ```
+    ///
+    /// The assertion fails if the subject is an absolute path:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let path = std::env::current_dir().unwrap();
+    /// expect!(path, to_be_relative);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_relative(&self) -> ToHavePathKind {
+        ToHavePathKind::new(PathKind::Relative)
+    }
+}
+
+impl<T, M> PathAssertions<T, M> for AssertionBuilder<T, M> where T: AsRef<Path> {}
@@ -0,0 +1,18 @@
+//! Assertions for ranges, like [`Range`](std::ops::Range) and
+//! [`RangeInclusive`](std::ops::RangeInclusive).
+//!
+//! Any type implementing [`RangeSubject`] can be used as a subject.
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! expect!(2..5, to_have_start(2));
+//! expect!(2..=5, to_contain_value(5));
+//! ```
+
+mod assertions;
+mod extensions;
+mod range_subject;
+
+pub use assertions::*;
+pub use extensions::*;
+pub use range_subject::*;
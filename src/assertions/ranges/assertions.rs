@@ -0,0 +1,7 @@
+mod to_contain_value;
+mod to_have_bound;
+mod to_have_length;
+
+pub use to_contain_value::*;
+pub use to_have_bound::*;
+pub use to_have_length::*;
@@ -0,0 +1,58 @@
+use std::fmt::Debug;
+
+use crate::{
+    assertions::{ranges::RangeSubject, Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+use super::annotate_bounds;
+
+/// Asserts that the subject contains a value.
+#[derive(Clone, Debug)]
+pub struct ToContainValue<U> {
+    value: Annotated<U>,
+}
+
+impl<U> ToContainValue<U> {
+    #[inline]
+    pub(crate) fn new(value: Annotated<U>) -> Self {
+        Self { value }
+    }
+}
+
+impl<T> Assertion<T> for ToContainValue<T::Idx>
+where
+    T: RangeSubject<Idx: PartialOrd + Debug>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("value", &self.value);
+        annotate_bounds(&mut cx, &subject);
+        cx.pass_if(
+            subject.contains_value(self.value.inner()),
+            "value not in range",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case(1..3, 3; "exclusive end")]
+    #[test_case(1..3, 0; "below start")]
+    #[should_panic = "value not in range"]
+    fn failure_cases(subject: std::ops::Range<i32>, value: i32) {
+        expect!(subject, to_contain_value(value));
+    }
+
+    #[test]
+    fn inclusive_end_is_contained() {
+        expect!(1..=3, to_contain_value(3));
+        expect!(0.5..1.0, to_contain_value(0.75));
+    }
+}
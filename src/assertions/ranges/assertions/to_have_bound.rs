@@ -0,0 +1,76 @@
+use std::fmt::Debug;
+
+use crate::{
+    assertions::{ranges::RangeSubject, Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject has the given start or end bound.
+#[derive(Clone, Debug)]
+pub struct ToHaveBound<U> {
+    expected: Annotated<U>,
+    bound: RangeBound,
+}
+
+impl<U> ToHaveBound<U> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<U>, bound: RangeBound) -> Self {
+        Self { expected, bound }
+    }
+}
+
+impl<T, U> Assertion<T> for ToHaveBound<U>
+where
+    T: RangeSubject<Idx: PartialEq<U> + Debug>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.expected);
+        annotate_bounds(&mut cx, &subject);
+
+        let (actual, message) = match self.bound {
+            RangeBound::Start => (subject.start(), "start bounds not equal"),
+            RangeBound::End => (subject.end(), "end bounds not equal"),
+        };
+        cx.pass_if(actual == self.expected.inner(), message)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum RangeBound {
+    Start,
+    End,
+}
+
+/// Annotates the bounds of a range.
+pub(crate) fn annotate_bounds<T>(cx: &mut AssertionContext, subject: &T)
+where
+    T: RangeSubject<Idx: Debug>,
+{
+    cx.annotate("start", format_args!("{:?}", subject.start()));
+    let end_kind = if subject.is_end_inclusive() {
+        "inclusive"
+    } else {
+        "exclusive"
+    };
+    cx.annotate("end", format_args!("{:?} ({end_kind})", subject.end()));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn bounds_are_annotated() {
+        expect!(1..3, to_have_start(1));
+        expect!(1..=3, to_have_end(3));
+        expect!(
+            try_expect!(1..3, to_have_end(4)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("end: 3 (exclusive)"),
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::Debug;
+
+use crate::{
+    assertions::{ranges::RangeSubject, Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+use super::annotate_bounds;
+
+/// Asserts that the subject contains an exact number of values.
+#[derive(Clone, Debug)]
+pub struct ToHaveRangeLength {
+    expected: Annotated<usize>,
+}
+
+impl ToHaveRangeLength {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<usize>) -> Self {
+        Self { expected }
+    }
+}
+
+impl<T> Assertion<T> for ToHaveRangeLength
+where
+    T: RangeSubject<Idx: Debug> + Iterator,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let expected = *self.expected.inner();
+        cx.annotate("expected", self.expected);
+        annotate_bounds(&mut cx, &subject);
+
+        let actual = subject.count();
+        cx.annotate("actual", actual);
+        cx.pass_if(actual == expected, "lengths not equal")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn length_depends_on_end_inclusivity() {
+        expect!(1..4, to_have_length(3));
+        expect!(1..=4, to_have_length(4));
+        expect!(3..3, to_have_length(0));
+        expect!(
+            try_expect!('a'..='c', to_have_length(2)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("actual: 3"),
+        );
+    }
+}
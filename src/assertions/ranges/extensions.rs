@@ -0,0 +1,109 @@
+use std::fmt::Debug;
+
+use crate::{assertions::AssertionBuilder, metadata::Annotated};
+
+use super::{RangeBound, RangeSubject, ToContainValue, ToHaveBound, ToHaveRangeLength};
+
+/// Assertions for ranges.
+pub trait RangeAssertions<T, M>
+where
+    T: RangeSubject,
+{
+    /// Asserts that the subject starts at the given value.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(2..5, to_have_start(2));
+    /// ```
+    ///
+    /// The assertion fails if the start bound is different. The bounds of the
+    /// range are included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(2..5, to_have_start(1));
+    /// ```
+    #[inline]
+    fn to_have_start<U>(&self, expected: Annotated<U>) -> ToHaveBound<U>
+    where
+        T::Idx: PartialEq<U> + Debug,
+    {
+        ToHaveBound::new(expected, RangeBound::Start)
+    }
+
+    /// Asserts that the subject ends at the given value. This checks the end
+    /// bound as written, regardless of whether it is inclusive or exclusive.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(2..5, to_have_end(5));
+    /// expect!(2..=5, to_have_end(5));
+    /// ```
+    ///
+    /// The assertion fails if the end bound is different:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(2..5, to_have_end(4));
+    /// ```
+    #[inline]
+    fn to_have_end<U>(&self, expected: Annotated<U>) -> ToHaveBound<U>
+    where
+        T::Idx: PartialEq<U> + Debug,
+    {
+        ToHaveBound::new(expected, RangeBound::End)
+    }
+
+    /// Asserts that the given value is within the subject.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(2..5, to_contain_value(4));
+    /// expect!(0.0..=1.0, to_contain_value(1.0));
+    /// ```
+    ///
+    /// The assertion fails if the value is outside the range:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(2..5, to_contain_value(5));
+    /// ```
+    ///
+    /// This is named differently from
+    /// [`to_contain`](crate::prelude::IteratorAssertions::to_contain) since
+    /// many ranges are also iterators. Unlike `to_contain`, this doesn't
+    /// iterate over the range, so it works with ranges of any ordered type.
+    #[inline]
+    fn to_contain_value(&self, value: Annotated<T::Idx>) -> ToContainValue<T::Idx>
+    where
+        T::Idx: PartialOrd + Debug,
+    {
+        ToContainValue::new(value)
+    }
+
+    /// Asserts that the subject contains exactly `expected` values. The values
+    /// are counted by iterating over the range.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(2..5, to_have_length(3));
+    /// expect!(2..=5, to_have_length(4));
+    /// ```
+    ///
+    /// The assertion fails if the range has a different number of values:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(2..5, to_have_length(4));
+    /// ```
+    #[inline]
+    fn to_have_length(&self, expected: Annotated<usize>) -> ToHaveRangeLength
+    where
+        T: Iterator,
+        T::Idx: Debug,
+    {
+        ToHaveRangeLength::new(expected)
+    }
+}
+
+impl<T, M> RangeAssertions<T, M> for AssertionBuilder<T, M> where T: RangeSubject {}
@@ -0,0 +1,88 @@
+use std::ops::{Range, RangeInclusive};
+
+/// A range with both a start and an end bound.
+pub trait RangeSubject {
+    /// The type of the bounds of the range.
+    type Idx;
+
+    /// Gets the lower bound of the range.
+    fn start(&self) -> &Self::Idx;
+
+    /// Gets the upper bound of the range.
+    fn end(&self) -> &Self::Idx;
+
+    /// Gets whether the upper bound of the range is included in the range.
+    fn is_end_inclusive(&self) -> bool;
+
+    /// Gets whether the value is within the range.
+    fn contains_value(&self, value: &Self::Idx) -> bool
+    where
+        Self::Idx: PartialOrd,
+    {
+        if self.is_end_inclusive() {
+            self.start() <= value && value <= self.end()
+        } else {
+            self.start() <= value && value < self.end()
+        }
+    }
+}
+
+impl<R> RangeSubject for &R
+where
+    R: RangeSubject + ?Sized,
+{
+    type Idx = R::Idx;
+
+    #[inline]
+    fn start(&self) -> &Self::Idx {
+        R::start(self)
+    }
+
+    #[inline]
+    fn end(&self) -> &Self::Idx {
+        R::end(self)
+    }
+
+    #[inline]
+    fn is_end_inclusive(&self) -> bool {
+        R::is_end_inclusive(self)
+    }
+}
+
+impl<T> RangeSubject for Range<T> {
+    type Idx = T;
+
+    #[inline]
+    fn start(&self) -> &Self::Idx {
+        &self.start
+    }
+
+    #[inline]
+    fn end(&self) -> &Self::Idx {
+        &self.end
+    }
+
+    #[inline]
+    fn is_end_inclusive(&self) -> bool {
+        false
+    }
+}
+
+impl<T> RangeSubject for RangeInclusive<T> {
+    type Idx = T;
+
+    #[inline]
+    fn start(&self) -> &Self::Idx {
+        RangeInclusive::start(self)
+    }
+
+    #[inline]
+    fn end(&self) -> &Self::Idx {
+        RangeInclusive::end(self)
+    }
+
+    #[inline]
+    fn is_end_inclusive(&self) -> bool {
+        true
+    }
+}
@@ -42,14 +42,12 @@ where
 {
     type Output = <A::Output as IntoInitializableOutput>::Initialized;
 
-    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
-        let bytes = match subject.bytes().collect::<Result<Vec<_>, _>>() {
-            Ok(bytes) => bytes,
-            Err(error) => {
-                cx.annotate("error", &error);
-                return cx.fail("failed to read");
-            }
-        };
+    fn execute(self, mut cx: AssertionContext, mut subject: T) -> Self::Output {
+        let mut bytes = Vec::new();
+        if let Err(error) = subject.read_to_end(&mut bytes) {
+            cx.annotate("error", &error);
+            return cx.fail("failed to read");
+        }
 
         cx.annotate("read bytes", bytes.len());
         self.next.execute(cx, bytes).into_initialized()
@@ -0,0 +1,111 @@
+use crate::{metadata::SourceLoc, AssertionOutput};
+
+use super::AssertionError;
+
+/// A report of an assertion's execution, created by [`try_expect_report!`].
+///
+/// Unlike the [`Result`] returned by [`try_expect!`], this keeps the context
+/// of the assertion even when it succeeds. This is useful when building custom
+/// test harnesses that aggregate the results of many assertions.
+///
+/// ```
+/// # use expecters::prelude::*;
+/// let report = try_expect_report!(1 + 1, to_equal(2));
+/// expect!(report.is_pass(), to_equal(true));
+/// expect!(report.subject(), to_equal("2"));
+/// ```
+///
+/// [`try_expect!`]: crate::try_expect!
+/// [`try_expect_report!`]: crate::try_expect_report!
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct AssertionReport {
+    output: AssertionOutput,
+}
+
+impl AssertionReport {
+    /// Creates a new report from the output of an assertion.
+    #[inline]
+    pub fn new(output: AssertionOutput) -> Self {
+        Self { output }
+    }
+
+    /// Gets whether the assertion succeeded.
+    #[inline]
+    #[must_use]
+    pub fn is_pass(&self) -> bool {
+        self.output.is_pass()
+    }
+
+    /// Gets the string representation of the subject of the assertion.
+    #[inline]
+    #[must_use]
+    pub fn subject(&self) -> &str {
+        self.output.subject()
+    }
+
+    /// Gets the location in the source code where the assertion was created.
+    #[inline]
+    #[must_use]
+    pub fn source_loc(&self) -> SourceLoc {
+        self.output.source_loc()
+    }
+
+    /// Gets the custom message attached to the assertion, if any. The message
+    /// is only generated if the assertion failed.
+    #[inline]
+    #[must_use]
+    pub fn message(&self) -> Option<&str> {
+        self.output.cx().message.as_deref()
+    }
+
+    /// Gets the failure message of the assertion, if it failed.
+    #[inline]
+    #[must_use]
+    pub fn failure(&self) -> Option<&str> {
+        self.output.error()
+    }
+
+    /// Gets the output of the assertion.
+    #[inline]
+    pub fn output(&self) -> &AssertionOutput {
+        &self.output
+    }
+
+    /// Converts this report into the output of the assertion.
+    #[inline]
+    pub fn into_output(self) -> AssertionOutput {
+        self.output
+    }
+
+    /// Converts this report into a [`Result`], like the one returned by
+    /// [`try_expect!`](crate::try_expect!).
+    #[inline]
+    pub fn into_result(self) -> Result<(), AssertionError> {
+        self.output.into_result()
+    }
+}
+
+impl From<AssertionOutput> for AssertionReport {
+    #[inline]
+    fn from(output: AssertionOutput) -> Self {
+        Self::new(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn failing_report_keeps_context() {
+        let report = try_expect_report!([1, 2], count, to_equal(3); "custom");
+
+        expect!(report.is_pass(), to_equal(false));
+        expect!(report.subject(), to_equal("[1, 2]"));
+        expect!(report.source_loc().file(), to_equal(file!()));
+        expect!(report.message(), to_equal(Some("custom")));
+        expect!(report.failure(), to_equal(Some("values not equal")));
+        expect!(report.into_result(), to_be_err);
+    }
+}
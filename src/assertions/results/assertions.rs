@@ -1,3 +1,5 @@
 mod to_be_variant;
+mod to_satisfy_result;
 
 pub use to_be_variant::*;
+pub use to_satisfy_result::*;
@@ -1,5 +1,9 @@
 use crate::{
-    assertions::{results::Resultish, Assertion, AssertionContext},
+    assertions::{
+        results::{annotated_err_repr, Resultish},
+        Assertion, AssertionContext,
+    },
+    metadata::Annotated,
     AssertionOutput,
 };
 
@@ -29,6 +33,19 @@ where
             ResultVariant::Err => cx.pass_if(subject.err().is_some(), "received Ok"),
         }
     }
+
+    fn execute_annotated(self, mut cx: AssertionContext, subject: Annotated<R>) -> Self::Output
+    where
+        Self: Sized,
+    {
+        if self.expected == ResultVariant::Ok {
+            if let Some(error) = annotated_err_repr(&subject) {
+                cx.annotate("error", error);
+            }
+        }
+
+        self.execute(cx, subject.into_inner())
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -66,4 +83,24 @@ mod tests {
         expect!(&mut result, not, to_be_err);
         expect!(result, not, to_be_err);
     }
+
+    #[test]
+    fn unexpected_err_is_annotated() {
+        let result: Result<(), &str> = Err("file not found");
+        expect!(
+            try_expect!(result, to_be_ok),
+            to_be_err_and,
+            as_display,
+            to_contain_substr(r#"error: Err("file not found")"#),
+        );
+    }
+
+    #[test]
+    fn errors_without_debug_are_supported() {
+        struct NotDebug;
+
+        expect!(Err::<i32, _>(NotDebug), to_be_err);
+        expect!(Err::<i32, _>(NotDebug), not, to_be_ok);
+        expect!(Err::<i32, _>(NotDebug), not, to_be_ok_and, to_equal(1));
+    }
 }
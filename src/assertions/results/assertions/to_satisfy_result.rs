@@ -0,0 +1,64 @@
+use crate::{
+    assertions::{results::Resultish, Assertion, AssertionContext, AssertionError},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject satisfies a series of assertions, depending on
+/// which [`Result`] variant it is.
+#[derive(Clone, Debug)]
+pub struct ToSatisfyResult<F, G> {
+    ok_fn: Annotated<F>,
+    err_fn: Annotated<G>,
+}
+
+impl<F, G> ToSatisfyResult<F, G> {
+    #[inline]
+    pub(crate) fn new(ok_fn: Annotated<F>, err_fn: Annotated<G>) -> Self {
+        Self { ok_fn, err_fn }
+    }
+}
+
+impl<F, G, R> Assertion<R> for ToSatisfyResult<F, G>
+where
+    F: FnOnce(R::OutT) -> Result<(), AssertionError>,
+    G: FnOnce(R::OutE) -> Result<(), AssertionError>,
+    R: Resultish,
+{
+    type Output = AssertionOutput;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: R) -> Self::Output {
+        let result = match subject.into_result() {
+            Ok(value) => {
+                cx.annotate("variant", "Ok");
+                cx.annotate("function", &self.ok_fn);
+                (self.ok_fn.into_inner())(value)
+            }
+            Err(error) => {
+                cx.annotate("variant", "Err");
+                cx.annotate("function", &self.err_fn);
+                (self.err_fn.into_inner())(error)
+            }
+        };
+        cx.pass_if(result.is_ok(), "inner assertions failed")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn variant_is_dispatched() {
+        let ok_fn = |n: &i32| try_expect!(*n, to_equal(1));
+        let err_fn = |e: &&str| try_expect!(*e, to_equal("error"));
+
+        let result: Result<i32, &str> = Ok(1);
+        expect!(&result, to_satisfy_result(ok_fn, err_fn));
+        let result: Result<i32, &str> = Err("error");
+        expect!(&result, to_satisfy_result(ok_fn, err_fn));
+        let result: Result<i32, &str> = Err("other");
+        expect!(&result, not, to_satisfy_result(ok_fn, err_fn));
+    }
+}
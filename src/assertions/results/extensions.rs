@@ -1,6 +1,12 @@
-use crate::assertions::AssertionBuilder;
+use crate::{
+    assertions::{AssertionBuilder, AssertionError},
+    metadata::Annotated,
+};
 
-use super::{ErrAndModifier, OkAndModifier, ResultVariant, Resultish, ToBeResultVariant};
+use super::{
+    ErrAndModifier, FlattenResultModifier, MapErrModifier, OkAndModifier, ResultVariant, Resultish,
+    ToBeResultVariant, ToSatisfyResult,
+};
 
 /// Assertions and modifiers for [`Result`]s.
 pub trait ResultAssertions<T, M>
@@ -16,7 +22,9 @@ where
     /// expect!(subject, to_be_ok_and, to_equal(1));
     /// ```
     ///
-    /// The assertion fails if the result is [`Err`]:
+    /// The assertion fails if the result is [`Err`]. If the result has a
+    /// [`Debug`](std::fmt::Debug) representation, then the error is included in
+    /// the failure message:
     ///
     /// ```should_panic
     /// # use expecters::prelude::*;
@@ -43,6 +51,47 @@ where
     /// ```
     fn to_be_err_and(self) -> AssertionBuilder<T::OutE, ErrAndModifier<M>>;
 
+    /// Asserts that both layers of a nested result hold a success, then
+    /// continues the assertion with the innermost value.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let result: Result<Result<i32, &str>, &str> = Ok(Ok(1));
+    /// expect!(result, flatten, to_equal(1));
+    /// ```
+    ///
+    /// The assertion fails if either layer is [`Err`]:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let result: Result<Result<i32, &str>, &str> = Ok(Err("error"));
+    /// expect!(result, flatten, to_equal(1));
+    /// ```
+    fn flatten<U>(self) -> AssertionBuilder<U::OutT, FlattenResultModifier<M>>
+    where
+        T: Resultish<OutT = U>,
+        U: Resultish;
+
+    /// Asserts that the target holds an error, then continues the assertion with
+    /// the result of mapping the contained value with a function.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let result: Result<i32, &str> = Err("error");
+    /// expect!(result, map_err(|e: &str| e.len()), to_equal(5));
+    /// ```
+    ///
+    /// The assertion fails if the result is [`Ok`]:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let result: Result<i32, &str> = Ok(1);
+    /// expect!(result, map_err(|e: &str| e.len()), to_equal(5));
+    /// ```
+    fn map_err<F, U>(self, map: Annotated<F>) -> AssertionBuilder<U, MapErrModifier<M, F>>
+    where
+        F: FnOnce(T::OutE) -> U;
+
     /// Asserts that the target holds a success.
     ///
     /// ```
@@ -51,7 +100,9 @@ where
     /// expect!(result, to_be_ok);
     /// ```
     ///
-    /// The assertion fails if the subject does not hold a success:
+    /// The assertion fails if the subject does not hold a success. If the result
+    /// has a [`Debug`](std::fmt::Debug) representation, then the error is
+    /// included in the failure message:
     ///
     /// ```should_panic
     /// # use expecters::prelude::*;
@@ -84,6 +135,54 @@ where
     fn to_be_err(&self) -> ToBeResultVariant {
         ToBeResultVariant::new(ResultVariant::Err)
     }
+
+    /// Asserts that the subject satisfies a series of inner assertions, chosen
+    /// based on whether the subject holds a success or an error. This is useful
+    /// when either variant is acceptable, but each has its own expectations.
+    ///
+    /// Like [`to_satisfy_with`], each function performs some inner assertions on
+    /// the contained value, returning a [`Result<(), AssertionError>`] to
+    /// indicate whether the assertion should pass or fail.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// let result: Result<i32, &str> = Err("error");
+    /// expect!(
+    ///     result,
+    ///     to_satisfy_result(
+    ///         |value| try_expect!(value, to_be_greater_than(0)),
+    ///         |error| try_expect!(error, to_equal("error")),
+    ///     ),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the function for the subject's variant fails:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// let result: Result<i32, &str> = Ok(0);
+    /// expect!(
+    ///     result,
+    ///     to_satisfy_result(
+    ///         |value| try_expect!(value, to_be_greater_than(0)),
+    ///         |error| try_expect!(error, to_equal("error")),
+    ///     ),
+    /// );
+    /// ```
+    ///
+    /// [`to_satisfy_with`]: crate::prelude::GeneralAssertions::to_satisfy_with
+    #[inline]
+    fn to_satisfy_result<F, G>(
+        &self,
+        ok_fn: Annotated<F>,
+        err_fn: Annotated<G>,
+    ) -> ToSatisfyResult<F, G>
+    where
+        F: FnOnce(T::OutT) -> Result<(), AssertionError>,
+        G: FnOnce(T::OutE) -> Result<(), AssertionError>,
+    {
+        ToSatisfyResult::new(ok_fn, err_fn)
+    }
 }
 
 impl<T, M> ResultAssertions<T, M> for AssertionBuilder<T, M>
@@ -99,4 +198,21 @@ where
     fn to_be_err_and(self) -> AssertionBuilder<T::OutE, ErrAndModifier<M>> {
         AssertionBuilder::modify(self, ErrAndModifier::new)
     }
+
+    #[inline]
+    fn flatten<U>(self) -> AssertionBuilder<U::OutT, FlattenResultModifier<M>>
+    where
+        T: Resultish<OutT = U>,
+        U: Resultish,
+    {
+        AssertionBuilder::modify(self, FlattenResultModifier::new)
+    }
+
+    #[inline]
+    fn map_err<F, U>(self, map: Annotated<F>) -> AssertionBuilder<U, MapErrModifier<M, F>>
+    where
+        F: FnOnce(T::OutE) -> U,
+    {
+        AssertionBuilder::modify(self, move |prev| MapErrModifier::new(prev, map))
+    }
 }
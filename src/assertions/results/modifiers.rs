@@ -1,5 +1,9 @@
 mod err_and;
+mod flatten;
+mod map_err;
 mod ok_and;
 
 pub use err_and::*;
+pub use flatten::*;
+pub use map_err::*;
 pub use ok_and::*;
@@ -0,0 +1,100 @@
+use crate::{
+    assertions::{
+        general::IntoInitializableOutput,
+        results::{annotated_err_repr, Resultish},
+        Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier,
+    },
+    metadata::Annotated,
+};
+
+/// Maps a nested result subject to its innermost [`Ok`] value.
+#[derive(Clone, Debug)]
+pub struct FlattenResultModifier<M> {
+    prev: M,
+}
+
+impl<M> FlattenResultModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M) -> Self {
+        Self { prev }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for FlattenResultModifier<M>
+where
+    M: AssertionModifier<FlattenResultAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(cx, FlattenResultAssertion { next })
+    }
+}
+
+/// Executes the inner assertion on the subject's innermost [`Ok`] value.
+#[derive(Clone, Debug)]
+pub struct FlattenResultAssertion<A> {
+    next: A,
+}
+
+impl<A, R, I> Assertion<R> for FlattenResultAssertion<A>
+where
+    A: Assertion<I::OutT, Output: IntoInitializableOutput>,
+    R: Resultish<OutT = I>,
+    I: Resultish,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: R) -> Self::Output {
+        let Some(subject) = subject.ok() else {
+            cx.annotate("layer", "outer");
+            return cx.fail("received Err");
+        };
+        let Some(subject) = subject.ok() else {
+            cx.annotate("layer", "inner");
+            return cx.fail("received Err");
+        };
+        self.next.execute(cx, subject).into_initialized()
+    }
+
+    fn execute_annotated(self, mut cx: AssertionContext, subject: Annotated<R>) -> Self::Output
+    where
+        Self: Sized,
+    {
+        if let Some(error) = annotated_err_repr(&subject) {
+            cx.annotate("error", error);
+        }
+
+        self.execute(cx, subject.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn missing_layer_is_annotated() {
+        let result: Result<Result<i32, &str>, ()> = Ok(Ok(1));
+        expect!(result, flatten, to_equal(1));
+        expect!(&result, flatten, to_equal(&1));
+
+        let result: Result<Result<i32, &str>, ()> = Err(());
+        expect!(
+            try_expect!(result, flatten, to_equal(1)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("layer: outer"),
+        );
+
+        let result: Result<Result<i32, &str>, ()> = Ok(Err("error"));
+        expect!(
+            try_expect!(result, flatten, to_equal(1)),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("layer: inner"),
+        );
+    }
+}
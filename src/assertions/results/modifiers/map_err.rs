@@ -0,0 +1,95 @@
+use crate::{
+    assertions::{
+        general::IntoInitializableOutput, results::Resultish, Assertion, AssertionContext,
+        AssertionContextBuilder, AssertionModifier,
+    },
+    metadata::Annotated,
+};
+
+/// Maps the subject to its [`Err`] value, then maps that value with a function.
+#[derive(Clone, Debug)]
+pub struct MapErrModifier<M, F> {
+    prev: M,
+    map: Annotated<F>,
+}
+
+impl<M, F> MapErrModifier<M, F> {
+    #[inline]
+    pub(crate) fn new(prev: M, map: Annotated<F>) -> Self {
+        Self { prev, map }
+    }
+}
+
+impl<M, F, A> AssertionModifier<A> for MapErrModifier<M, F>
+where
+    M: AssertionModifier<MapErrAssertion<A, F>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            MapErrAssertion {
+                next,
+                map: self.map,
+            },
+        )
+    }
+}
+
+/// Executes the inner assertion on the mapped [`Err`] value of the subject.
+#[derive(Clone, Debug)]
+pub struct MapErrAssertion<A, F> {
+    next: A,
+    map: Annotated<F>,
+}
+
+impl<A, F, R, U> Assertion<R> for MapErrAssertion<A, F>
+where
+    A: Assertion<U, Output: IntoInitializableOutput>,
+    F: FnOnce(R::OutE) -> U,
+    R: Resultish,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    #[inline]
+    fn execute(self, mut cx: AssertionContext, subject: R) -> Self::Output {
+        cx.annotate("function", &self.map);
+
+        let Some(subject) = subject.err() else {
+            return cx.fail("received Ok");
+        };
+        let map = self.map.into_inner();
+        self.next.execute(cx, map(subject)).into_initialized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Error, ErrorKind};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn error_is_mapped() {
+        let result: Result<(), Error> = Err(ErrorKind::NotFound.into());
+        expect!(
+            &result,
+            map_err(|error: &Error| error.kind()),
+            to_equal(ErrorKind::NotFound),
+        );
+        expect!(
+            result,
+            map_err(|error: Error| error.kind()),
+            not,
+            to_equal(ErrorKind::Other)
+        );
+    }
+
+    #[test]
+    fn ok_fails() {
+        let result: Result<i32, i32> = Ok(1);
+        expect!(result, not, map_err(|n: i32| n + 1), to_equal(1));
+    }
+}
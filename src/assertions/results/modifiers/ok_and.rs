@@ -1,6 +1,10 @@
-use crate::assertions::{
-    general::IntoInitializableOutput, results::Resultish, Assertion, AssertionContext,
-    AssertionContextBuilder, AssertionModifier,
+use crate::{
+    assertions::{
+        general::IntoInitializableOutput,
+        results::{annotated_err_repr, Resultish},
+        Assertion, AssertionContext, AssertionContextBuilder, AssertionModifier,
+    },
+    metadata::Annotated,
 };
 
 /// Maps the subject to its [`Ok`] value.
@@ -48,6 +52,17 @@ where
         };
         self.next.execute(cx, subject).into_initialized()
     }
+
+    fn execute_annotated(self, mut cx: AssertionContext, subject: Annotated<R>) -> Self::Output
+    where
+        Self: Sized,
+    {
+        if let Some(error) = annotated_err_repr(&subject) {
+            cx.annotate("error", error);
+        }
+
+        self.execute(cx, subject.into_inner())
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,5 @@
+use crate::metadata::Annotated;
+
 mod sealed {
     pub trait Sealed {
         type T;
@@ -8,6 +10,8 @@ mod sealed {
 
         fn ok(self) -> Option<Self::OutT>;
         fn err(self) -> Option<Self::OutE>;
+        fn is_err(&self) -> bool;
+        fn into_result(self) -> Result<Self::OutT, Self::OutE>;
     }
 
     impl<T, E> Sealed for Result<T, E> {
@@ -26,6 +30,16 @@ mod sealed {
         fn err(self) -> Option<Self::OutE> {
             self.err()
         }
+
+        #[inline]
+        fn is_err(&self) -> bool {
+            Result::is_err(self)
+        }
+
+        #[inline]
+        fn into_result(self) -> Result<Self::OutT, Self::OutE> {
+            self
+        }
     }
 
     impl<'a, T, E> Sealed for &'a Result<T, E> {
@@ -44,6 +58,16 @@ mod sealed {
         fn err(self) -> Option<Self::OutE> {
             self.as_ref().err()
         }
+
+        #[inline]
+        fn is_err(&self) -> bool {
+            Result::is_err(self)
+        }
+
+        #[inline]
+        fn into_result(self) -> Result<Self::OutT, Self::OutE> {
+            self.as_ref()
+        }
     }
 
     impl<'a, T, E> Sealed for &'a mut Result<T, E> {
@@ -62,6 +86,16 @@ mod sealed {
         fn err(self) -> Option<Self::OutE> {
             self.as_mut().err()
         }
+
+        #[inline]
+        fn is_err(&self) -> bool {
+            Result::is_err(self)
+        }
+
+        #[inline]
+        fn into_result(self) -> Result<Self::OutT, Self::OutE> {
+            self.as_mut()
+        }
     }
 }
 
@@ -75,3 +109,17 @@ mod sealed {
 pub trait Resultish: sealed::Sealed {}
 
 impl<R> Resultish for R where R: sealed::Sealed {}
+
+/// Gets the [`Debug`](std::fmt::Debug) representation of an annotated result
+/// if it holds an error and has a debug representation. Results holding a
+/// success are never formatted.
+pub(crate) fn annotated_err_repr<R>(subject: &Annotated<R>) -> Option<String>
+where
+    R: Resultish,
+{
+    if !subject.inner().is_err() {
+        return None;
+    }
+
+    subject.as_debug().map(|debug| format!("{debug:?}"))
+}
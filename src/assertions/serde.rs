@@ -0,0 +1,19 @@
+//! Assertions for types that can be serialized and deserialized with
+//! [serde](https://crates.io/crates/serde).
+//!
+//! > *Note: requires crate feature `serde`.*
+//!
+//! Values are serialized to JSON using
+//! [serde_json](https://crates.io/crates/serde_json).
+//!
+//! ```
+//! # use expecters::prelude::*;
+//! expect!(vec![1, 2, 3], to_serialize_to_json("[1, 2, 3]"));
+//! expect!(vec![1, 2, 3], to_roundtrip);
+//! ```
+
+mod assertions;
+mod extensions;
+
+pub use assertions::*;
+pub use extensions::*;
@@ -0,0 +1,5 @@
+mod to_roundtrip;
+mod to_serialize_to_json;
+
+pub use to_roundtrip::*;
+pub use to_serialize_to_json::*;
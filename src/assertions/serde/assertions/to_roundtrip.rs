@@ -0,0 +1,80 @@
+use ::serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    diff::fmt_diff,
+    AssertionOutput,
+};
+
+use super::to_serialize_to_json::pretty;
+
+/// Asserts that the subject is unchanged after serializing and deserializing it.
+#[derive(Clone, Debug)]
+pub struct ToRoundtrip {}
+
+impl ToRoundtrip {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T> Assertion<T> for ToRoundtrip
+where
+    T: Serialize + DeserializeOwned + PartialEq,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let serialized = match serde_json::to_string(&subject) {
+            Ok(serialized) => serialized,
+            Err(error) => {
+                cx.annotate("error", error);
+                return cx.fail("failed to serialize");
+            }
+        };
+        cx.annotate("serialized", &serialized);
+
+        let deserialized: T = match serde_json::from_str(&serialized) {
+            Ok(deserialized) => deserialized,
+            Err(error) => {
+                cx.annotate("error", error);
+                return cx.fail("failed to deserialize");
+            }
+        };
+        if deserialized == subject {
+            return cx.pass();
+        }
+
+        // Show how the values differ when serialized again
+        if let Some((original, roundtripped)) = serde_json::to_value(&subject)
+            .ok()
+            .zip(serde_json::to_value(&deserialized).ok())
+        {
+            if let Some(diff) = fmt_diff(&pretty(&original), &pretty(&roundtripped)) {
+                cx.add_page("diff", diff);
+            }
+        }
+        cx.fail("value changed after roundtrip")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::prelude::*;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Point {
+        x: i32,
+        #[serde(skip)]
+        y: i32,
+    }
+
+    #[test]
+    fn skipped_fields_fail() {
+        expect!(Point { x: 1, y: 0 }, to_roundtrip);
+        expect!(Point { x: 1, y: 2 }, not, to_roundtrip);
+    }
+}
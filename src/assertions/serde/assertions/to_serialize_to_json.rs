@@ -0,0 +1,95 @@
+use ::serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    diff::fmt_diff,
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject serializes to the given JSON.
+#[derive(Clone, Debug)]
+pub struct ToSerializeToJson<P> {
+    expected: Annotated<P>,
+}
+
+impl<P> ToSerializeToJson<P> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<P>) -> Self {
+        Self { expected }
+    }
+}
+
+impl<P, T> Assertion<T> for ToSerializeToJson<P>
+where
+    P: AsRef<str>,
+    T: Serialize,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("expected", &self.expected);
+
+        let expected: Value = match serde_json::from_str(self.expected.inner().as_ref()) {
+            Ok(expected) => expected,
+            Err(error) => {
+                cx.annotate("error", error);
+                return cx.fail("expected value is not valid JSON");
+            }
+        };
+        let actual = match serde_json::to_value(&subject) {
+            Ok(actual) => actual,
+            Err(error) => {
+                cx.annotate("error", error);
+                return cx.fail("failed to serialize");
+            }
+        };
+
+        // Values are compared structurally, so formatting doesn't matter
+        cx.annotate("actual", &actual);
+        if actual == expected {
+            return cx.pass();
+        }
+
+        if let Some(diff) = fmt_diff(&pretty(&expected), &pretty(&actual)) {
+            cx.add_page("diff", diff);
+        }
+        cx.fail("serialized values not equal")
+    }
+}
+
+pub(super) fn pretty(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use crate::prelude::*;
+
+    #[derive(Serialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn formatting_is_ignored() {
+        expect!(
+            Point { x: 1, y: 2 },
+            to_serialize_to_json(r#"{ "y": 2, "x": 1 }"#),
+        );
+        expect!(
+            Point { x: 1, y: 2 },
+            not,
+            to_serialize_to_json(r#"{ "x": 2, "y": 1 }"#),
+        );
+    }
+
+    #[test]
+    fn invalid_json_fails() {
+        expect!(Point { x: 1, y: 2 }, not, to_serialize_to_json("{"));
+    }
+}
@@ -0,0 +1,64 @@
+use ::serde::{de::DeserializeOwned, Serialize};
+
+use crate::{assertions::AssertionBuilder, metadata::Annotated};
+
+use super::{ToRoundtrip, ToSerializeToJson};
+
+/// Assertions for types that can be serialized and deserialized.
+pub trait SerdeAssertions<T, M>
+where
+    T: Serialize,
+{
+    /// Asserts that the subject serializes to the given JSON. The JSON values
+    /// are compared structurally, so whitespace and the order of fields in
+    /// objects are ignored.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([("a", 1), ("b", 2)]);
+    /// expect!(map, to_serialize_to_json(r#"{ "b": 2, "a": 1 }"#));
+    /// ```
+    ///
+    /// The assertion fails if the serialized values are different:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(vec![1, 2, 3], to_serialize_to_json("[3, 2, 1]"));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_serialize_to_json<P>(&self, expected: Annotated<P>) -> ToSerializeToJson<P>
+    where
+        P: AsRef<str>,
+    {
+        ToSerializeToJson::new(expected)
+    }
+
+    /// Asserts that the subject is equal to itself after being serialized and
+    /// deserialized again.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(vec![1, 2, 3], to_roundtrip);
+    /// ```
+    ///
+    /// The assertion fails if the deserialized value is different:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// // NaN serializes to null, which can't be deserialized into a float
+    /// expect!(f64::NAN, to_roundtrip);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_roundtrip(&self) -> ToRoundtrip
+    where
+        T: DeserializeOwned + PartialEq,
+    {
+        ToRoundtrip::new()
+    }
+}
+
+impl<T, M> SerdeAssertions<T, M> for AssertionBuilder<T, M> where T: Serialize {}
@@ -1,7 +1,17 @@
+mod to_be_empty;
 mod to_contain_substr;
+mod to_equal_ignoring_ascii_case;
+mod to_have_length;
+mod to_have_line;
+mod to_match_debug;
 #[cfg(feature = "regex")]
 mod to_match_regex;
 
+pub use to_be_empty::*;
 pub use to_contain_substr::*;
+pub use to_equal_ignoring_ascii_case::*;
+pub use to_have_length::*;
+pub use to_have_line::*;
+pub use to_match_debug::*;
 #[cfg(feature = "regex")]
 pub use to_match_regex::*;
@@ -0,0 +1,64 @@
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    AssertionOutput,
+};
+
+/// Asserts that the subject is empty, or that it contains only whitespace.
+#[derive(Clone, Debug)]
+pub struct ToBeEmptyString {
+    kind: Emptiness,
+}
+
+impl ToBeEmptyString {
+    #[inline]
+    pub(crate) fn new(kind: Emptiness) -> Self {
+        Self { kind }
+    }
+}
+
+impl<T> Assertion<T> for ToBeEmptyString
+where
+    T: AsRef<str>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let subject = subject.as_ref();
+        let trimmed = subject.trim();
+        cx.annotate("trimmed", format_args!("{trimmed:?}"));
+        cx.annotate("length", subject.len());
+
+        match self.kind {
+            Emptiness::Empty => cx.pass_if(subject.is_empty(), "string is not empty"),
+            Emptiness::Blank => cx.pass_if(trimmed.is_empty(), "string is not blank"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Emptiness {
+    Empty,
+    Blank,
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::prelude::*;
+
+    #[test_case("", true, true; "empty")]
+    #[test_case(" \t\n", false, true; "whitespace")]
+    #[test_case(" a ", false, false; "text")]
+    fn empty_and_blank(subject: &str, empty: bool, blank: bool) {
+        expect!(try_expect!(subject, to_be_empty).is_ok(), to_equal(empty));
+        expect!(try_expect!(subject, to_be_blank).is_ok(), to_equal(blank));
+    }
+
+    #[test]
+    fn trimmed_form_is_annotated() {
+        let message = try_expect!("  ", to_be_empty).unwrap_err().to_string();
+        expect!(&message, to_contain_substr(r#"trimmed: """#));
+        expect!(&message, to_contain_substr("length: 2"));
+    }
+}
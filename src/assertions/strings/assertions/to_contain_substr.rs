@@ -39,9 +39,58 @@ where
     }
 }
 
+/// Asserts that the subject contains the given substring an exact number of
+/// times.
+#[derive(Clone, Debug)]
+pub struct ToContainSubstrTimes<P> {
+    pattern: Annotated<P>,
+    expected: Annotated<usize>,
+}
+
+impl<P> ToContainSubstrTimes<P> {
+    #[inline]
+    pub(crate) fn new(pattern: Annotated<P>, expected: Annotated<usize>) -> Self {
+        Self { pattern, expected }
+    }
+}
+
+impl<P, T> Assertion<T> for ToContainSubstrTimes<P>
+where
+    P: AsRef<str>,
+    T: AsRef<str>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let pattern = self.pattern.inner().as_ref();
+        cx.annotate("pattern", format_args!("{pattern:?}"));
+
+        let expected = *self.expected.inner();
+        let actual = subject.as_ref().matches(pattern).count();
+        cx.annotate("expected", self.expected);
+        cx.annotate("actual", actual);
+        cx.pass_if(
+            actual == expected,
+            "substring found a different number of times",
+        )
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub(crate) enum ContainsLocation {
     Anywhere,
     Start,
     End,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn matches_do_not_overlap() {
+        expect!("aaaa", to_contain_substr_times("aa", 2));
+        expect!("abab", to_contain_substr_times("aba", 1));
+        expect!("abc", to_contain_substr_times("d", 0));
+    }
+}
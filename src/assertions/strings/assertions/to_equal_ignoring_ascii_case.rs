@@ -0,0 +1,52 @@
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject is equal to an expected string, ignoring ASCII
+/// case.
+#[derive(Clone, Debug)]
+pub struct ToEqualIgnoringAsciiCase<P> {
+    expected: Annotated<P>,
+}
+
+impl<P> ToEqualIgnoringAsciiCase<P> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<P>) -> Self {
+        Self { expected }
+    }
+}
+
+impl<T, P> Assertion<T> for ToEqualIgnoringAsciiCase<P>
+where
+    T: AsRef<str>,
+    P: AsRef<str>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let subject = subject.as_ref();
+        cx.annotate("expected", &self.expected);
+        cx.annotate("actual", format_args!("{subject:?}"));
+
+        let expected = self.expected.inner().as_ref();
+        cx.pass_if(
+            subject.eq_ignore_ascii_case(expected),
+            "strings not equal ignoring ASCII case",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn only_ascii_case_is_ignored() {
+        expect!("Content-Type", to_equal_ignoring_ascii_case("content-type"));
+        expect!("SELECT", to_equal_ignoring_ascii_case("select"));
+        expect!("ÉCOLE", not, to_equal_ignoring_ascii_case("école"));
+        expect!("abc", not, to_equal_ignoring_ascii_case("abcd"));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject has the given length, measured in either bytes or
+/// characters.
+#[derive(Clone, Debug)]
+pub struct ToHaveStringLength {
+    expected: Annotated<usize>,
+    unit: StringLengthUnit,
+}
+
+impl ToHaveStringLength {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<usize>, unit: StringLengthUnit) -> Self {
+        Self { expected, unit }
+    }
+}
+
+impl<T> Assertion<T> for ToHaveStringLength
+where
+    T: AsRef<str>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let subject = subject.as_ref();
+        let actual = match self.unit {
+            StringLengthUnit::Bytes => subject.len(),
+            StringLengthUnit::Chars => subject.chars().count(),
+        };
+
+        let expected = *self.expected.inner();
+        cx.annotate("expected", self.expected);
+        cx.annotate("actual", actual);
+        cx.pass_if(actual == expected, "lengths not equal")
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum StringLengthUnit {
+    Bytes,
+    Chars,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn non_ascii_lengths_differ() {
+        expect!("héllo", to_have_byte_length(6));
+        expect!("héllo", to_have_char_length(5));
+        expect!("héllo", not, to_have_byte_length(5));
+        expect!("héllo", not, to_have_char_length(6));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that a specific line of the subject is equal to an expected string.
+#[derive(Clone, Debug)]
+pub struct ToHaveLine<P> {
+    index: Annotated<usize>,
+    expected: Annotated<P>,
+}
+
+impl<P> ToHaveLine<P> {
+    #[inline]
+    pub(crate) fn new(index: Annotated<usize>, expected: Annotated<P>) -> Self {
+        Self { index, expected }
+    }
+}
+
+impl<T, P> Assertion<T> for ToHaveLine<P>
+where
+    T: AsRef<str>,
+    P: AsRef<str>,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        let index = *self.index.inner();
+        cx.annotate("index", self.index);
+        cx.annotate("expected", &self.expected);
+
+        let subject = subject.as_ref();
+        let Some(line) = subject.lines().nth(index) else {
+            cx.annotate("line count", subject.lines().count());
+            return cx.fail("line not found");
+        };
+
+        cx.annotate("actual", format_args!("{line:?}"));
+        cx.pass_if(line == self.expected.inner().as_ref(), "lines not equal")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn line_endings_are_ignored() {
+        expect!("a\r\nb\r\n", to_have_line(1, "b"));
+        expect!("a\nb\n", not, to_have_line(2, ""));
+        expect!(
+            try_expect!("a\nb", to_have_line(5, "c")),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("line count: 2"),
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt::Debug;
+
+use crate::{
+    assertions::{Assertion, AssertionContext},
+    diff::fmt_diff,
+    metadata::Annotated,
+    AssertionOutput,
+};
+
+/// Asserts that the subject's pretty-printed [`Debug`] representation matches
+/// an expected string.
+#[derive(Clone, Debug)]
+pub struct ToMatchDebug<P> {
+    expected: Annotated<P>,
+}
+
+impl<P> ToMatchDebug<P> {
+    #[inline]
+    pub(crate) fn new(expected: Annotated<P>) -> Self {
+        Self { expected }
+    }
+}
+
+impl<P, T> Assertion<T> for ToMatchDebug<P>
+where
+    P: AsRef<str>,
+    T: Debug,
+{
+    type Output = AssertionOutput;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        // Surrounding whitespace is ignored so the expected string can start on
+        // its own line
+        let expected = self.expected.inner().as_ref().trim();
+        let actual = format!("{subject:#?}");
+        if actual.trim() == expected {
+            return cx.pass();
+        }
+
+        cx.annotate("expected", expected);
+        cx.annotate("actual", actual.trim());
+        if let Some(diff) = fmt_diff(expected, actual.trim()) {
+            cx.add_page("diff", diff);
+        }
+        cx.fail("debug representations not equal")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn surrounding_whitespace_is_ignored() {
+        expect!(
+            Point { x: 1, y: 2 },
+            to_match_debug(
+                r"
+Point {
+    x: 1,
+    y: 2,
+}
+"
+            ),
+        );
+    }
+
+    #[test]
+    fn mismatch_annotates_both_representations() {
+        let message = try_expect!(Point { x: 1, y: 2 }, to_match_debug("Point { x: 1, y: 2 }"))
+            .unwrap_err()
+            .to_string();
+        expect!(
+            &message,
+            to_contain_substr("expected: Point { x: 1, y: 2 }")
+        );
+        expect!(&message, to_contain_substr("actual: Point {\n    x: 1,"));
+    }
+
+    #[test]
+    #[cfg(feature = "diff")]
+    fn mismatch_has_diff() {
+        expect!(
+            try_expect!(Point { x: 1, y: 2 }, to_match_debug("Point { x: 1, y: 2 }")),
+            to_be_err_and,
+            as_display,
+            to_contain_substr("diff"),
+        );
+    }
+}
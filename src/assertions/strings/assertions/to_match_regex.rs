@@ -16,13 +16,18 @@ pub struct ToMatchRegex {
 impl ToMatchRegex {
     #[inline]
     pub(crate) fn new(pattern: &str) -> Self {
-        let regex = Regex::new(pattern).expect("invalid regex");
         Self {
-            regex: Arc::new(regex),
+            regex: compile_regex(pattern),
         }
     }
 }
 
+/// Compiles a regular expression so it can be cheaply shared between clones of
+/// an assertion.
+pub(crate) fn compile_regex(pattern: &str) -> Arc<Regex> {
+    Arc::new(Regex::new(pattern).expect("invalid regex"))
+}
+
 impl<T> Assertion<T> for ToMatchRegex
 where
     T: AsRef<str>,
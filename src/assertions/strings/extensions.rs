@@ -2,7 +2,11 @@ use std::fmt::{Debug, Display};
 
 use crate::{assertions::AssertionBuilder, metadata::Annotated};
 
-use super::{AsDebugModifier, AsDisplayModifier, CharsModifier, ContainsLocation, ToContainSubstr};
+use super::{
+    AsDebugModifier, AsDisplayModifier, CharsModifier, ContainsLocation, Emptiness,
+    StringLengthUnit, ToBeEmptyString, ToContainSubstr, ToContainSubstrTimes,
+    ToEqualIgnoringAsciiCase, ToHaveLine, ToHaveStringLength, ToMatchDebug,
+};
 
 /// Assertions and modifiers for [`String`]s.
 pub trait StringAssertions<T, M>
@@ -40,6 +44,34 @@ where
         ToContainSubstr::new(pattern, ContainsLocation::Anywhere)
     }
 
+    /// Asserts that the subject contains the given substring exactly `count`
+    /// times. Occurrences are counted without overlapping.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!("{{name}} and {{name}}", to_contain_substr_times("{{name}}", 2));
+    /// expect!("aaaa", to_contain_substr_times("aa", 2));
+    /// ```
+    ///
+    /// The assertion fails if the substring occurs a different number of times:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!("Hello, world!", to_contain_substr_times("o", 1));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_contain_substr_times<P>(
+        &self,
+        pattern: Annotated<P>,
+        count: Annotated<usize>,
+    ) -> ToContainSubstrTimes<P>
+    where
+        P: AsRef<str>,
+    {
+        ToContainSubstrTimes::new(pattern, count)
+    }
+
     /// Asserts that the subject starts with the given substring.
     ///
     /// ```
@@ -84,6 +116,134 @@ where
         ToContainSubstr::new(pattern, ContainsLocation::End)
     }
 
+    /// Asserts that the subject is an empty string.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!("", to_be_empty);
+    /// ```
+    ///
+    /// The assertion fails if the subject contains any characters, including
+    /// whitespace. Use [`to_be_blank`](Self::to_be_blank) to allow whitespace:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!("  ", to_be_empty);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_empty(&self) -> ToBeEmptyString {
+        ToBeEmptyString::new(Emptiness::Empty)
+    }
+
+    /// Asserts that the subject is empty or contains only whitespace.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!("", to_be_blank);
+    /// expect!(" \t\n", to_be_blank);
+    /// ```
+    ///
+    /// The assertion fails if the subject contains any other characters:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(" a ", to_be_blank);
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_be_blank(&self) -> ToBeEmptyString {
+        ToBeEmptyString::new(Emptiness::Blank)
+    }
+
+    /// Asserts that the subject is equal to the expected string, ignoring the
+    /// case of ASCII letters. Non-ASCII characters must match exactly. This is
+    /// the correct comparison for many protocols, like HTTP header names or SQL
+    /// keywords.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!("Content-Type", to_equal_ignoring_ascii_case("content-type"));
+    /// ```
+    ///
+    /// The assertion fails if the strings differ by more than ASCII case:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!("ÉCOLE", to_equal_ignoring_ascii_case("école"));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_equal_ignoring_ascii_case<P>(&self, expected: Annotated<P>) -> ToEqualIgnoringAsciiCase<P>
+    where
+        P: AsRef<str>,
+    {
+        ToEqualIgnoringAsciiCase::new(expected)
+    }
+
+    /// Asserts that the subject is the given number of bytes long.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!("héllo", to_have_byte_length(6));
+    /// ```
+    ///
+    /// The assertion fails if the subject has a different number of bytes:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// // 'é' is two bytes long
+    /// expect!("héllo", to_have_byte_length(5));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_have_byte_length(&self, expected: Annotated<usize>) -> ToHaveStringLength {
+        ToHaveStringLength::new(expected, StringLengthUnit::Bytes)
+    }
+
+    /// Asserts that the subject contains the given number of [`char`]s.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!("héllo", to_have_char_length(5));
+    /// ```
+    ///
+    /// The assertion fails if the subject has a different number of characters:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!("héllo", to_have_char_length(6));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_have_char_length(&self, expected: Annotated<usize>) -> ToHaveStringLength {
+        ToHaveStringLength::new(expected, StringLengthUnit::Chars)
+    }
+
+    /// Asserts that the line at the given (zero-based) index of the subject is
+    /// equal to the expected string. Lines are split using [`str::lines`], so
+    /// line endings are not included in the line.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!("first\nsecond\nthird", to_have_line(1, "second"));
+    /// ```
+    ///
+    /// The assertion fails if the line is different, or if the subject does not
+    /// have enough lines. The actual line is included in the failure message:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!("first\nsecond\nthird", to_have_line(1, "third"));
+    /// ```
+    #[inline]
+    fn to_have_line<P>(&self, index: Annotated<usize>, expected: Annotated<P>) -> ToHaveLine<P>
+    where
+        P: AsRef<str>,
+    {
+        ToHaveLine::new(index, expected)
+    }
+
     /// Asserts that the subject matches the given regular expression.
     ///
     /// ```
@@ -111,6 +271,37 @@ where
     {
         super::ToMatchRegex::new(pattern.inner().as_ref())
     }
+
+    /// Finds the first match of the given regular expression in the subject,
+    /// then continues the assertion with the matched substring.
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// expect!(
+    ///     "version: 1.2.3",
+    ///     to_contain_match(r"\d+\.\d+\.\d+"),
+    ///     to_start_with("1."),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the pattern is not found in the subject:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!("version: unknown", to_contain_match(r"\d+"), to_equal("1"));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// This panics immediately, without executing the assertion, if the provided
+    /// pattern is an invalid regular expression.
+    #[cfg(feature = "regex")]
+    fn to_contain_match<P>(
+        self,
+        pattern: Annotated<P>,
+    ) -> AssertionBuilder<String, super::ContainMatchModifier<M>>
+    where
+        P: AsRef<str>;
 }
 
 impl<T, M> StringAssertions<T, M> for AssertionBuilder<T, M>
@@ -121,6 +312,20 @@ where
     fn chars(self) -> AssertionBuilder<Vec<char>, CharsModifier<M>> {
         AssertionBuilder::modify(self, CharsModifier::new)
     }
+
+    #[inline]
+    #[cfg(feature = "regex")]
+    fn to_contain_match<P>(
+        self,
+        pattern: Annotated<P>,
+    ) -> AssertionBuilder<String, super::ContainMatchModifier<M>>
+    where
+        P: AsRef<str>,
+    {
+        AssertionBuilder::modify(self, move |prev| {
+            super::ContainMatchModifier::new(prev, pattern.inner().as_ref())
+        })
+    }
 }
 
 /// Assertions and modifiers for types with a [`Debug`] representation.
@@ -138,6 +343,48 @@ where
     fn as_debug(self) -> AssertionBuilder<String, AsDebugModifier<M>>
     where
         T: Debug;
+
+    /// Asserts that the subject's pretty-printed (`{:#?}`) [`Debug`]
+    /// representation is equal to the given string. Whitespace at the start and
+    /// end of both representations is ignored. A diff is shown on failure.
+    ///
+    /// This is useful as a lightweight inline snapshot:
+    ///
+    /// ```
+    /// # use expecters::prelude::*;
+    /// #[derive(Debug)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// expect!(
+    ///     Point { x: 1, y: 2 },
+    ///     to_match_debug(
+    ///         r"
+    /// Point {
+    ///     x: 1,
+    ///     y: 2,
+    /// }
+    /// "
+    ///     ),
+    /// );
+    /// ```
+    ///
+    /// The assertion fails if the representations are different:
+    ///
+    /// ```should_panic
+    /// # use expecters::prelude::*;
+    /// expect!(Some(1), to_match_debug("Some(2)"));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn to_match_debug<P>(&self, expected: Annotated<P>) -> ToMatchDebug<P>
+    where
+        P: AsRef<str>,
+    {
+        ToMatchDebug::new(expected)
+    }
 }
 
 impl<T, M> DebugAssertions<T, M> for AssertionBuilder<T, M>
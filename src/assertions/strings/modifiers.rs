@@ -1,7 +1,11 @@
 mod chars;
+#[cfg(feature = "regex")]
+mod contain_match;
 mod debug;
 mod display;
 
 pub use chars::*;
+#[cfg(feature = "regex")]
+pub use contain_match::*;
 pub use debug::*;
 pub use display::*;
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use regex::Regex;
+
+use crate::assertions::{
+    general::IntoInitializableOutput, strings::compile_regex, Assertion, AssertionContext,
+    AssertionContextBuilder, AssertionModifier,
+};
+
+/// Finds a match for a regular expression within the subject.
+#[derive(Clone, Debug)]
+pub struct ContainMatchModifier<M> {
+    prev: M,
+    regex: Arc<Regex>,
+}
+
+impl<M> ContainMatchModifier<M> {
+    #[inline]
+    pub(crate) fn new(prev: M, pattern: &str) -> Self {
+        Self {
+            prev,
+            regex: compile_regex(pattern),
+        }
+    }
+}
+
+impl<M, A> AssertionModifier<A> for ContainMatchModifier<M>
+where
+    M: AssertionModifier<ContainMatchAssertion<A>>,
+{
+    type Output = M::Output;
+
+    #[inline]
+    fn apply(self, cx: AssertionContextBuilder, next: A) -> Self::Output {
+        self.prev.apply(
+            cx,
+            ContainMatchAssertion {
+                next,
+                regex: self.regex,
+            },
+        )
+    }
+}
+
+/// Executes the inner assertion on the first match of a regular expression
+/// within the subject.
+#[derive(Clone, Debug)]
+pub struct ContainMatchAssertion<A> {
+    next: A,
+    regex: Arc<Regex>,
+}
+
+impl<A, T> Assertion<T> for ContainMatchAssertion<A>
+where
+    A: Assertion<String, Output: IntoInitializableOutput>,
+    T: AsRef<str>,
+{
+    type Output = <A::Output as IntoInitializableOutput>::Initialized;
+
+    fn execute(self, mut cx: AssertionContext, subject: T) -> Self::Output {
+        cx.annotate("pattern", self.regex.as_str());
+
+        let subject = subject.as_ref();
+        let Some(found) = self.regex.find(subject) else {
+            cx.annotate("subject", format_args!("{subject:?}"));
+            return cx.fail("no match found");
+        };
+        self.next
+            .execute(cx, found.as_str().to_string())
+            .into_initialized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn first_match_is_used() {
+        expect!(
+            "order 123, order 456",
+            to_contain_match(r"\d+"),
+            to_equal("123"),
+        );
+        expect!("no digits", not, to_contain_match(r"\d+"), to_equal(""));
+    }
+}
@@ -5,6 +5,15 @@ pub fn fmt_diff(_expected: &str, _actual: &str) -> Option<String> {
 
 #[cfg(feature = "diff")]
 pub fn fmt_diff(expected: &str, actual: &str) -> Option<String> {
+    fmt_diff_with_theme(expected, actual, crate::styles::theme())
+}
+
+#[cfg(feature = "diff")]
+fn fmt_diff_with_theme(
+    expected: &str,
+    actual: &str,
+    theme: crate::styles::Theme,
+) -> Option<String> {
     use diff::Result;
 
     let lines = diff::lines(expected, actual);
@@ -14,8 +23,9 @@ pub fn fmt_diff(expected: &str, actual: &str) -> Option<String> {
 
     for line in lines {
         different = different || matches!(line, Result::Left(_) | Result::Right(_));
-        state = state.step(&mut output, line);
+        state = state.step(&mut output, theme, line);
     }
+    state.flush(&mut output, theme);
 
     if different {
         Some(output)
@@ -30,7 +40,7 @@ mod diff_utils {
 
     use diff::Result;
 
-    use crate::styles;
+    use crate::styles::{self, Theme};
 
     #[derive(Debug, Default)]
     pub enum LineDiffState<'a> {
@@ -60,6 +70,7 @@ mod diff_utils {
         added: &mut String,
         buffer: &mut String,
         buffer_type: ChangeType,
+        theme: Theme,
     ) {
         if buffer.is_empty() {
             return;
@@ -70,9 +81,15 @@ mod diff_utils {
                 write!(removed, "{}", styles::removed(&buffer)).unwrap();
                 write!(added, "{}", styles::added(&buffer)).unwrap();
             }
+            ChangeType::Removed if theme == Theme::Plain => {
+                write!(removed, "[-{buffer}-]").unwrap();
+            }
             ChangeType::Removed => {
                 write!(removed, "{}", styles::emphasize_removed(&buffer)).unwrap();
             }
+            ChangeType::Added if theme == Theme::Plain => {
+                write!(added, "{{+{buffer}+}}").unwrap();
+            }
             ChangeType::Added => {
                 write!(added, "{}", styles::emphasize_added(&buffer)).unwrap();
             }
@@ -81,7 +98,7 @@ mod diff_utils {
         buffer.clear();
     }
 
-    fn diff_line(output: &mut String, removed: &str, added: &str) {
+    fn diff_line(output: &mut String, theme: Theme, removed: &str, added: &str) {
         // Get removed/added representations
         let diff = diff::chars(removed, added);
         let mut removed_repr = String::with_capacity(removed.len());
@@ -92,7 +109,13 @@ mod diff_utils {
             match c {
                 Result::Both(c, _) => {
                     if buffer_type != ChangeType::NoChange {
-                        flush_buffer(&mut removed_repr, &mut added_repr, &mut buffer, buffer_type);
+                        flush_buffer(
+                            &mut removed_repr,
+                            &mut added_repr,
+                            &mut buffer,
+                            buffer_type,
+                            theme,
+                        );
                         buffer_type = ChangeType::NoChange;
                     }
 
@@ -100,7 +123,13 @@ mod diff_utils {
                 }
                 Result::Left(c) => {
                     if buffer_type != ChangeType::Removed {
-                        flush_buffer(&mut removed_repr, &mut added_repr, &mut buffer, buffer_type);
+                        flush_buffer(
+                            &mut removed_repr,
+                            &mut added_repr,
+                            &mut buffer,
+                            buffer_type,
+                            theme,
+                        );
                         buffer_type = ChangeType::Removed;
                     }
 
@@ -108,7 +137,13 @@ mod diff_utils {
                 }
                 Result::Right(c) => {
                     if buffer_type != ChangeType::Added {
-                        flush_buffer(&mut removed_repr, &mut added_repr, &mut buffer, buffer_type);
+                        flush_buffer(
+                            &mut removed_repr,
+                            &mut added_repr,
+                            &mut buffer,
+                            buffer_type,
+                            theme,
+                        );
                         buffer_type = ChangeType::Added;
                     }
 
@@ -117,13 +152,19 @@ mod diff_utils {
             }
         }
 
-        flush_buffer(&mut removed_repr, &mut added_repr, &mut buffer, buffer_type);
+        flush_buffer(
+            &mut removed_repr,
+            &mut added_repr,
+            &mut buffer,
+            buffer_type,
+            theme,
+        );
         writeln!(output, "{} {removed_repr}", styles::removed(&"-")).unwrap();
         writeln!(output, "{} {added_repr}", styles::added(&"+")).unwrap();
     }
 
     impl<'a> LineDiffState<'a> {
-        fn flush(self, output: &mut String) {
+        pub fn flush(self, output: &mut String, theme: Theme) {
             match self {
                 LineDiffState::NoDiff => {}
                 LineDiffState::Removing(removed) => {
@@ -159,7 +200,7 @@ mod diff_utils {
                                     .unwrap();
                             }
                             (Some(removed), Some(added)) => {
-                                diff_line(output, removed, added);
+                                diff_line(output, theme, removed, added);
                             }
                         }
                     }
@@ -167,7 +208,7 @@ mod diff_utils {
             }
         }
 
-        pub fn step(self, output: &mut String, result: Result<&'a str>) -> Self {
+        pub fn step(self, output: &mut String, theme: Theme, result: Result<&'a str>) -> Self {
             match (self, result) {
                 // NoDiff
                 (LineDiffState::NoDiff, Result::Left(line)) => LineDiffState::Removing(vec![line]),
@@ -223,10 +264,26 @@ mod diff_utils {
 
                 // Flush
                 (state, result) => {
-                    state.flush(output);
-                    LineDiffState::NoDiff.step(output, result)
+                    state.flush(output, theme);
+                    LineDiffState::NoDiff.step(output, theme, result)
                 }
             }
         }
     }
 }
+
+#[cfg(all(test, feature = "diff"))]
+mod tests {
+    use crate::{prelude::*, styles::Theme};
+
+    use super::fmt_diff_with_theme;
+
+    #[test]
+    fn plain_theme_marks_changes() {
+        let diff = fmt_diff_with_theme("the lazy dog", "the lazyish dog", Theme::Plain);
+        expect!(&diff, to_be_some_and, to_contain_substr("{+ish+}"));
+
+        let diff = fmt_diff_with_theme("the lazy dog", "the lazyish dog", Theme::Default);
+        expect!(diff, to_be_some_and, not, to_contain_substr("{+ish+}"));
+    }
+}
@@ -71,18 +71,28 @@
 //! - `futures`*: Enables async assertions.
 //! - `regex`*: Enables assertions that use regular expressions. Uses
 //!   [regex](https://crates.io/crates/regex) to execute them.
-//! - `colors`*: Enables styled failure messages. Styled messages can always be
-//!   disabled by setting `NO_COLOR`.
+//! - `serde`: Enables assertions on serializable types. Uses
+//!   [serde_json](https://crates.io/crates/serde_json) to serialize them.
+//! - `colors`*: Enables styled failure messages. Styled messages can be
+//!   disabled by setting `NO_COLOR`, or forced on by setting `FORCE_COLOR`. See
+//!   the [`styles`] module for alternative themes and color modes.
+//!
+//! ## Debugging assertions
+//!
+//! Each step of an assertion can write the value it received to stderr as it
+//! executes by setting the `EXPECTERS_TRACE` environment variable. See the
+//! [`trace`] module for more information.
 
 pub mod assertions;
 pub mod metadata;
 pub mod prelude;
 #[doc(hidden)]
 pub mod specialization;
+pub mod styles;
+pub mod trace;
 
 mod diff;
 mod macros;
-mod styles;
 
 pub use assertions::AssertionOutput;
 
@@ -27,12 +27,13 @@
 /// Breaking this down, the macro accepts arguments in the format
 /// `expect!(subject, modifiers..., assertion)`. The subject may be any value
 /// that you want to execute an assertion on (and is moved/copied into the
-/// assertion - make sure to borrow the value if needed). The final argument
-/// must be a fully built assertion.
+/// assertion - make sure to borrow the value if needed, or use
+/// [`expect_ref!`]). The final argument must be a fully built assertion.
 ///
 /// Both the modifiers and the final assertion must be either identifiers or
-/// simple function calls in the format `<ident>(params...)`. This is because
-/// the parameters to function calls will be annotated. This means that **the
+/// simple function calls in the format `<ident>(params...)`, optionally with
+/// generic arguments like `<ident>::<T>(params...)`. This is because the
+/// parameters to function calls will be annotated. This means that **the
 /// following syntax is invalid**, as paths are not supported:
 ///
 /// ```compile_fail
@@ -47,6 +48,26 @@
 /// expect!(1, not, to_equal(0));
 /// ```
 ///
+/// A custom message can be added after the final assertion, separated by a
+/// semicolon. The message is shown at the top of the failure message, and
+/// accepts the same formatting arguments as [`format!`]:
+///
+/// ```should_panic
+/// # use expecters::prelude::*;
+/// let id = 1;
+/// expect!(id, to_equal(2); "ids must match (got {id})");
+/// ```
+///
+/// The final assertion may also be a macro invocation in the format
+/// `<ident>!(tokens...)`, like [`to_match_pattern!`]. These assertions receive
+/// their tokens unparsed, so they can accept syntax that function parameters
+/// can't, like patterns:
+///
+/// ```
+/// # use expecters::prelude::*;
+/// expect!(Some(1), to_match_pattern!(Some(_)));
+/// ```
+///
 /// Modifiers are special assertion builders that are used to modify a later
 /// assertion either by transforming the input to that assertion (like [`map`]),
 /// transforming the output from the assertion (like [`not`]), or even calling
@@ -185,6 +206,134 @@ macro_rules! try_expect {
     };
 }
 
+/// Same as [`try_expect!`], but returns an [`AssertionReport`] that keeps the
+/// context of the assertion even if it succeeds.
+///
+/// The report exposes the subject's representation and the source location of
+/// the assertion, as well as the failure message if the assertion failed. This
+/// is useful for building custom harnesses that aggregate assertion results.
+///
+/// ```
+/// # use expecters::prelude::*;
+/// let report = try_expect_report!(1, to_equal(2));
+/// expect!(report.subject(), to_equal("1"));
+/// expect!(report.failure(), to_equal(Some("values not equal")));
+/// ```
+///
+/// This macro only supports assertions that output an [`AssertionOutput`], so
+/// async assertions are not supported.
+///
+/// See [`expect!`] for more information on how to use this macro.
+///
+/// [`AssertionOutput`]: crate::AssertionOutput
+/// [`AssertionReport`]: crate::assertions::AssertionReport
+#[macro_export]
+macro_rules! try_expect_report {
+    ($($tokens:tt)*) => {
+        $crate::assertions::AssertionReport::new($crate::__expect_inner!($($tokens)*))
+    };
+}
+
+/// Same as [`expect!`], but borrows the subject rather than moving it.
+///
+/// The assertion is executed on a reference to the subject, so the subject can
+/// still be used after the assertion. This is useful for asserting on a field
+/// of a value that is needed later without cloning it.
+///
+/// ```
+/// # use expecters::prelude::*;
+/// struct User {
+///     name: String,
+/// }
+///
+/// let user = User {
+///     name: "Alice".to_string(),
+/// };
+/// expect_ref!(user.name, to_equal("Alice"));
+/// expect_ref!(user.name, to_have_char_length(5));
+/// drop(user);
+/// ```
+///
+/// Failure messages show the subject's source as it was written, without the
+/// added borrow:
+///
+/// ```should_panic
+/// # use expecters::prelude::*;
+/// let names = vec!["Alice".to_string()];
+/// expect_ref!(names, count, to_equal(2));
+/// ```
+///
+/// See [`expect!`] for more information on how to use this macro.
+#[macro_export]
+macro_rules! expect_ref {
+    ($subject:expr, $($tokens:tt)*) => {
+        $crate::assertions::general::UnwrappableOutput::unwrap(
+            $crate::__expect_inner!(@ref, $subject, $($tokens)*),
+        )
+    };
+}
+
+/// Asserts that the subject matches a pattern.
+///
+/// This macro can only be used as the final assertion in an [`expect!`] (or
+/// [`try_expect!`]) call. The pattern accepts the same syntax as the pattern in
+/// [`matches!`], including an optional `if` guard, and its source is included
+/// in failure messages.
+///
+/// ```
+/// # use expecters::prelude::*;
+/// expect!(Some(1), to_match_pattern!(Some(n) if n > 0));
+/// expect!('b', to_match_pattern!('a'..='z'));
+/// ```
+///
+/// The assertion fails if the subject does not match the pattern:
+///
+/// ```should_panic
+/// # use expecters::prelude::*;
+/// expect!(Some(0), to_match_pattern!(Some(n) if n > 0));
+/// ```
+#[macro_export]
+macro_rules! to_match_pattern {
+    (@build, $builder:expr, $($pattern:tt)+) => {
+        $crate::assertions::general::__to_match_pattern(
+            &$builder,
+            |subject| ::std::matches!(subject, $($pattern)+),
+            ::std::stringify!($($pattern)+),
+        )
+    };
+}
+
+/// Asserts that the subject is a particular enum variant, ignoring any of the
+/// variant's fields.
+///
+/// This macro can only be used as the final assertion in an [`expect!`] (or
+/// [`try_expect!`]) call. Both the expected variant and the subject's actual
+/// variant (if it implements [`Debug`](std::fmt::Debug)) are included in
+/// failure messages.
+///
+/// ```
+/// # use expecters::prelude::*;
+/// expect!(Some(1), to_be_variant!(Some));
+/// expect!(Ok::<i32, ()>(1), to_be_variant!(Result::Ok));
+/// ```
+///
+/// The assertion fails if the subject is a different variant:
+///
+/// ```should_panic
+/// # use expecters::prelude::*;
+/// expect!(None::<i32>, to_be_variant!(Some));
+/// ```
+#[macro_export]
+macro_rules! to_be_variant {
+    (@build, $builder:expr, $variant:path $(,)?) => {
+        $crate::assertions::general::__to_be_variant(
+            &$builder,
+            |subject| ::std::matches!(subject, $variant { .. }),
+            ::std::stringify!($variant),
+        )
+    };
+}
+
 // Note: it's important to use the input tokens before stringifying them. This
 // is necessary to ensure that the tokens are treated as values instead of
 // arbitrary, meaningless tokens, and ensures that LSPs provide real completions
@@ -193,14 +342,33 @@ macro_rules! try_expect {
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __expect_inner {
+    // Entrypoint (borrowed subject)
+    (
+        @ref,
+        $subject:expr,
+        $($assertions:tt)*
+    ) => {{
+        let subject = $crate::annotated!(&$subject, ::std::stringify!($subject));
+        $crate::__expect_inner!(@start, subject, $($assertions)*)
+    }};
+
     // Entrypoint
     (
         $subject:expr,
         $($assertions:tt)*
     ) => {{
         let subject = $crate::annotated!($subject);
-        let subject_repr = ::std::string::ToString::to_string(&subject);
-        let builder = $crate::assertions::AssertionBuilder::__new(subject);
+        $crate::__expect_inner!(@start, subject, $($assertions)*)
+    }};
+
+    // Start building the assertion from an annotated subject
+    (
+        @start,
+        $subject:ident,
+        $($assertions:tt)*
+    ) => {{
+        let subject_repr = ::std::string::ToString::to_string(&$subject);
+        let builder = $crate::assertions::AssertionBuilder::__new($subject);
         $crate::__expect_inner!(
             @build_assertion,
             [],
@@ -217,11 +385,12 @@ macro_rules! __expect_inner {
         [$($frame_name:expr,)*],
         $subject:expr,
         $builder:expr,
-        $assertion:ident($($param:expr),* $(,)?)
+        $assertion:ident $(::<$($generic:ty),+ $(,)?>)? ($($param:expr),* $(,)?)
         $(,)?
+        $(; $($message:tt)+)?
     ) => {{
         let builder = $crate::__expect_inner!(@annotate, $builder);
-        let assertion = builder.$assertion($($crate::annotated!($param),)*);
+        let assertion = builder.$assertion $(::<$($generic),+>)? ($($crate::annotated!($param),)*);
         let cx = $crate::assertions::AssertionContext::__new(
             $subject,
             $crate::source_loc!(),
@@ -233,11 +402,16 @@ macro_rules! __expect_inner {
                 FRAMES
             },
         );
-        $crate::assertions::AssertionBuilder::__apply(
+        let output = $crate::assertions::AssertionBuilder::__apply(
             builder,
             cx,
             assertion,
-        )
+        );
+        $(let output = $crate::assertions::general::MessageOutput::with_message(
+            output,
+            || ::std::format!($($message)+),
+        );)?
+        output
     }};
     (
         // Base case (without params)
@@ -245,28 +419,64 @@ macro_rules! __expect_inner {
         [$($frame_name:expr,)*],
         $subject:expr,
         $builder:expr,
-        $assertion:ident
+        $assertion:ident $(::<$($generic:ty),+ $(,)?>)?
         $(,)?
+        $(; $($message:tt)+)?
     ) => {
         $crate::__expect_inner!(
             @build_assertion,
             [$($frame_name,)*],
             $subject,
             $builder,
-            $assertion()
+            $assertion $(::<$($generic),+>)? ()
+            $(; $($message)+)?
         )
     };
+    (
+        // Base case (macro)
+        @build_assertion,
+        [$($frame_name:expr,)*],
+        $subject:expr,
+        $builder:expr,
+        $assertion:ident!($($args:tt)*)
+        $(,)?
+        $(; $($message:tt)+)?
+    ) => {{
+        let builder = $crate::__expect_inner!(@annotate, $builder);
+        let assertion = $assertion!(@build, builder, $($args)*);
+        let cx = $crate::assertions::AssertionContext::__new(
+            $subject,
+            $crate::source_loc!(),
+            {
+                const FRAMES: &'static [&'static str] = &[
+                    $($frame_name,)*
+                    ::std::stringify!($assertion),
+                ];
+                FRAMES
+            },
+        );
+        let output = $crate::assertions::AssertionBuilder::__apply(
+            builder,
+            cx,
+            assertion,
+        );
+        $(let output = $crate::assertions::general::MessageOutput::with_message(
+            output,
+            || ::std::format!($($message)+),
+        );)?
+        output
+    }};
     (
         // Recursive case (with params)
         @build_assertion,
         [$($frame_name:expr,)*],
         $subject:expr,
         $builder:expr,
-        $modifier:ident($($param:expr),* $(,)?),
+        $modifier:ident $(::<$($generic:ty),+ $(,)?>)? ($($param:expr),* $(,)?),
         $($rest:tt)*
     ) => {{
         let builder = $crate::__expect_inner!(@annotate, $builder);
-        let builder = builder.$modifier(
+        let builder = builder.$modifier $(::<$($generic),+>)? (
             $($crate::annotated!($param),)*
         );
         $crate::__expect_inner!(
@@ -286,7 +496,7 @@ macro_rules! __expect_inner {
         [$($frame_name:expr,)*],
         $subject:expr,
         $builder:expr,
-        $modifier:ident,
+        $modifier:ident $(::<$($generic:ty),+ $(,)?>)?,
         $($rest:tt)*
     ) => {
         $crate::__expect_inner!(
@@ -294,7 +504,7 @@ macro_rules! __expect_inner {
             [$($frame_name,)*],
             $subject,
             $builder,
-            $modifier(),
+            $modifier $(::<$($generic),+>)? (),
             $($rest)*
         )
     };
@@ -5,13 +5,16 @@ use crate::specialization::__SpecializeWrapper;
 #[macro_export]
 #[doc(hidden)]
 macro_rules! annotated {
-    ($value:expr) => {{
+    ($value:expr) => {
+        $crate::annotated!($value, ::std::stringify!($value))
+    };
+    ($value:expr, $stringified:expr) => {{
         #[allow(unused_imports)]
         use $crate::specialization::annotated::*;
 
         // $value needs to be used as a value before it's stringified to get
         // proper completions from tools like rust-analyzer
-        let mut annotated = $crate::metadata::Annotated::__new($value, ::std::stringify!($value));
+        let mut annotated = $crate::metadata::Annotated::__new($value, $stringified);
         let wrapper = annotated.__specialize();
         wrapper
             .__for_trait::<dyn ::std::fmt::Debug>()
@@ -13,15 +13,32 @@
 
 pub use crate::{
     assertions::{
-        general::GeneralAssertions,
+        any::AnyAssertions,
+        bytes::BytesAssertions,
+        capacity::CapacityAssertions,
+        channels::ChannelAssertions,
+        cow::CowAssertions,
+        errors::ErrorAssertions,
+        functions::FunctionAssertions,
+        general::{GeneralAssertions, IntegerAssertions},
         iterators::IteratorAssertions,
+        laws::LawAssertions,
+        maps::MapAssertions,
         options::OptionAssertions,
+        paths::PathAssertions,
+        ranges::RangeAssertions,
         read::ReadExtensions,
         results::ResultAssertions,
         strings::{DebugAssertions, DisplayAssertions, StringAssertions},
     },
-    expect, try_expect,
+    expect, expect_ref, to_be_variant, to_match_pattern, try_expect, try_expect_report,
 };
 
 #[cfg(feature = "futures")]
-pub use crate::assertions::{async_read::AsyncReadAssertions, futures::FutureAssertions};
+pub use crate::assertions::{
+    async_read::AsyncReadAssertions,
+    futures::{AsyncFunctionAssertions, FutureAssertions, FutureTupleAssertions},
+};
+
+#[cfg(feature = "serde")]
+pub use crate::assertions::serde::SerdeAssertions;
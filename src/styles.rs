@@ -1,32 +1,219 @@
-use std::fmt::Display;
+//! Styling for failure messages.
+//!
+//! Failure messages are styled according to the current [`Theme`]. The theme
+//! can be chosen by calling [`set_theme`], or by setting the `EXPECTERS_THEME`
+//! environment variable to one of `default`, `colorblind`, or `plain`. If
+//! [`set_theme`] is called, then it overrides the environment variable.
+//!
+//! Colors require the `colors` feature, and can be disabled by setting
+//! `NO_COLOR`. The [`Plain`](Theme::Plain) theme does not depend on colors, so
+//! it can be used to highlight differences in diffs even without that feature.
+//!
+//! By default, colors are only used when writing to a terminal. Some CI
+//! systems render colors without being detected as a terminal, so colors can
+//! be forced on by calling [`set_color_mode`] or by setting the `FORCE_COLOR`
+//! environment variable to anything other than `0` or `false`. `NO_COLOR`
+//! takes priority over `FORCE_COLOR`, but not over [`set_color_mode`].
+
+use std::{
+    fmt::Display,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// A palette used to style failure messages.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Theme {
+    /// The default palette. Additions in diffs are green and removals are red.
+    #[default]
+    Default,
+
+    /// A palette that avoids distinguishing values by red and green. Additions
+    /// in diffs are blue and removals are yellow.
+    Colorblind,
+
+    /// No colors at all. Changed sections of lines in diffs are surrounded by
+    /// `{+...+}` (added) and `[-...-]` (removed) markers instead.
+    Plain,
+}
+
+impl Theme {
+    fn from_env() -> Self {
+        match std::env::var("EXPECTERS_THEME").as_deref() {
+            Ok("colorblind") => Theme::Colorblind,
+            Ok("plain") => Theme::Plain,
+            _ => Theme::Default,
+        }
+    }
+}
+
+// 0 means the theme hasn't been chosen yet
+static THEME: AtomicU8 = AtomicU8::new(0);
+
+/// Sets the theme used to style failure messages. This affects all threads,
+/// and overrides the `EXPECTERS_THEME` environment variable.
+///
+/// ```
+/// use expecters::styles::{set_theme, theme, Theme};
+///
+/// set_theme(Theme::Colorblind);
+/// assert_eq!(theme(), Theme::Colorblind);
+/// ```
+pub fn set_theme(theme: Theme) {
+    let value = match theme {
+        Theme::Default => 1,
+        Theme::Colorblind => 2,
+        Theme::Plain => 3,
+    };
+    THEME.store(value, Ordering::Relaxed);
+}
+
+/// Gets the theme used to style failure messages.
+#[must_use]
+pub fn theme() -> Theme {
+    match THEME.load(Ordering::Relaxed) {
+        1 => Theme::Default,
+        2 => Theme::Colorblind,
+        3 => Theme::Plain,
+        _ => {
+            let theme = Theme::from_env();
+            set_theme(theme);
+            theme
+        }
+    }
+}
+
+/// Whether failure messages should be styled with colors.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ColorMode {
+    /// Always use colors, even when not writing to a terminal.
+    Always,
+
+    /// Never use colors.
+    Never,
+
+    /// Use colors only if the output stream supports them.
+    #[default]
+    Auto,
+}
+
+impl ColorMode {
+    fn from_env() -> Self {
+        let is_set = |name| std::env::var_os(name).is_some_and(|value| !value.is_empty());
+        if is_set("NO_COLOR") {
+            return ColorMode::Never;
+        }
+
+        match std::env::var("FORCE_COLOR").as_deref() {
+            Ok("0" | "false") | Err(_) => ColorMode::Auto,
+            Ok(_) => ColorMode::Always,
+        }
+    }
+}
+
+// 0 means the color mode hasn't been chosen yet
+static COLOR_MODE: AtomicU8 = AtomicU8::new(0);
+
+/// Sets whether failure messages are styled with colors. This affects all
+/// threads, and overrides the `FORCE_COLOR` and `NO_COLOR` environment
+/// variables.
+///
+/// ```
+/// use expecters::styles::{color_mode, set_color_mode, ColorMode};
+///
+/// set_color_mode(ColorMode::Always);
+/// assert_eq!(color_mode(), ColorMode::Always);
+/// ```
+pub fn set_color_mode(mode: ColorMode) {
+    let value = match mode {
+        ColorMode::Always => 1,
+        ColorMode::Never => 2,
+        ColorMode::Auto => 3,
+    };
+    COLOR_MODE.store(value, Ordering::Relaxed);
+}
+
+/// Gets whether failure messages are styled with colors.
+#[must_use]
+pub fn color_mode() -> ColorMode {
+    match COLOR_MODE.load(Ordering::Relaxed) {
+        1 => ColorMode::Always,
+        2 => ColorMode::Never,
+        3 => ColorMode::Auto,
+        _ => {
+            let mode = ColorMode::from_env();
+            set_color_mode(mode);
+            mode
+        }
+    }
+}
+
+/// A value that is either styled unconditionally or only if the output stream
+/// supports colors.
+#[cfg(feature = "colors")]
+enum MaybeStyled<A, B> {
+    Auto(A),
+    Forced(B),
+}
+
+#[cfg(feature = "colors")]
+impl<A, B> Display for MaybeStyled<A, B>
+where
+    A: Display,
+    B: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaybeStyled::Auto(inner) => inner.fmt(f),
+            MaybeStyled::Forced(inner) => inner.fmt(f),
+        }
+    }
+}
 
 macro_rules! maybe_styled {
-    ($fn:ident, [$($style:ident),*]) => {
+    ($fn:ident, [$($default:ident),*], [$($colorblind:ident),*]) => {
         #[cfg(feature = "colors")]
         #[inline]
         #[allow(dead_code)]
-        pub fn $fn(s: &impl Display) -> impl Display + '_ {
+        pub(crate) fn $fn(s: &impl Display) -> impl Display + '_ {
             use owo_colors::{OwoColorize, Stream, Style};
-            let style = Style::new() $(.$style())*;
-            s.if_supports_color(Stream::Stderr, move |s| s.style(style))
+            let style = match theme() {
+                Theme::Default => Style::new() $(.$default())*,
+                Theme::Colorblind => Style::new() $(.$colorblind())*,
+                Theme::Plain => Style::new(),
+            };
+            match color_mode() {
+                ColorMode::Always => MaybeStyled::Forced(s.style(style)),
+                ColorMode::Never => MaybeStyled::Forced(s.style(Style::new())),
+                ColorMode::Auto => {
+                    MaybeStyled::Auto(s.if_supports_color(Stream::Stderr, move |s| s.style(style)))
+                }
+            }
         }
 
         #[cfg(not(feature = "colors"))]
         #[inline]
         #[allow(dead_code)]
-        pub fn $fn(s: &impl Display) -> impl Display + '_ {
+        pub(crate) fn $fn(s: &impl Display) -> impl Display + '_ {
             s
         }
     };
 }
 
 // Error messages
-maybe_styled!(dimmed, [dimmed]);
-maybe_styled!(error, [bright_red]);
-maybe_styled!(reference, [yellow]);
+maybe_styled!(dimmed, [dimmed], [dimmed]);
+maybe_styled!(error, [bright_red], [bright_magenta]);
+maybe_styled!(reference, [yellow], [cyan]);
 
 // Diffs
-maybe_styled!(added, [green]);
-maybe_styled!(removed, [red]);
-maybe_styled!(emphasize_added, [green, bold, underline]);
-maybe_styled!(emphasize_removed, [red, bold, underline]);
+maybe_styled!(added, [green], [blue]);
+maybe_styled!(removed, [red], [yellow]);
+maybe_styled!(
+    emphasize_added,
+    [green, bold, underline],
+    [blue, bold, underline]
+);
+maybe_styled!(
+    emphasize_removed,
+    [red, bold, underline],
+    [yellow, bold, underline]
+);
@@ -0,0 +1,102 @@
+//! Tracing for assertion steps.
+//!
+//! When tracing is enabled, each step of an assertion writes the value it
+//! received to stderr as it executes. This can help with understanding why a
+//! complex chain of modifiers behaves unexpectedly, since it shows every value
+//! that passes through the chain (including values for steps that are executed
+//! multiple times, like the steps after [`all`]).
+//!
+//! Tracing can be enabled by calling [`set_tracing`], or by setting the
+//! `EXPECTERS_TRACE` environment variable to anything other than `0` or
+//! `false`. If [`set_tracing`] is called, then it overrides the environment
+//! variable.
+//!
+//! [`all`]: crate::prelude::IteratorAssertions::all
+
+use std::{
+    fmt::Write,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::metadata::SourceLoc;
+
+// 0 means tracing hasn't been configured yet
+static TRACING: AtomicU8 = AtomicU8::new(0);
+
+fn tracing_from_env() -> bool {
+    match std::env::var("EXPECTERS_TRACE").as_deref() {
+        Ok("" | "0" | "false") | Err(_) => false,
+        Ok(_) => true,
+    }
+}
+
+/// Sets whether assertion steps are traced. This affects all threads, and
+/// overrides the `EXPECTERS_TRACE` environment variable.
+///
+/// ```
+/// use expecters::trace::{is_tracing, set_tracing};
+///
+/// set_tracing(true);
+/// assert!(is_tracing());
+/// # set_tracing(false);
+/// ```
+pub fn set_tracing(enabled: bool) {
+    TRACING.store(if enabled { 2 } else { 1 }, Ordering::Relaxed);
+}
+
+/// Gets whether assertion steps are traced.
+#[must_use]
+pub fn is_tracing() -> bool {
+    match TRACING.load(Ordering::Relaxed) {
+        1 => false,
+        2 => true,
+        _ => {
+            let enabled = tracing_from_env();
+            set_tracing(enabled);
+            enabled
+        }
+    }
+}
+
+/// Writes a step and the value it received to stderr.
+pub(crate) fn trace_step(source_loc: SourceLoc, step: &str, received: &str) {
+    let mut line = String::new();
+    write_step(&mut line, source_loc, step, received).unwrap();
+    eprint!("{line}");
+}
+
+/// Writes a step and the value it received as a single line.
+fn write_step(
+    output: &mut impl Write,
+    source_loc: SourceLoc,
+    step: &str,
+    received: &str,
+) -> std::fmt::Result {
+    writeln!(
+        output,
+        "[expecters] {source_loc}: {step} received {received}"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn steps_are_written_as_lines() {
+        let source_loc = crate::source_loc!();
+        let mut output = String::new();
+        write_step(&mut output, source_loc, "all", "[1, 2]").unwrap();
+        write_step(&mut output, source_loc, "to_equal", "1").unwrap();
+
+        let lines: Vec<_> = output.lines().collect();
+        expect!(
+            lines,
+            to_equal([
+                format!("[expecters] {source_loc}: all received [1, 2]"),
+                format!("[expecters] {source_loc}: to_equal received 1"),
+            ]),
+        );
+    }
+}
@@ -1,3 +1,5 @@
+//! Checks the contents of failure messages.
+
 use expecters::prelude::*;
 
 #[test]
@@ -56,3 +58,43 @@ fn annotated_strings() {
         }),
     );
 }
+
+#[test]
+#[should_panic = "subject: value.0"]
+fn borrowed_subject() {
+    struct NotDebug(i32);
+
+    let value = (NotDebug(1),);
+    expect_ref!(value.0, to_satisfy(|inner: &NotDebug| inner.0 == 2));
+}
+
+#[test]
+fn custom_message() {
+    let id = 1;
+    expect!(
+        try_expect!(id, not, to_be_one_of([1, 2]); "id {id} must be unused"),
+        to_be_err_and,
+        as_display,
+        to_contain_substr("assertion failed: id 1 must be unused"),
+    );
+    expect!(
+        try_expect!(Some(1), to_match_pattern!(None); "expected nothing"),
+        to_be_err_and,
+        as_display,
+        to_contain_substr("assertion failed: expected nothing"),
+    );
+    expect!(1, to_equal(1); "not shown on success");
+}
+
+#[test]
+fn custom_message_is_formatted_on_failure_only() {
+    struct Message;
+
+    impl std::fmt::Display for Message {
+        fn fmt(&self, _: &mut std::fmt::Formatter) -> std::fmt::Result {
+            panic!("formatted the message")
+        }
+    }
+
+    expect!(1, to_equal(1); "{}", Message);
+}
@@ -1,3 +1,5 @@
+//! Example failures. Run these manually to see how failure messages look.
+
 use expecters::prelude::*;
 
 #[test]
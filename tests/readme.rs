@@ -1,3 +1,5 @@
+//! Checks that the examples in the readme are kept up to date.
+
 use expecters::prelude::*;
 
 const TEST_CONTENTS: &str = include_str!("./readme_example.rs");
@@ -1,5 +1,5 @@
 #![cfg(feature = "futures")]
-#![allow(clippy::unused_async)]
+#![allow(clippy::ignore_without_reason, clippy::unused_async, missing_docs)]
 
 use expecters::prelude::*;
 
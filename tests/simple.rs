@@ -1,3 +1,5 @@
+//! Simple end-to-end assertions.
+
 use expecters::prelude::*;
 
 #[test]
@@ -8,3 +10,11 @@ fn not_debug() {
     expect!(NotDebug(1), to_equal(NotDebug(1)));
     expect!([NotDebug(1)], all, to_equal(NotDebug(1)));
 }
+
+#[test]
+fn borrowed_subject() {
+    let values = vec![String::from("a"), String::from("b")];
+    expect_ref!(values, count, to_equal(2));
+    expect_ref!(values[0], to_equal("a"));
+    expect!(values, all, to_have_byte_length(1));
+}